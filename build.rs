@@ -81,10 +81,94 @@ fn generate_sbom() -> Result<()> {
     Ok(())
 }
 
+/// Converts a translation key into a `SCREAMING_SNAKE_CASE` constant name.
+fn i18n_key_ident(key: &str) -> String {
+    let mut ident = String::new();
+    for c in key.chars() {
+        if c.is_ascii_alphanumeric() {
+            ident.push(c.to_ascii_uppercase());
+        } else if !ident.is_empty() && !ident.ends_with('_') {
+            ident.push('_');
+        }
+    }
+    let ident = ident.trim_end_matches('_').to_string();
+
+    match ident.chars().next() {
+        None => "KEY".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("KEY_{}", ident),
+        Some(_) => ident,
+    }
+}
+
+/// Generates `i18n_keys.rs` with one constant per key in `en.json` and `i18n.yml`.
+fn generate_i18n_keys() -> Result<()> {
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::fs;
+    use std::path::Path;
+
+    let i18n_dir = Path::new("assets/i18n");
+    let en_path = i18n_dir.join("en.json");
+    let yml_path = i18n_dir.join("i18n.yml");
+    println!("cargo:rerun-if-changed={}", en_path.display());
+    println!("cargo:rerun-if-changed={}", yml_path.display());
+
+    let mut keys = BTreeSet::new();
+
+    let en: serde_json::Value = serde_json::from_str(&fs::read_to_string(&en_path)?)?;
+    if let Some(en) = en.as_object() {
+        keys.extend(en.keys().cloned());
+    }
+
+    let yml: serde_yaml::Value = serde_yaml::from_str(&fs::read_to_string(&yml_path)?)?;
+    if let Some(entries) = yml.get("i18n").and_then(serde_yaml::Value::as_mapping) {
+        keys.extend(
+            entries
+                .keys()
+                .filter_map(|key| key.as_str().map(String::from)),
+        );
+    }
+
+    // Two keys with the same constant name, like `Tasks` and `Tasks:`, would
+    // need made-up names, so they have to be merged in the translations.
+    let mut idents: BTreeMap<String, &String> = BTreeMap::new();
+    for key in &keys {
+        if let Some(other) = idents.insert(i18n_key_ident(key), key) {
+            anyhow::bail!(
+                "i18n keys {:?} and {:?} both map to the constant {}; merge them in assets/i18n",
+                other,
+                key,
+                i18n_key_ident(key)
+            );
+        }
+    }
+    let mut code = String::from("// @generated by build.rs from assets/i18n. Do not edit.\n");
+    for (ident, key) in &idents {
+        code.push_str(&format!(
+            "\n#[doc = {:?}]\npub const {}: &str = {:?};\n",
+            key, ident, key
+        ));
+    }
+
+    code.push_str(
+        "\n/// Every translation key known at compile time.\npub const ALL: &[&str] = &[\n",
+    );
+    for key in &keys {
+        code.push_str(&format!("    {:?},\n", key));
+    }
+    code.push_str("];\n");
+
+    let out_dir = std::env::var("OUT_DIR")?;
+    fs::write(Path::new(&out_dir).join("i18n_keys.rs"), code)?;
+    Ok(())
+}
+
 fn main() -> Result<()> {
     // Generate SBOM if feature is enabled
     generate_sbom()?;
 
+    // Generate compile-time checked translation keys
+    generate_i18n_keys()?;
+
     // Generate version information
     let build = BuildBuilder::all_build()?;
     let cargo = CargoBuilder::all_cargo()?;
@@ -1,4 +1,6 @@
-use crate::components::{challenge::ChallengeComponent, game_path::GamePathComponent};
+use crate::components::{
+    ContentPrefetchComponent, challenge::ChallengeComponent, game_path::GamePathComponent,
+};
 use crate::prelude::create_i18n_config;
 
 #[cfg(feature = "chat")]
@@ -18,7 +20,7 @@ use crate::components::profile::{ProfileConfigComponent, ProfilePointsComponent}
 
 use crate::model::DefaultSessionInitializer;
 use crate::prelude::{BrowserCoordinate, ChallengeIndex, MapComponent, ProfilePointsManager};
-use crate::providers::{I18nProvider, create_repositories};
+use crate::providers::{I18nProvider, RepositoryProvider, SoundProvider, create_repositories};
 use crate::repository::LocalStorage;
 use konnektoren_core::prelude::*;
 use std::sync::Arc;
@@ -96,7 +98,17 @@ pub fn Example() -> Html {
             <GamePathComponent game_path={game.game_paths[0].clone()} on_challenge_config={new_challenge_cb} />
             {
                 if let Some(ref challenge) = *challenge {
-                    html! { <ChallengeComponent challenge={challenge.clone()} /> }
+                    let challenge_index = game.game_paths[0]
+                        .challenges
+                        .iter()
+                        .position(|config| config.id == challenge.challenge_config.id)
+                        .unwrap_or_default();
+                    html! {
+                        <>
+                            <ChallengeComponent challenge={challenge.clone()} />
+                            <ContentPrefetchComponent game={game.clone()} {challenge_index} />
+                        </>
+                    }
                 } else {
                     html! {}
                 }
@@ -123,6 +135,8 @@ pub fn preview_groups() -> ComponentList {
             MultipleChoiceResultComponent::preview(),
             SortTableComponent::preview(),
             SortTableResultComponent::preview(),
+            MatchingComponent::preview(),
+            MatchingResultComponent::preview(),
             ContextualChoiceComponent::preview(),
             ContextualChoiceResultComponent::preview(),
             GapFillComponent::preview(),
@@ -137,10 +151,17 @@ pub fn preview_groups() -> ComponentList {
             ChallengeReviewComponent::preview(),
             ChallengeRatingComponent::preview(),
             ChallengeTimerComponent::preview(),
+            CountdownComponent::preview(),
             ChallengesSummaryComp::preview(),
             ChallengeHistorySummaryComponent::preview(),
+            ReviewQueueComponent::preview(),
             ResultSummaryComponent::preview(),
             VocabularyComponent::preview(),
+            FlashcardsComponent::preview(),
+            VocabularyResultComponent::preview(),
+            ResultActionsComponent::preview(),
+            PracticeFeedbackComponent::preview(),
+            ResumePromptComponent::preview(),
             DialogComponent::preview(),
             DialogResultComponent::preview(),
             DialogObserverResultComponent::preview(),
@@ -169,6 +190,7 @@ pub fn preview_groups() -> ComponentList {
             "UI Components",
             RatingStarsComponent::preview(),
             InformativeComponent::preview(),
+            CacheStatusComponent::preview(),
             InformativeMarkdownComponent::preview(),
             #[cfg(feature = "effects")]
             ReadText::preview(),
@@ -186,6 +208,8 @@ pub fn preview_groups() -> ComponentList {
             MusicComponent::preview(),
             MusicConfig::preview(),
             SoundConfig::preview(),
+            SpeechConfig::preview(),
+            AccessibilityConfig::preview(),
             SelectDesign::preview(),
             SelectTheme::preview(),
             AppVersionComponent::preview(),
@@ -277,6 +301,7 @@ pub fn App() -> Html {
         <DesignProvider>
         <I18nProvider config={i18n_config}>
             <GameControllerProvider>
+            <SoundProvider>
             <div style="
                 font-family: Arial, sans-serif;
                 height: 100vh;
@@ -311,6 +336,7 @@ pub fn App() -> Html {
                     <SelectDesign />
                 </div>
             </div>
+            </SoundProvider>
             </GameControllerProvider>
         </I18nProvider>
         </DesignProvider>
@@ -320,8 +346,12 @@ pub fn App() -> Html {
     }
     #[cfg(not(feature = "yew-preview"))]
     html! {
+        <RepositoryProvider config={repository_config}>
         <I18nProvider config={i18n_config}>
+        <SoundProvider>
             <Example />
+        </SoundProvider>
         </I18nProvider>
+        </RepositoryProvider>
     }
 }
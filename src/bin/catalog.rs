@@ -8,6 +8,9 @@
 //!
 //! Override the output path via the OUTPUT environment variable:
 //!   OUTPUT=dist/catalog.html cargo run --bin catalog --features catalog
+//!
+//! Render the catalog in the pseudo-locale to check layouts for long strings:
+//!   LANG=xx cargo run --bin catalog --features catalog
 
 use konnektoren_yew::app::preview_groups;
 use konnektoren_yew::model::DefaultSessionInitializer;
@@ -0,0 +1,260 @@
+//! Validate challenge and game path YAML files before they are published.
+//!
+//! Checks the structural invariants of every challenge type, and renders
+//! each challenge once via Yew SSR to catch components that panic on it.
+//! Issues are printed as `file:line: message`; the exit code is 1 when there
+//! are any.
+//!
+//! Run with:
+//!   cargo run --bin content-check --features tools,ssr -- assets/challenges
+//!
+//! Arguments are YAML files or directories, which are searched recursively.
+//! Game paths are recognized by their `challenges` key, and their challenges
+//! must refer to one of the challenge files checked in the same run.
+
+use konnektoren_core::challenges::{Challenge, ChallengeType, ChallengeVariant};
+use konnektoren_core::game::GamePath;
+use konnektoren_yew::model::DefaultSessionInitializer;
+use konnektoren_yew::prelude::{
+    ChallengeComponent, GameControllerProvider, I18nProvider, RepositoryProvider,
+    create_i18n_config,
+};
+use konnektoren_yew::providers::create_repositories;
+use konnektoren_yew::repository::LocalStorage;
+use konnektoren_yew::tools::{
+    ContentIssue, check_challenge, check_game_path, locate, render_variant,
+};
+use std::collections::HashSet;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::{Arc, Mutex};
+use yew::prelude::*;
+
+enum Content {
+    Challenge(ChallengeType),
+    GamePath(GamePath),
+}
+
+struct ContentFile {
+    path: PathBuf,
+    source: String,
+    content: Content,
+}
+
+/// An issue with the file and line it was found at.
+struct Report {
+    path: PathBuf,
+    line: Option<usize>,
+    message: String,
+}
+
+impl Report {
+    fn new(file: &Path, line: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            path: file.to_path_buf(),
+            line,
+            message: message.into(),
+        }
+    }
+
+    fn from_issue(file: &ContentFile, issue: &ContentIssue) -> Self {
+        Self::new(
+            &file.path,
+            locate(&file.source, &issue.path),
+            issue.to_string(),
+        )
+    }
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.path.display(), line, self.message),
+            None => write!(f, "{}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if path.is_dir() {
+        let mut entries = std::fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort();
+        for entry in entries {
+            collect_files(&entry, files)?;
+        }
+    } else if path
+        .extension()
+        .is_some_and(|extension| extension == "yml" || extension == "yaml")
+    {
+        files.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+fn load(path: &Path) -> Result<ContentFile, Report> {
+    let source =
+        std::fs::read_to_string(path).map_err(|e| Report::new(path, None, e.to_string()))?;
+    let parse_error = |e: serde_yaml::Error| {
+        let line = e.location().map(|location| location.line());
+        Report::new(path, line, format!("invalid YAML: {}", e))
+    };
+    let value: serde_yaml::Value = serde_yaml::from_str(&source).map_err(parse_error)?;
+    let content = if value.get("challenges").is_some() {
+        Content::GamePath(serde_yaml::from_value(value).map_err(parse_error)?)
+    } else {
+        Content::Challenge(serde_yaml::from_str(&source).map_err(parse_error)?)
+    };
+    Ok(ContentFile {
+        path: path.to_path_buf(),
+        source,
+        content,
+    })
+}
+
+#[derive(Properties, PartialEq)]
+struct RenderedChallengeProps {
+    challenge: Challenge,
+    variant: ChallengeVariant,
+}
+
+#[function_component]
+fn RenderedChallenge(props: &RenderedChallengeProps) -> Html {
+    let i18n_config = create_i18n_config();
+    let storage = LocalStorage::new(None);
+    let repository_config = create_repositories(storage, Arc::new(DefaultSessionInitializer));
+
+    html! {
+        <RepositoryProvider config={repository_config}>
+        <I18nProvider config={i18n_config}>
+            <GameControllerProvider>
+                <ChallengeComponent
+                    challenge={props.challenge.clone()}
+                    variant={Some(props.variant.clone())}
+                />
+            </GameControllerProvider>
+        </I18nProvider>
+        </RepositoryProvider>
+    }
+}
+
+/// Message of the last panic, recorded by the panic hook.
+static LAST_PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+/// Renders a challenge once, returning the panic message if it panics.
+fn render(
+    runtime: &tokio::runtime::Runtime,
+    challenge_type: &ChallengeType,
+    variant: ChallengeVariant,
+) -> Result<(), String> {
+    let props = RenderedChallengeProps {
+        challenge: Challenge {
+            challenge_type: challenge_type.clone(),
+            ..Default::default()
+        },
+        variant,
+    };
+    let rendered = panic::catch_unwind(AssertUnwindSafe(|| {
+        let local = tokio::task::LocalSet::new();
+        local.block_on(
+            runtime,
+            yew::LocalServerRenderer::<RenderedChallenge>::with_props(props).render(),
+        )
+    }));
+    rendered.map(drop).map_err(|_| {
+        LAST_PANIC
+            .lock()
+            .ok()
+            .and_then(|mut message| message.take())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+fn main() -> ExitCode {
+    let args: Vec<PathBuf> = std::env::args().skip(1).map(PathBuf::from).collect();
+    if args.is_empty() {
+        eprintln!("Usage: content-check <file or directory>...");
+        return ExitCode::from(2);
+    }
+
+    let mut paths = Vec::new();
+    for arg in &args {
+        if let Err(e) = collect_files(arg, &mut paths) {
+            eprintln!("{}: {}", arg.display(), e);
+            return ExitCode::from(2);
+        }
+    }
+
+    let mut reports = Vec::new();
+    let mut files = Vec::new();
+    for path in &paths {
+        match load(path) {
+            Ok(file) => files.push(file),
+            Err(report) => reports.push(report),
+        }
+    }
+
+    let challenge_ids: HashSet<String> = files
+        .iter()
+        .filter_map(|file| match &file.content {
+            Content::Challenge(challenge_type) => Some(challenge_type.id().to_string()),
+            Content::GamePath(_) => None,
+        })
+        .collect();
+
+    let runtime = match tokio::runtime::Builder::new_current_thread().build() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Failed to start the runtime: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+    panic::set_hook(Box::new(|info| {
+        if let Ok(mut message) = LAST_PANIC.lock() {
+            *message = Some(info.to_string());
+        }
+    }));
+
+    let mut challenges = 0;
+    for file in &files {
+        match &file.content {
+            Content::Challenge(challenge_type) => {
+                challenges += 1;
+                let issues = check_challenge(challenge_type);
+                reports.extend(issues.iter().map(|issue| Report::from_issue(file, issue)));
+                if let Err(message) =
+                    render(&runtime, challenge_type, render_variant(challenge_type))
+                {
+                    reports.push(Report::new(
+                        &file.path,
+                        None,
+                        format!("rendering panicked: {}", message),
+                    ));
+                }
+            }
+            Content::GamePath(game_path) => {
+                let issues = check_game_path(game_path, &challenge_ids);
+                reports.extend(issues.iter().map(|issue| Report::from_issue(file, issue)));
+            }
+        }
+    }
+    let _ = panic::take_hook();
+
+    for report in &reports {
+        println!("{}", report);
+    }
+    println!(
+        "Checked {} files ({} challenges): {} issues.",
+        paths.len(),
+        challenges,
+        reports.len()
+    );
+
+    if reports.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -1,7 +1,11 @@
+use konnektoren_core::challenges::ChallengeType;
 use konnektoren_yew::app_ssr::App;
+use konnektoren_yew::model::VocabularyAudio;
+use konnektoren_yew::tools::{check_vocabulary_audio, locate};
 use std::collections::HashMap;
 use std::env;
 use std::error::Error;
+use std::path::{Path, PathBuf};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 use tracing::{error, info};
@@ -15,6 +19,7 @@ const ENV_BASE_URL: &str = "BASE_URL";
 const ENV_SITE_NAME: &str = "SITE_NAME";
 const DEFAULT_SITE_NAME: &str = "Konnektoren";
 const DEFAULT_KEYWORDS: &str = "konnektoren,rust,yew";
+const OUTPUT_DIR: &str = "dist";
 
 #[derive(Clone, Routable, PartialEq, Debug, EnumIter)]
 pub enum Route {
@@ -56,7 +61,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Configure SSG
     let mut builder = SsgConfigBuilder::new()
-        .output_dir("dist")
+        .output_dir(OUTPUT_DIR)
         .template("dist/index.html");
 
     // Add global metadata
@@ -79,6 +84,9 @@ async fn main() -> Result<(), Box<dyn Error>> {
             default_image: "".to_string(),
         });
 
+    // Verify the recorded audio of vocabulary challenges
+    verify_vocabulary_audio(Path::new(OUTPUT_DIR))?;
+
     // Build and run generator
     let config = builder.build();
     let generator = StaticSiteGenerator::new(config)?;
@@ -123,3 +131,49 @@ fn add_route_metadata(builder: SsgConfigBuilder, base_url: &str) -> SsgConfigBui
 
     builder
 }
+
+fn collect_yaml_files(path: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if path.is_dir() {
+        for entry in std::fs::read_dir(path)? {
+            collect_yaml_files(&entry?.path(), files)?;
+        }
+    } else if path
+        .extension()
+        .is_some_and(|extension| extension == "yml" || extension == "yaml")
+    {
+        files.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+/// Checks that every audio file the vocabulary challenges below `site_root`
+/// refer to exists, so no recorded pronunciation silently falls back to
+/// speech synthesis.
+fn verify_vocabulary_audio(site_root: &Path) -> Result<(), Box<dyn Error>> {
+    let mut files = Vec::new();
+    collect_yaml_files(site_root, &mut files)?;
+
+    let mut checked = 0;
+    let mut issues = 0;
+    for path in files {
+        let source = std::fs::read_to_string(&path)?;
+        let Ok(ChallengeType::Vocabulary(vocabulary)) = serde_yaml::from_str(&source) else {
+            continue;
+        };
+        let audio = VocabularyAudio::from_yaml(&source)?;
+        checked += audio.files.len();
+        for issue in check_vocabulary_audio(&vocabulary, &audio, site_root) {
+            match locate(&source, &issue.path) {
+                Some(line) => error!("{}:{}: {}", path.display(), line, issue),
+                None => error!("{}: {}", path.display(), issue),
+            }
+            issues += 1;
+        }
+    }
+
+    if issues > 0 {
+        return Err(format!("{} vocabulary audio issues", issues).into());
+    }
+    info!("🔊 Verified {} vocabulary audio files", checked);
+    Ok(())
+}
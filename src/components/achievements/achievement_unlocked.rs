@@ -1,5 +1,7 @@
 use super::render_icon;
 use crate::i18n::use_i18n;
+use crate::model::SoundEffect;
+use crate::providers::use_sound_effect;
 use konnektoren_core::achievements::AchievementDefinition;
 use yew::prelude::*;
 
@@ -20,6 +22,14 @@ pub fn achievement_unlocked_component(props: &AchievementUnlockedComponentProps)
     let i18n = use_i18n();
     let achievement = &props.achievement;
     let opened = use_state(|| props.open);
+    let play_sound = use_sound_effect();
+
+    use_effect_with(*opened, move |opened| {
+        if *opened {
+            play_sound.emit(SoundEffect::Achievement);
+        }
+        || ()
+    });
 
     let onclick = {
         let opened = opened.clone();
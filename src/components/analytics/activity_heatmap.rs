@@ -1,7 +1,8 @@
 use crate::i18n::use_i18n;
+use crate::model::AttemptRecords;
+use crate::providers::use_attempt_records;
 use chrono::{Datelike, Days, NaiveDate, Utc};
 use konnektoren_core::challenges::{Challenge, ChallengeHistory};
-use konnektoren_core::prelude::Performance;
 use yew::prelude::*;
 
 // ─── Props ────────────────────────────────────────────────────────────────────
@@ -20,6 +21,7 @@ pub struct ActivityHeatmapProps {
 struct DayDetailModalProps {
     pub date: NaiveDate,
     pub challenges: Vec<Challenge>,
+    pub records: AttemptRecords,
     pub on_close: Callback<()>,
 }
 
@@ -45,7 +47,7 @@ fn day_detail_modal(props: &DayDetailModalProps) -> Html {
         .challenges
         .iter()
         .map(|c| {
-            let perf = c.performance(&c.challenge_result);
+            let perf = props.records.performance(c);
             let badge_mod = if perf >= 80 {
                 "badge-success-soft"
             } else if perf >= 60 {
@@ -115,13 +117,14 @@ fn day_detail_modal(props: &DayDetailModalProps) -> Html {
 pub fn activity_heatmap(props: &ActivityHeatmapProps) -> Html {
     let i18n = use_i18n();
     let selected_date: UseStateHandle<Option<NaiveDate>> = use_state(|| None);
+    let records = use_attempt_records(props.challenge_history.challenges.len());
 
     // All i18n strings extracted upfront — avoids borrow issues inside html! closures
     let title_text = i18n.t("Activity Heatmap");
     let weeks_text = i18n.t("weeks of");
     let learning_text = i18n.t("learning");
     let no_challenges_text = i18n.t("No challenges");
-    let challenges_text = i18n.t("challenges");
+    let challenges_text = i18n.t("Challenges");
     let less_text = i18n.t("Less");
     let more_text = i18n.t("More");
     let active_days_text = i18n.t("active days");
@@ -207,6 +210,7 @@ pub fn activity_heatmap(props: &ActivityHeatmapProps) -> Html {
             <DayDetailModal
                 date={date}
                 challenges={challenges_for_day}
+                records={records.clone()}
                 on_close={close_cb}
             />
         }
@@ -251,7 +255,7 @@ pub fn activity_heatmap(props: &ActivityHeatmapProps) -> Html {
                             let title = if count == 0 {
                                 format!("{}: {}", no_challenges_text, date.format("%b %d, %Y"))
                             } else {
-                                format!("{} {} – {}", count, challenges_text, date.format("%b %d, %Y"))
+                                format!("{}: {} – {}", challenges_text, count, date.format("%b %d, %Y"))
                             };
                             let sd = selected_date.clone();
                             let onclick = Callback::from(move |_: MouseEvent| {
@@ -1,8 +1,11 @@
 use crate::i18n::use_i18n;
+use crate::model::{ChallengeTimeout, without_timeouts};
+use crate::providers::use_challenge_timeouts;
 use chrono::Duration;
 use konnektoren_core::analytics::Metric;
 use konnektoren_core::analytics::Trend;
 use konnektoren_core::analytics::metrics::AverageTimeTakenMetric;
+use konnektoren_core::challenges::ChallengeHistory;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq, Clone)]
@@ -10,6 +13,25 @@ pub struct AverageTimeTakenProps {
     pub metric: AverageTimeTakenMetric,
     #[prop_or(Duration::days(7))]
     pub trend_window: Duration,
+    /// History to build the metric from instead of `metric`, without the
+    /// challenges that timed out.
+    #[prop_or_default]
+    pub challenge_history: Option<ChallengeHistory>,
+    /// Time-outs to leave out of `challenge_history`. Loaded from the
+    /// session repository when not given.
+    #[prop_or_default]
+    pub timeouts: Option<Vec<ChallengeTimeout>>,
+}
+
+/// The metric of `history` without its timed-out challenges, and how many
+/// were left out.
+fn completed_metric(
+    history: &ChallengeHistory,
+    timeouts: &[ChallengeTimeout],
+) -> (AverageTimeTakenMetric, usize) {
+    let completed = without_timeouts(history, timeouts);
+    let left_out = history.challenges.len() - completed.challenges.len();
+    (AverageTimeTakenMetric::new(completed), left_out)
 }
 
 // Clamp value to a gauge width: fast (<10s) fills near 100%, slow (>120s) near 100% red.
@@ -32,13 +54,24 @@ fn get_speed_modifier(value: f64) -> &'static str {
 #[function_component(AverageTimeTakenComponent)]
 pub fn average_time_taken(props: &AverageTimeTakenProps) -> Html {
     let i18n = use_i18n();
-    let value = props.metric.value();
-    let trend = props.metric.get_trend(props.trend_window);
+    let loaded_timeouts = use_challenge_timeouts(
+        props
+            .challenge_history
+            .as_ref()
+            .map_or(0, |history| history.challenges.len()),
+    );
+    let timeouts = props.timeouts.clone().unwrap_or(loaded_timeouts);
+    let (metric, left_out) = match &props.challenge_history {
+        Some(history) => completed_metric(history, &timeouts),
+        None => (props.metric.clone(), 0),
+    };
+    let value = metric.value();
+    let trend = metric.get_trend(props.trend_window);
 
     html! {
         <div class="average-time-taken">
             <div class="average-time-taken__header">
-                <h3 class="average-time-taken__title">{ i18n.t(props.metric.name()) }</h3>
+                <h3 class="average-time-taken__title">{ i18n.t(metric.name()) }</h3>
                 <div class={classes!("average-time-taken__trend", get_trend_modifier(&trend))}>
                     <i class={classes!("average-time-taken__trend-icon", "fas", get_trend_icon(&trend))}></i>
                     <span class="average-time-taken__trend-label">{ i18n.t(&trend.to_string()) }</span>
@@ -61,8 +94,13 @@ pub fn average_time_taken(props: &AverageTimeTakenProps) -> Html {
                 </div>
                 <div class="average-time-taken__details">
                     <p class="average-time-taken__description">
-                        { i18n.t(props.metric.description()) }
+                        { i18n.t(metric.description()) }
                     </p>
+                    if left_out > 0 {
+                        <p class="average-time-taken__timeouts">
+                            { format!("{} {}", left_out, i18n.t("time-outs not counted")) }
+                        </p>
+                    }
                 </div>
             </div>
         </div>
@@ -85,6 +123,40 @@ fn get_trend_icon(trend: &Trend) -> &'static str {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use konnektoren_core::challenges::Challenge;
+
+    #[test]
+    fn timed_out_challenges_are_left_out_of_the_average() {
+        let finished = |id: &str, seconds: i64| {
+            let mut challenge = Challenge::default();
+            challenge.challenge_config.id = id.to_string();
+            challenge.end_time = Some(Utc::now());
+            challenge.start_time = Some(Utc::now() - Duration::seconds(seconds));
+            challenge
+        };
+        let mut history = ChallengeHistory::new();
+        history.add_challenge(finished("articles", 20));
+        history.add_challenge(finished("verbs", 300));
+        let timed_out = &history.challenges[1];
+        let timeout = ChallengeTimeout {
+            challenge_id: "verbs".to_string(),
+            started_at: timed_out.start_time,
+            timed_out_at: timed_out.end_time.unwrap(),
+            task_index: 4,
+            time_limit: crate::model::TimeLimit::per_challenge(300),
+        };
+
+        let (metric, left_out) = completed_metric(&history, &[timeout]);
+        assert_eq!(left_out, 1);
+        assert!((metric.value() - 20.0).abs() < 1.0);
+        assert_eq!(completed_metric(&history, &[]).1, 0);
+    }
+}
+
 #[cfg(feature = "yew-preview")]
 mod preview {
     use super::*;
@@ -132,6 +204,26 @@ mod preview {
         challenge
     }
 
+    /// Four completed challenges and two that ran out of time after 120s.
+    fn timed_out_history() -> (ChallengeHistory, Vec<ChallengeTimeout>) {
+        let mut history = ChallengeHistory::new();
+        let mut timeouts = vec![];
+        for (duration_secs, days_ago) in [(20, 1), (25, 2), (120, 3), (18, 4), (120, 5), (22, 6)] {
+            let challenge = create_challenge_at(duration_secs, days_ago);
+            if duration_secs == 120 {
+                timeouts.push(ChallengeTimeout {
+                    challenge_id: challenge.challenge_config.id.clone(),
+                    started_at: challenge.start_time,
+                    timed_out_at: challenge.end_time.unwrap_or_else(Utc::now),
+                    task_index: 0,
+                    time_limit: crate::model::TimeLimit::per_challenge(120),
+                });
+            }
+            history.add_challenge(challenge);
+        }
+        (history, timeouts)
+    }
+
     fn create_improving_metric() -> AverageTimeTakenMetric {
         // Older (>7 days ago): slow ~60s; recent (<7 days): fast ~10s
         let mut history = ChallengeHistory::new();
@@ -159,6 +251,8 @@ mod preview {
     yew_preview::create_preview_with_tests!(
         component: AverageTimeTakenComponent,
         default_props: AverageTimeTakenProps {
+            challenge_history: None,
+            timeouts: None,
             metric: create_medium_metric(),
             trend_window: Duration::days(7),
         },
@@ -166,6 +260,8 @@ mod preview {
             (
                 "Fast Performance (< 10s)",
                 AverageTimeTakenProps {
+                    challenge_history: None,
+                    timeouts: None,
                     metric: create_fast_metric(),
                     trend_window: Duration::days(7),
                 }
@@ -173,6 +269,8 @@ mod preview {
             (
                 "Slow Performance (> 60s)",
                 AverageTimeTakenProps {
+                    challenge_history: None,
+                    timeouts: None,
                     metric: create_slow_metric(),
                     trend_window: Duration::days(7),
                 }
@@ -180,6 +278,8 @@ mod preview {
             (
                 "Improving Trend",
                 AverageTimeTakenProps {
+                    challenge_history: None,
+                    timeouts: None,
                     metric: create_improving_metric(),
                     trend_window: Duration::days(7),
                 }
@@ -187,6 +287,8 @@ mod preview {
             (
                 "Declining Trend",
                 AverageTimeTakenProps {
+                    challenge_history: None,
+                    timeouts: None,
                     metric: create_declining_metric(),
                     trend_window: Duration::days(7),
                 }
@@ -194,13 +296,26 @@ mod preview {
             (
                 "Single Challenge",
                 AverageTimeTakenProps {
+                    challenge_history: None,
+                    timeouts: None,
                     metric: create_single_challenge_metric(),
                     trend_window: Duration::days(7),
                 }
             ),
+            (
+                "With time-outs",
+                AverageTimeTakenProps {
+                    challenge_history: Some(timed_out_history().0),
+                    timeouts: Some(timed_out_history().1),
+                    metric: create_medium_metric(),
+                    trend_window: Duration::days(7),
+                }
+            ),
             (
                 "Empty (No Challenges)",
                 AverageTimeTakenProps {
+                    challenge_history: None,
+                    timeouts: None,
                     metric: AverageTimeTakenMetric::new(ChallengeHistory::new()),
                     trend_window: Duration::days(7),
                 }
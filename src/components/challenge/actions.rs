@@ -5,11 +5,17 @@ pub enum ChallengeActions {
     Next,
     Previous,
     Help,
+    /// Take the next hint for the current question.
+    Hint,
 }
 
 #[derive(Properties, PartialEq)]
 pub struct ChallengeActionsComponentProps {
     pub on_action: Callback<ChallengeActions>,
+    /// Hints left for the current question. Without it there is no hint
+    /// button.
+    #[prop_or_default]
+    pub hints_left: Option<usize>,
 }
 
 #[function_component(ChallengeActionsComponent)]
@@ -31,6 +37,11 @@ pub fn challenge_actions_component(props: &ChallengeActionsComponentProps) -> Ht
         Callback::from(move |_| on_action.emit(ChallengeActions::Help))
     };
 
+    let on_hint = {
+        let on_action = props.on_action.clone();
+        Callback::from(move |_| on_action.emit(ChallengeActions::Hint))
+    };
+
     html! {
         <div class="challenge-actions">
             <div class="challenge-actions__buttons">
@@ -43,6 +54,15 @@ pub fn challenge_actions_component(props: &ChallengeActionsComponentProps) -> Ht
                 <button class="challenge-actions__button challenge-actions__button--help" onclick={on_help}>
                     { i18n.t("Help") }
                 </button>
+                if let Some(hints_left) = props.hints_left {
+                    <button
+                        class="challenge-actions__button challenge-actions__button--hint"
+                        onclick={on_hint}
+                        disabled={hints_left == 0}
+                    >
+                        { format!("{} ({})", i18n.t("Hint"), hints_left) }
+                    </button>
+                }
             </div>
         </div>
     }
@@ -56,7 +76,15 @@ mod preview {
     yew_preview::create_preview!(
         ChallengeActionsComponent,
         ChallengeActionsComponentProps {
-            on_action: Callback::noop()
+            on_action: Callback::noop(),
+            hints_left: None,
         },
+        (
+            "With hints",
+            ChallengeActionsComponentProps {
+                on_action: Callback::noop(),
+                hints_left: Some(2),
+            }
+        ),
     );
 }
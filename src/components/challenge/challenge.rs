@@ -1,15 +1,31 @@
+use super::matching::is_matching_table;
+use super::mistake_review::mistakes_challenge;
 use super::{
     ContextualChoiceComponent, ContextualChoiceResultComponent, CustomComponent,
-    CustomPackageComponent, DialogComponent, DialogObserverResultComponent, DialogResultComponent,
-    GapFillComponent, GapFillResultComponent, InformativeComponent, InformativeMarkdownComponent,
-    MultipleChoice4Component, MultipleChoiceCircleComponent, MultipleChoiceComponent,
-    MultipleChoiceResultComponent, OrderingComponent, OrderingResultComponent,
-    PlaceholderComponent, SortTableComponent, VocabularyComponent,
+    CustomPackageComponent, CustomResultComponent, DialogComponent, DialogObserverResultComponent,
+    DialogResultComponent, GapFillComponent, GapFillResultComponent, InformativeComponent,
+    InformativeMarkdownComponent, InformativeResultComponent, MatchingComponent,
+    MatchingResultComponent, MistakeReviewComponent, MultipleChoice4Component,
+    MultipleChoiceCircleComponent, MultipleChoiceComponent, MultipleChoiceResultComponent,
+    OrderingComponent, OrderingResultComponent, PlaceholderComponent, PracticeFeedback,
+    PracticeFeedbackComponent, ResultActionsComponent, ResumePromptComponent, SortTableComponent,
+    SortTableResultComponent, VocabularyComponent, VocabularyResultComponent,
 };
-use crate::components::{ChallengeInfoComponent, ChallengeTimerComponent};
+use crate::components::{
+    ChallengeInfoComponent, ChallengeTimerComponent, CountdownComponent, LiveRegion,
+};
+use crate::i18n::use_i18n;
+use crate::model::{
+    ChallengeCheckpoint, ChallengeHints, ChallengeTimeout, DialogGraph, DialogOutcome, HintUsage,
+    SoundEffect, TimeLimit, TimeLimitScope, VocabularyAudio,
+};
+use crate::providers::{
+    GameControllerContext, RepositoryContext, SessionContext, use_sound_effect,
+};
+use crate::tools::challenge_seed;
 use konnektoren_core::challenges::ChallengeVariant;
-use konnektoren_core::commands::Command;
-use konnektoren_core::events::Event;
+use konnektoren_core::commands::{ChallengeCommand, Command};
+use konnektoren_core::events::{ChallengeEvent, Event};
 use konnektoren_core::prelude::*;
 use yew::prelude::*;
 
@@ -26,15 +42,210 @@ pub struct ChallengeComponentProps {
     pub language: Option<String>,
     #[prop_or(false)]
     pub preview: bool,
+    /// Called with the result when the learner starts reviewing their mistakes.
+    #[prop_or_default]
+    pub on_review_mistakes: Option<Callback<ChallengeResult>>,
+    /// Type gap fill answers instead of choosing them.
+    #[prop_or(false)]
+    pub typed_answers: bool,
+    /// Read dialogs aloud: Observer dialogs play turn by turn, Quiz dialogs
+    /// are heard before their text is shown.
+    #[prop_or(false)]
+    pub read_aloud: bool,
+    /// Branches of a role-play dialog, read with [`DialogGraph::from_yaml`]
+    /// from the challenge file. The result view then shows the path taken and
+    /// the ending reached, and its score is saved as a [`DialogOutcome`].
+    #[prop_or_default]
+    pub dialog_graph: Option<DialogGraph>,
+    /// Recorded pronunciations of the items of a vocabulary.
+    #[prop_or_default]
+    pub vocabulary_audio: Option<VocabularyAudio>,
+    /// Play without scoring: `Finish` is not passed on to `on_command`, so no
+    /// XP, leaderboard entry or history is recorded, and every answer gets
+    /// immediate feedback. Also enabled by a practice `GameControllerProvider`.
+    #[prop_or(false)]
+    pub practice: bool,
+    /// Called with the hints taken when the challenge is finished, just
+    /// before the `Finish` command. The hints are also saved as
+    /// [`ChallengeHints`], which score the history entry of the attempt.
+    #[prop_or_default]
+    pub on_hints: Option<Callback<HintUsage>>,
+    /// Finish the challenge with the answers given so far once the time is
+    /// up.
+    #[prop_or_default]
+    pub time_limit: Option<TimeLimit>,
+    /// Called when the time limit ran out, before the `Finish` command.
+    #[prop_or_default]
+    pub on_timeout: Option<Callback<ChallengeTimeout>>,
+    /// Seed for shuffled layouts. Defaults to one derived from the challenge
+    /// id and the session, so a layout stays the same within a session.
+    #[prop_or_default]
+    pub seed: Option<u64>,
+}
+
+/// Result to finish with when time runs out before the first answer. `None`
+/// for challenge types that can't be timed.
+fn empty_result(challenge_type: &ChallengeType) -> Option<ChallengeResult> {
+    match challenge_type {
+        ChallengeType::MultipleChoice(_) => Some(ChallengeResult::MultipleChoice(vec![])),
+        ChallengeType::ContextualChoice(_) => Some(ChallengeResult::ContextualChoice(vec![])),
+        ChallengeType::GapFill(_) => Some(ChallengeResult::GapFill(vec![])),
+        ChallengeType::SortTable(_) => Some(ChallengeResult::SortTable(vec![])),
+        ChallengeType::Ordering(_) => Some(ChallengeResult::Ordering(vec![])),
+        ChallengeType::Dialog(_) => Some(ChallengeResult::Dialog(vec![])),
+        _ => None,
+    }
 }
 
 #[function_component(ChallengeComponent)]
 pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
+    let i18n = use_i18n();
+    let practice = use_context::<GameControllerContext>().is_some_and(|context| context.practice)
+        || props.practice;
     let challenge_result = use_state(|| Option::<ChallengeResult>::None);
+    let practice_feedback = use_state(|| Option::<PracticeFeedback>::None);
+    let announcement = use_state(String::new);
+    let attempt = use_state(|| 0_usize);
+    let hints = use_state(HintUsage::default);
+    // Task index and answers of the latest checkpoint, submitted on time-out
+    let latest_result = use_mut_ref(|| Option::<(usize, ChallengeResult)>::None);
+    // Changes with every question, to restart a per-question countdown
+    let question = use_state(|| 0_usize);
+    let timed_out = use_state(|| false);
+    // Checkpoint found on mount, waiting for "continue" or "restart"
+    let saved_checkpoint = use_state(|| Option::<ChallengeCheckpoint>::None);
+    // Checkpoint the current attempt started from
+    let checkpoint = use_state(|| Option::<ChallengeCheckpoint>::None);
+    // Reduced challenge of the questions answered incorrectly, while replaying it
+    let mistake_review = use_state(|| Option::<Challenge>::None);
+    let attempt_repository = use_context::<RepositoryContext>()
+        .filter(|_| !props.preview && !practice)
+        .map(|context| context.attempt_repository);
+    let challenge_id = props.challenge.challenge_config.id.clone();
+    let session_id = use_context::<SessionContext>()
+        .map(|context| context.session.id.clone())
+        .unwrap_or_default();
+    let seed = props
+        .seed
+        .unwrap_or_else(|| challenge_seed(&challenge_id, &session_id));
+
+    {
+        let challenge_result = challenge_result.clone();
+        let saved_checkpoint = saved_checkpoint.clone();
+        let checkpoint = checkpoint.clone();
+        let mistake_review = mistake_review.clone();
+        let practice_feedback = practice_feedback.clone();
+        let hints = hints.clone();
+        let latest_result = latest_result.clone();
+        let timed_out = timed_out.clone();
+        let attempt_repository = attempt_repository.clone();
+        use_effect_with(challenge_id.clone(), move |challenge_id| {
+            challenge_result.set(None);
+            practice_feedback.set(None);
+            hints.set(HintUsage::default());
+            *latest_result.borrow_mut() = None;
+            timed_out.set(false);
+            saved_checkpoint.set(None);
+            checkpoint.set(None);
+            mistake_review.set(None);
+
+            #[cfg(feature = "csr")]
+            {
+                if let Some(attempt_repository) = attempt_repository {
+                    let challenge_id = challenge_id.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match attempt_repository.get_checkpoint(&challenge_id).await {
+                            Ok(found) => saved_checkpoint.set(found),
+                            Err(e) => tracing::error!("Failed to load checkpoint: {:?}", e),
+                        }
+                    });
+                }
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (challenge_id, attempt_repository);
+            || ()
+        });
+    }
+
+    let save_checkpoint = {
+        let attempt_repository = attempt_repository.clone();
+        let challenge_id = challenge_id.clone();
+        Callback::from(move |checkpoint: Option<ChallengeCheckpoint>| {
+            #[cfg(feature = "csr")]
+            {
+                if let Some(attempt_repository) = attempt_repository.clone() {
+                    let challenge_id = challenge_id.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let saved = match checkpoint {
+                            Some(checkpoint) => {
+                                attempt_repository
+                                    .save_checkpoint(&challenge_id, &checkpoint)
+                                    .await
+                            }
+                            None => attempt_repository.delete_checkpoint(&challenge_id).await,
+                        };
+                        if let Err(e) = saved {
+                            tracing::error!("Failed to save checkpoint: {:?}", e);
+                        }
+                    });
+                }
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (&attempt_repository, &challenge_id, checkpoint);
+        })
+    };
+
+    let handle_checkpoint = {
+        let hints = hints.clone();
+        let latest_result = latest_result.clone();
+        save_checkpoint.reform(move |mut checkpoint: ChallengeCheckpoint| {
+            *latest_result.borrow_mut() =
+                Some((checkpoint.task_index, checkpoint.challenge_result.clone()));
+            checkpoint.hints = (*hints).clone();
+            Some(checkpoint)
+        })
+    };
 
+    let handle_hint = {
+        let hints = hints.clone();
+        Callback::from(move |index: usize| {
+            let mut updated = (*hints).clone();
+            updated.take(index);
+            hints.set(updated);
+        })
+    };
+
+    let play_sound = use_sound_effect();
     let handle_event = {
         let on_event = props.on_event.clone();
+        let announcement = announcement.clone();
+        let practice_feedback = practice_feedback.clone();
+        let challenge = props.challenge.clone();
+        let i18n = i18n.clone();
         Callback::from(move |event: Event| {
+            let feedback = match &event {
+                Event::Challenge(ChallengeEvent::SolvedCorrect(index)) => {
+                    Some((*index, true, i18n.t("Correct")))
+                }
+                Event::Challenge(ChallengeEvent::SolvedIncorrect(index)) => {
+                    Some((*index, false, i18n.t("Incorrect")))
+                }
+                _ => None,
+            };
+            if let Some(effect) = SoundEffect::for_event(&event) {
+                play_sound.emit(effect);
+            }
+            if let Some((index, correct, feedback)) = feedback {
+                if practice {
+                    practice_feedback.set(Some(PracticeFeedback::new(&challenge, index, correct)));
+                }
+                announcement.set(format!(
+                    "{} {}: {}",
+                    i18n.t("Question"),
+                    index + 1,
+                    feedback
+                ));
+            }
             if let Some(on_event) = on_event.as_ref() {
                 on_event.emit(event);
             }
@@ -43,13 +254,130 @@ pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
 
     let handle_command = {
         let on_command = props.on_command.clone();
+        let challenge_result = challenge_result.clone();
+        let save_checkpoint = save_checkpoint.clone();
+        let practice_feedback = practice_feedback.clone();
+        let hints = hints.clone();
+        let on_hints = props.on_hints.clone();
+        let question = question.clone();
+        let attempt_repository = attempt_repository.clone();
+        let challenge = props.challenge.clone();
+        let dialog_graph = props.dialog_graph.clone();
         Callback::from(move |command: Command| {
+            if let Command::Challenge(ChallengeCommand::NextTask | ChallengeCommand::PreviousTask) =
+                &command
+            {
+                question.set(*question + 1);
+            }
+            if let Command::Challenge(ChallengeCommand::Finish(result)) = &command {
+                save_checkpoint.emit(None);
+                practice_feedback.set(None);
+                if let Some(result) = result {
+                    challenge_result.set(Some(result.clone()));
+                }
+                if practice {
+                    return;
+                }
+                let recorded = ChallengeHints::new(&challenge, (*hints).clone());
+                let outcome = match (&dialog_graph, result) {
+                    (Some(graph), Some(ChallengeResult::Dialog(answers))) => {
+                        DialogOutcome::new(&challenge, graph, answers)
+                    }
+                    _ => None,
+                };
+                #[cfg(feature = "csr")]
+                {
+                    if let Some(attempt_repository) = attempt_repository.clone() {
+                        wasm_bindgen_futures::spawn_local(async move {
+                            if !recorded.hints.is_empty()
+                                && let Err(e) = attempt_repository.add_hints(&recorded).await
+                            {
+                                tracing::error!("Failed to save hints: {:?}", e);
+                            }
+                            if let Some(outcome) = outcome
+                                && let Err(e) =
+                                    attempt_repository.add_dialog_outcome(&outcome).await
+                            {
+                                tracing::error!("Failed to save dialog outcome: {:?}", e);
+                            }
+                        });
+                    }
+                }
+                #[cfg(not(feature = "csr"))]
+                let _ = (&attempt_repository, recorded, outcome);
+                if let Some(on_hints) = on_hints.as_ref() {
+                    on_hints.emit((*hints).clone());
+                }
+            }
             if let Some(on_command) = on_command.as_ref() {
                 on_command.emit(command);
             }
         })
     };
 
+    let handle_timeout = {
+        let handle_command = handle_command.clone();
+        let latest_result = latest_result.clone();
+        let timed_out = timed_out.clone();
+        let attempt_repository = attempt_repository.clone();
+        let on_timeout = props.on_timeout.clone();
+        let challenge = props.challenge.clone();
+        let time_limit = props.time_limit;
+        Callback::from(move |_: ()| {
+            let latest = latest_result
+                .borrow()
+                .clone()
+                .or_else(|| empty_result(&challenge.challenge_type).map(|result| (0, result)));
+            let (Some(time_limit), Some((task_index, result))) = (time_limit, latest) else {
+                return;
+            };
+            let timeout = ChallengeTimeout {
+                challenge_id: challenge.challenge_config.id.clone(),
+                started_at: challenge.start_time,
+                timed_out_at: chrono::Utc::now(),
+                task_index,
+                time_limit,
+            };
+            timed_out.set(true);
+
+            #[cfg(feature = "csr")]
+            {
+                if let Some(attempt_repository) = attempt_repository.clone() {
+                    let timeout = timeout.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        if let Err(e) = attempt_repository.add_timeout(&timeout).await {
+                            tracing::error!("Failed to save timeout: {:?}", e);
+                        }
+                    });
+                }
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = &attempt_repository;
+
+            if let Some(on_timeout) = on_timeout.as_ref() {
+                on_timeout.emit(timeout);
+            }
+            handle_command.emit(Command::Challenge(ChallengeCommand::Finish(Some(result))));
+        })
+    };
+
+    let countdown = match props.time_limit {
+        Some(time_limit)
+            if challenge_result.is_none()
+                && saved_checkpoint.is_none()
+                && empty_result(&props.challenge.challenge_type).is_some() =>
+        {
+            let key = match time_limit.scope {
+                TimeLimitScope::Challenge => format!("{}", *attempt),
+                TimeLimitScope::Question => format!("{}-{}", *attempt, *question),
+            };
+            html! {
+                <CountdownComponent key={key} {time_limit} on_timeout={handle_timeout} />
+            }
+        }
+        _ => html! {},
+    };
+
     let challenge_component = match (
         &*challenge_result,
         &props.challenge.challenge_type,
@@ -57,9 +385,15 @@ pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
     ) {
         (None, ChallengeType::MultipleChoice(challenge), ChallengeVariant::MultipleChoice) => {
             html! {
-                <MultipleChoiceComponent challenge={challenge.clone()}
-                on_event={handle_event}
-               on_command={handle_command} />
+                <MultipleChoiceComponent
+                    challenge={challenge.clone()}
+                    on_event={handle_event}
+                    on_command={handle_command}
+                    checkpoint={(*checkpoint).clone()}
+                    on_checkpoint={handle_checkpoint.clone()}
+                    hints={(*hints).clone()}
+                    on_hint={handle_hint.clone()}
+                />
             }
         }
         (
@@ -67,31 +401,76 @@ pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
             ChallengeType::MultipleChoice(challenge),
             ChallengeVariant::MultipleChoiceCircle,
         ) => html! {
-            <MultipleChoiceCircleComponent challenge={challenge.clone()}
+            <MultipleChoiceCircleComponent
+                challenge={challenge.clone()}
                 on_event={handle_event}
-               on_command={handle_command} />
+                on_command={handle_command}
+                checkpoint={(*checkpoint).clone()}
+                on_checkpoint={handle_checkpoint.clone()}
+                hints={(*hints).clone()}
+                on_hint={handle_hint.clone()}
+            />
         },
         (None, ChallengeType::MultipleChoice(challenge), ChallengeVariant::MultipleChoice4) => {
             html! {
-                <MultipleChoice4Component challenge={challenge.clone()}
+                <MultipleChoice4Component
+                    challenge={challenge.clone()}
                     on_event={handle_event}
-                    on_command={handle_command} />
+                    on_command={handle_command}
+                    checkpoint={(*checkpoint).clone()}
+                    on_checkpoint={handle_checkpoint.clone()}
+                    hints={(*hints).clone()}
+                    on_hint={handle_hint.clone()}
+                    seed={seed}
+                />
             }
         }
         (None, ChallengeType::ContextualChoice(challenge), ChallengeVariant::ContextualChoice) => {
             html! {
-                <ContextualChoiceComponent challenge={challenge.clone()}
-                on_event={handle_event}
-               on_command={handle_command} />
+                <ContextualChoiceComponent
+                    challenge={challenge.clone()}
+                    on_event={handle_event}
+                    on_command={handle_command}
+                    checkpoint={(*checkpoint).clone()}
+                    on_checkpoint={handle_checkpoint.clone()}
+                />
             }
         }
         (None, ChallengeType::GapFill(challenge), _) => html! {
-            <GapFillComponent challenge={challenge.clone()} on_event={handle_event}
-           on_command={handle_command} />
+            <GapFillComponent
+                challenge={challenge.clone()}
+                on_event={handle_event}
+                on_command={handle_command}
+                checkpoint={(*checkpoint).clone()}
+                on_checkpoint={handle_checkpoint.clone()}
+                typed={props.typed_answers}
+                hints={(*hints).clone()}
+                on_hint={handle_hint.clone()}
+            />
         },
+        (None, ChallengeType::SortTable(challenge), ChallengeVariant::SortTable)
+            if is_matching_table(challenge) =>
+        {
+            html! {
+                <MatchingComponent
+                    challenge={challenge.clone()}
+                    on_event={handle_event}
+                    on_command={handle_command}
+                    checkpoint={(*checkpoint).clone()}
+                    on_checkpoint={handle_checkpoint.clone()}
+                    seed={seed}
+                />
+            }
+        }
         (None, ChallengeType::SortTable(challenge), ChallengeVariant::SortTable) => html! {
-            <SortTableComponent challenge={challenge.clone()} on_event={handle_event}
-           on_command={handle_command} />
+            <SortTableComponent
+                challenge={challenge.clone()}
+                on_event={handle_event}
+                on_command={handle_command}
+                checkpoint={(*checkpoint).clone()}
+                on_checkpoint={handle_checkpoint.clone()}
+                seed={seed}
+            />
         },
         (None, ChallengeType::Informative(challenge), ChallengeVariant::InformativeText) => html! {
             <InformativeComponent challenge={challenge.clone()} on_command={handle_command} language={props.language.clone()} />
@@ -102,8 +481,16 @@ pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
             }
         }
         (None, ChallengeType::Ordering(challenge), _) => html! {
-            <OrderingComponent challenge={challenge.clone()} on_event={handle_event}
-           on_command={handle_command} />
+            <OrderingComponent
+                challenge={challenge.clone()}
+                on_event={handle_event}
+                on_command={handle_command}
+                checkpoint={(*checkpoint).clone()}
+                on_checkpoint={handle_checkpoint.clone()}
+                hints={(*hints).clone()}
+                on_hint={handle_hint.clone()}
+                seed={seed}
+            />
         },
         (None, ChallengeType::Custom(challenge), ChallengeVariant::Custom) => html! {
             <CustomComponent challenge={challenge.clone()} on_event={handle_event}
@@ -117,7 +504,13 @@ pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
             <PlaceholderComponent challenge={challenge.clone()} on_command={handle_command} language={props.language.clone()} />
         },
         (None, ChallengeType::Vocabulary(challenge), _) => html! {
-            <VocabularyComponent challenge={challenge.clone()} on_command={handle_command} />
+            <VocabularyComponent
+                challenge={challenge.clone()}
+                on_event={handle_event}
+                on_command={handle_command}
+                seed={seed}
+                audio={props.vocabulary_audio.clone()}
+            />
         },
         (None, ChallengeType::Dialog(challenge), ChallengeVariant::DialogObserver) => html! {
             <DialogComponent
@@ -125,6 +518,8 @@ pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
                 on_event={handle_event}
                 on_command={handle_command}
                 quiz_mode={false}
+                read_aloud={props.read_aloud}
+                graph={props.dialog_graph.clone()}
             />
         },
         (None, ChallengeType::Dialog(challenge), ChallengeVariant::DialogQuiz) => html! {
@@ -133,6 +528,8 @@ pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
                 on_event={handle_event}
                 on_command={handle_command}
                 quiz_mode={true}
+                listen_first={props.read_aloud}
+                graph={props.dialog_graph.clone()}
             />
         },
         _ => html! {},
@@ -140,16 +537,33 @@ pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
 
     let challenge_result_component = match (&*challenge_result, &props.challenge.challenge_type) {
         (Some(result), ChallengeType::MultipleChoice(challenge)) => html! {
-            <MultipleChoiceResultComponent challenge={challenge.clone()} challenge_result={result.clone()} />
+            <MultipleChoiceResultComponent challenge={challenge.clone()} challenge_result={result.clone()} hints={(*hints).clone()} />
         },
         (Some(result), ChallengeType::ContextualChoice(challenge)) => html! {
             <ContextualChoiceResultComponent challenge={challenge.clone()} challenge_result={result.clone()} />
         },
         (Some(result), ChallengeType::GapFill(challenge)) => html! {
-            <GapFillResultComponent challenge={challenge.clone()} challenge_result={result.clone()} />
+            <GapFillResultComponent challenge={challenge.clone()} challenge_result={result.clone()} hints={(*hints).clone()} />
         },
         (Some(result), ChallengeType::Ordering(challenge)) => html! {
-            <OrderingResultComponent challenge={challenge.clone()} challenge_result={result.clone()} />
+            <OrderingResultComponent challenge={challenge.clone()} challenge_result={result.clone()} hints={(*hints).clone()} />
+        },
+        (Some(result), ChallengeType::SortTable(challenge)) if is_matching_table(challenge) => {
+            html! {
+                <MatchingResultComponent challenge={challenge.clone()} challenge_result={result.clone()} />
+            }
+        }
+        (Some(result), ChallengeType::SortTable(challenge)) => html! {
+            <SortTableResultComponent challenge={challenge.clone()} challenge_result={result.clone()} />
+        },
+        (Some(ChallengeResult::Custom(result)), ChallengeType::Custom(challenge)) => html! {
+            <CustomResultComponent challenge={challenge.clone()} result={result.clone()} />
+        },
+        (Some(_), ChallengeType::Informative(_)) => html! {
+            <InformativeResultComponent />
+        },
+        (Some(result), ChallengeType::Vocabulary(challenge)) => html! {
+            <VocabularyResultComponent challenge={challenge.clone()} challenge_result={result.clone()} />
         },
         (Some(_), ChallengeType::Dialog(challenge))
             if props.variant == Some(ChallengeVariant::DialogObserver) =>
@@ -159,11 +573,82 @@ pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
             }
         }
         (Some(result), ChallengeType::Dialog(challenge)) => html! {
-            <DialogResultComponent challenge={challenge.clone()} challenge_result={result.clone()} variant={props.variant.clone()} />
+            <DialogResultComponent challenge={challenge.clone()} challenge_result={result.clone()} variant={props.variant.clone()} graph={props.dialog_graph.clone()} />
         },
         _ => html! {},
     };
 
+    let result_actions = match &*challenge_result {
+        Some(result) => {
+            let on_retry = {
+                let challenge_result = challenge_result.clone();
+                let attempt = attempt.clone();
+                let checkpoint = checkpoint.clone();
+                let practice_feedback = practice_feedback.clone();
+                let hints = hints.clone();
+                let latest_result = latest_result.clone();
+                let timed_out = timed_out.clone();
+                Callback::from(move |_| {
+                    challenge_result.set(None);
+                    checkpoint.set(None);
+                    practice_feedback.set(None);
+                    hints.set(HintUsage::default());
+                    *latest_result.borrow_mut() = None;
+                    timed_out.set(false);
+                    attempt.set(*attempt + 1);
+                })
+            };
+            let on_review_mistakes = mistakes_challenge(&props.challenge, result).map(|review| {
+                let mistake_review = mistake_review.clone();
+                let on_review_mistakes = props.on_review_mistakes.clone();
+                let result = result.clone();
+                Callback::from(move |_| {
+                    mistake_review.set(Some(review.clone()));
+                    if let Some(on_review_mistakes) = on_review_mistakes.as_ref() {
+                        on_review_mistakes.emit(result.clone());
+                    }
+                })
+            });
+            html! {
+                <ResultActionsComponent {on_retry} {on_review_mistakes} />
+            }
+        }
+        None => html! {},
+    };
+
+    let resume_prompt = match &*saved_checkpoint {
+        Some(saved) => {
+            let on_continue = {
+                let saved_checkpoint = saved_checkpoint.clone();
+                let checkpoint = checkpoint.clone();
+                let attempt = attempt.clone();
+                let hints = hints.clone();
+                let latest_result = latest_result.clone();
+                let saved = saved.clone();
+                Callback::from(move |_| {
+                    hints.set(saved.hints.clone());
+                    *latest_result.borrow_mut() =
+                        Some((saved.task_index, saved.challenge_result.clone()));
+                    checkpoint.set(Some(saved.clone()));
+                    saved_checkpoint.set(None);
+                    attempt.set(*attempt + 1);
+                })
+            };
+            let on_restart = {
+                let saved_checkpoint = saved_checkpoint.clone();
+                let save_checkpoint = save_checkpoint.clone();
+                Callback::from(move |_| {
+                    save_checkpoint.emit(None);
+                    saved_checkpoint.set(None);
+                })
+            };
+            html! {
+                <ResumePromptComponent task_index={saved.task_index} {on_continue} {on_restart} />
+            }
+        }
+        None => html! {},
+    };
+
     let challenge_header = {
         html! {
             <div class="challenge__header">
@@ -171,16 +656,46 @@ pub fn challenge_component(props: &ChallengeComponentProps) -> Html {
                     challenge_config={props.challenge.challenge_config.clone()}
                     open={!props.preview}
                 />
+                if practice {
+                    <span class="challenge__practice-badge">{ i18n.t("Practice") }</span>
+                }
                 <ChallengeTimerComponent challenge={props.challenge.clone()} running={true} />
+                {countdown}
             </div>
         }
     };
 
+    if let Some(review) = &*mistake_review {
+        let on_close = {
+            let mistake_review = mistake_review.clone();
+            Callback::from(move |_| mistake_review.set(None))
+        };
+        return html! {
+            <div class="challenge">
+                <MistakeReviewComponent challenge={review.clone()} variant={props.variant.clone()} {on_close} preview={props.preview} />
+            </div>
+        };
+    }
+
     html! {
         <div class="challenge">
             {challenge_header}
-            {challenge_component}
+            if saved_checkpoint.is_some() {
+                {resume_prompt}
+            } else {
+                <div class="challenge__content" key={*attempt}>
+                    {challenge_component}
+                </div>
+            }
+            if *timed_out {
+                <p class="challenge__timed-out" role="status">{ i18n.t("Time is up") }</p>
+            }
+            if let Some(feedback) = &*practice_feedback {
+                <PracticeFeedbackComponent feedback={feedback.clone()} />
+            }
             {challenge_result_component}
+            {result_actions}
+            <LiveRegion message={(*announcement).clone()} />
         </div>
     }
 }
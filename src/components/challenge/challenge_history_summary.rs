@@ -1,6 +1,8 @@
 use crate::i18n::use_i18n;
+use crate::model::AttemptRecords;
+use crate::providers::{use_attempt_records, use_challenge_timeouts};
 use konnektoren_core::challenges::ChallengeHistory;
-use konnektoren_core::prelude::{Challenge, Performance};
+use konnektoren_core::prelude::Challenge;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -56,6 +58,8 @@ pub struct ChallengeTableProps {
 #[function_component(ChallengeTable)]
 fn challenge_table(props: &ChallengeTableProps) -> Html {
     let i18n = use_i18n();
+    let records = use_attempt_records(props.challenge_history.challenges.len());
+    let timeouts = use_challenge_timeouts(props.challenge_history.challenges.len());
     html! {
         <table class="challenge-history__table">
             <thead class="challenge-history__table-head">
@@ -65,7 +69,10 @@ fn challenge_table(props: &ChallengeTableProps) -> Html {
                 </tr>
             </thead>
             <tbody>
-                { for props.challenge_history.challenges.iter().map(|c| html!{ <ChallengeRow challenge={c.clone()} /> }) }
+                { for props.challenge_history.challenges.iter().map(|c| {
+                    let timed_out = timeouts.iter().any(|timeout| timeout.matches(c));
+                    html!{ <ChallengeRow challenge={c.clone()} records={records.clone()} {timed_out} /> }
+                }) }
             </tbody>
         </table>
     }
@@ -74,6 +81,12 @@ fn challenge_table(props: &ChallengeTableProps) -> Html {
 #[derive(Properties, PartialEq)]
 pub struct ChallengeRowProps {
     pub challenge: Challenge,
+    /// Hints and dialog outcomes the performance shown is scored with.
+    #[prop_or_default]
+    pub records: AttemptRecords,
+    /// The attempt ran out of time rather than being answered wrong.
+    #[prop_or(false)]
+    pub timed_out: bool,
 }
 
 #[function_component(ChallengeRow)]
@@ -82,7 +95,12 @@ fn challenge_row(props: &ChallengeRowProps) -> Html {
     html! {
         <tr class="challenge-history__table-row">
             <td class="challenge-history__table-cell">{ i18n.t(&props.challenge.challenge_type.name()) }</td>
-            <td class="challenge-history__table-cell">{ props.challenge.performance(&props.challenge.challenge_result) }</td>
+            <td class="challenge-history__table-cell">
+                { props.records.performance(&props.challenge) }
+                if props.timed_out {
+                    <span class="challenge-history__timeout">{ i18n.t("Time is up") }</span>
+                }
+            </td>
         </tr>
     }
 }
@@ -51,7 +51,7 @@ pub fn challenge_info_block(props: &ChallengeInfoBlockProps) -> Html {
                         <span class="challenge-block__detail-value">{ i18n.t(&challenge.challenge) }</span>
                     </div>
                     <div class="challenge-block__detail">
-                        <span class="challenge-block__detail-label">{ i18n.t("Tasks:") }</span>
+                        <span class="challenge-block__detail-label">{ format!("{}:", i18n.t("Tasks")) }</span>
                         <span class="challenge-block__detail-value">{ format!("{:?}", challenge.tasks) }</span>
                     </div>
                     if let Some(variant) = &challenge.variant {
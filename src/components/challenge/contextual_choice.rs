@@ -1,6 +1,10 @@
-use super::{ChallengeActions, ChallengeActionsComponent, ChallengeHelpComponent};
-use crate::components::ProgressBar;
+use super::{
+    ChallengeActions, ChallengeActionsComponent, ChallengeHelpComponent, ChallengeKey,
+    on_challenge_keydown,
+};
+use crate::components::{ProgressBar, use_focus_on_change};
 use crate::i18n::use_i18n;
+use crate::model::ChallengeCheckpoint;
 use konnektoren_core::challenges::{ChallengeInput, ChallengeResult, ContextualChoice};
 use konnektoren_core::commands::{ChallengeCommand, Command};
 use konnektoren_core::events::{ChallengeEvent, Event};
@@ -14,12 +18,29 @@ pub struct ContextualChoiceComponentProps {
     pub on_command: Option<Callback<Command>>,
     #[prop_or_default]
     pub on_event: Option<Callback<Event>>,
+    /// Saved progress to continue from.
+    #[prop_or_default]
+    pub checkpoint: Option<ChallengeCheckpoint>,
+    #[prop_or_default]
+    pub on_checkpoint: Option<Callback<ChallengeCheckpoint>>,
 }
 
 #[function_component(ContextualChoiceComponent)]
 pub fn contextual_choice_component(props: &ContextualChoiceComponentProps) -> Html {
-    let item_index = use_state(|| 0);
-    let challenge_result = use_state(ChallengeResult::default);
+    let item_index = use_state(|| {
+        props.checkpoint.as_ref().map_or(0, |checkpoint| {
+            checkpoint.resume_index(props.challenge.items.len())
+        })
+    });
+    let container_ref = use_node_ref();
+    use_focus_on_change(container_ref.clone(), *item_index);
+    let challenge_result = use_state(|| {
+        props
+            .checkpoint
+            .as_ref()
+            .map(|checkpoint| checkpoint.challenge_result.clone())
+            .unwrap_or_else(ChallengeResult::default)
+    });
     let show_help = use_state(|| false);
     let selections = use_state(HashMap::new);
 
@@ -35,6 +56,7 @@ pub fn contextual_choice_component(props: &ContextualChoiceComponentProps) -> Ht
         let challenge_result = challenge_result.clone();
         let on_command = props.on_command.clone();
         let on_event = props.on_event.clone();
+        let on_checkpoint = props.on_checkpoint.clone();
         let selections = selections.clone();
 
         let deps = EffectDeps {
@@ -90,6 +112,13 @@ pub fn contextual_choice_component(props: &ContextualChoiceComponentProps) -> Ht
                         selections.set(new_selections);
                         item_index.set(next_idx);
 
+                        if let Some(on_checkpoint) = &on_checkpoint {
+                            on_checkpoint.emit(ChallengeCheckpoint::new(
+                                next_idx,
+                                (*challenge_result).clone(),
+                            ));
+                        }
+
                         if let Some(cmd) = &on_command {
                             cmd.emit(Command::Challenge(ChallengeCommand::NextTask));
                         }
@@ -161,8 +190,38 @@ pub fn contextual_choice_component(props: &ContextualChoiceComponentProps) -> Ht
 
     let current_item = &props.challenge.items[*item_index];
 
+    let handle_key = {
+        let option_counts: Vec<usize> = current_item
+            .choices
+            .iter()
+            .map(|choice| choice.options.len())
+            .collect();
+        let item_index = *item_index;
+        let selections = selections.clone();
+        let handle_option_selection = handle_option_selection.clone();
+        let handle_action = handle_action.clone();
+
+        on_challenge_keydown(Callback::from(move |key: ChallengeKey| match key {
+            // Number keys fill the first open choice of the current item
+            ChallengeKey::Choose(option_index) => {
+                let open_choice = (0..option_counts.len())
+                    .find(|choice_index| !selections.contains_key(&(item_index, *choice_index)));
+                if let Some(choice_index) = open_choice
+                    && option_index < option_counts[choice_index]
+                {
+                    handle_option_selection.emit((choice_index, option_index));
+                }
+            }
+            key => {
+                if let Some(action) = key.action() {
+                    handle_action.emit(action);
+                }
+            }
+        }))
+    };
+
     html! {
-        <div class="contextual-choice" key={component_key}>
+        <div class="contextual-choice" key={component_key} ref={container_ref} tabindex="0" onkeydown={handle_key}>
             <ContextualChoiceProgress
                 item_index={*item_index}
                 total_items={props.challenge.items.len()}
@@ -346,6 +405,7 @@ fn contextual_choice_select(props: &ContextualChoiceSelectProps) -> Html {
             id={select_id.clone()}
             class="contextual-choice__select"
             key={select_id.clone()}
+            aria-label={format!("{} {}", i18n.t("Choice"), props.choice_index + 1)}
             onchange={onchange}
         >
             <option value="" disabled=true selected={selected_value.is_none()}>
@@ -380,6 +440,7 @@ fn create_action_handler(
         }
         ChallengeActions::Previous => handle_previous_action(&item_index, &on_command, &selections),
         ChallengeActions::Help => show_help.set(!*show_help),
+        ChallengeActions::Hint => {}
     })
 }
 
@@ -475,6 +536,8 @@ mod preview {
             },
             on_command: None,
             on_event: None,
+            checkpoint: None,
+            on_checkpoint: None,
         },
         (
             "ContextualChoiceComponentMixed",
@@ -519,6 +582,8 @@ mod preview {
                 },
                 on_command: None,
                 on_event: None,
+                checkpoint: None,
+                on_checkpoint: None,
             }
         ),
         (
@@ -564,6 +629,8 @@ mod preview {
                 },
                 on_command: None,
                 on_event: None,
+                checkpoint: None,
+                on_checkpoint: None,
             }
         ),
         (
@@ -602,6 +669,8 @@ mod preview {
                 },
                 on_command: None,
                 on_event: None,
+                checkpoint: None,
+                on_checkpoint: None,
             }
         )
     );
@@ -1,3 +1,5 @@
+use super::{SandboxFrame, challenge_translations};
+use crate::i18n::SelectedLanguage;
 use konnektoren_core::challenges::Custom;
 use konnektoren_core::commands::Command;
 use konnektoren_core::events::Event;
@@ -6,12 +8,15 @@ use yew::prelude::*;
 #[derive(Properties, Clone, PartialEq)]
 pub struct CustomComponentProps {
     pub challenge: Custom,
+    /// Called with the answers the challenge reports through `sendEvent`.
     #[prop_or_default]
     pub on_event: Option<Callback<Event>>,
     #[prop_or_default]
     pub on_command: Option<Callback<Command>>,
 }
 
+/// Fetches the HTML, CSS, JS and translations of a custom challenge and runs
+/// them in a [`SandboxFrame`].
 #[function_component(CustomComponent)]
 pub fn custom_component(props: &CustomComponentProps) -> Html {
     let html_content = use_state(|| "".to_string());
@@ -20,17 +25,6 @@ pub fn custom_component(props: &CustomComponentProps) -> Html {
     let i18n_content = use_state(|| "".to_string());
     let loading = use_state(|| true);
 
-    #[cfg(feature = "csr")]
-    let konnektoren_js = {
-        use konnektoren_core::konnektoren_js::KonnektorenJs;
-        use web_sys::window;
-
-        use_mut_ref(|| {
-            let window = window().expect("no global `window` exists");
-            KonnektorenJs::new(&window)
-        })
-    };
-
     // Effect to fetch content when the challenge changes
     {
         let html_content = html_content.clone();
@@ -55,93 +49,34 @@ pub fn custom_component(props: &CustomComponentProps) -> Html {
                     loading.set(false);
                 });
             }
+            #[cfg(not(feature = "csr"))]
+            let _ = (html_content, css_content, js_content, i18n_content, loading);
             || ()
         });
     }
 
-    // Effect to set up the sendEvent and command callback once on mount
-    #[cfg(feature = "csr")]
-    {
-        let konnektoren_js = konnektoren_js.clone();
-        let on_event = props.on_event.clone();
-        let on_command = props.on_command.clone();
-
-        use_effect(move || {
-            use wasm_bindgen::JsValue;
-
-            let on_event = on_event.clone();
-            let on_command = on_command.clone();
-            konnektoren_js
-                .borrow_mut()
-                .expose_send_event(move |event: JsValue| {
-                    if let Some(on_event_callback) = &on_event {
-                        let event: Event = event.try_into().unwrap();
-                        on_event_callback.emit(event);
-                    }
-                });
-            konnektoren_js
-                .borrow_mut()
-                .expose_execute_command(move |command: JsValue| {
-                    if let Some(on_command_callback) = &on_command {
-                        let command: Command = command.try_into().unwrap();
-                        on_command_callback.emit(command);
-                    }
-                });
-            || ()
-        });
-    }
-
-    // Effect to process the loaded content after loading is complete
-    #[cfg(feature = "csr")]
-    {
-        use crate::i18n::I18nLoader;
-        use crate::prelude::I18nYmlLoader;
-        use crate::prelude::SelectedLanguage;
-        let konnektoren_js = konnektoren_js.clone();
-        let challenge = props.challenge.clone();
-        let js_code = (*js_content).clone();
-        let i18n_content = (*i18n_content).clone();
-        let loading = *loading;
-
-        use_effect_with(
-            (
-                loading,
-                challenge.clone(),
-                js_code.clone(),
-                i18n_content.clone(),
-            ),
-            move |(loading, challenge, js_code, i18n_content)| {
-                if !*loading {
-                    // Set challenge data
-                    konnektoren_js
-                        .borrow_mut()
-                        .set_challenge_data(challenge.clone());
-
-                    // Set i18n data if available
-                    if !i18n_content.is_empty() {
-                        let language = SelectedLanguage::default().get();
-                        let loader = I18nYmlLoader::new(i18n_content);
-                        let translations = loader.get(language.code()).unwrap_or_default();
-                        konnektoren_js.borrow_mut().set_i18n_data(translations);
-                    }
-
-                    // Execute JS code
-                    konnektoren_js.borrow_mut().execute_js(js_code);
-                }
-                || ()
-            },
-        );
+    if *loading {
+        return html! { <div class="custom-challenge" /> };
     }
 
-    // Render the HTML content
-    let parsed_html = Html::from_html_unchecked(AttrValue::from((*html_content).clone()));
+    let i18n = if i18n_content.is_empty() {
+        serde_json::Value::default()
+    } else {
+        let language = SelectedLanguage::default().get();
+        challenge_translations(&i18n_content, language.code())
+    };
 
     html! {
         <div class="custom-challenge">
-            <style>
-                {(*css_content).clone()}
-            </style>
-            {parsed_html}
+            <SandboxFrame
+                challenge={props.challenge.clone()}
+                html={(*html_content).clone()}
+                css={(*css_content).clone()}
+                js={(*js_content).clone()}
+                {i18n}
+                on_command={props.on_command.clone()}
+                on_event={props.on_event.clone()}
+            />
         </div>
     }
 }
@@ -8,32 +8,35 @@ pub struct CustomPackageComponentProps {
     pub challenge: Custom,
     #[prop_or_default]
     pub on_command: Option<Callback<Command>>,
+    /// Called with the answers the challenge reports through `sendEvent`.
     #[prop_or_default]
     pub on_event: Option<Callback<Event>>,
 }
 
+/// Downloads a challenge package through the content cache, so it also
+/// starts offline once loaded, and runs its HTML, CSS and JS in a
+/// [`SandboxFrame`](super::SandboxFrame).
 #[function_component(CustomPackageComponent)]
 pub fn custom_package_component(props: &CustomPackageComponentProps) -> Html {
     #[cfg(feature = "csr")]
     {
-        use konnektoren_core::konnektoren_js::KonnektorenJs;
-        use wasm_bindgen::JsValue;
-        use web_sys::window;
+        use super::{SandboxFrame, challenge_translations};
+        use crate::components::CacheStatusComponent;
+        use crate::i18n::SelectedLanguage;
+        use crate::model::{CacheStatus, ContentKind};
+        use crate::providers::RepositoryContext;
+        use crate::tools::fetch_cached;
 
         let package = use_state(|| None::<Package>);
+        let cache_status = use_state(|| None::<CacheStatus>);
         let loading = use_state(|| true);
         let error = use_state(|| None::<String>);
+        let repository = use_context::<RepositoryContext>().map(|c| c.content_cache_repository);
 
-        let konnektoren_js = {
-            use_mut_ref(|| {
-                let window = window().expect("no global `window` exists");
-                KonnektorenJs::new(&window)
-            })
-        };
-
-        // Effect to load the package
+        // Effect to load the package, from the content cache when offline
         {
             let package = package.clone();
+            let cache_status = cache_status.clone();
             let challenge = props.challenge.clone();
             let loading = loading.clone();
             let error = error.clone();
@@ -43,19 +46,22 @@ pub fn custom_package_component(props: &CustomPackageComponentProps) -> Html {
                     let package_url = package_url.clone();
 
                     wasm_bindgen_futures::spawn_local(async move {
-                        match PackageReader::download(&package_url).await {
-                            Ok(package_data) => match PackageReader::read(&package_data) {
-                                Ok(loaded_package) => {
-                                    package.set(Some(loaded_package));
-                                    loading.set(false);
+                        match fetch_cached(repository, &package_url, ContentKind::Package).await {
+                            Ok((package_data, status)) => {
+                                match PackageReader::read(&package_data) {
+                                    Ok(loaded_package) => {
+                                        package.set(Some(loaded_package));
+                                        cache_status.set(Some(status));
+                                        loading.set(false);
+                                    }
+                                    Err(e) => {
+                                        let err_msg = format!("Failed to read package: {}", e);
+                                        tracing::error!("{}", err_msg);
+                                        error.set(Some(err_msg));
+                                        loading.set(false);
+                                    }
                                 }
-                                Err(e) => {
-                                    let err_msg = format!("Failed to read package: {}", e);
-                                    tracing::error!("{}", err_msg);
-                                    error.set(Some(err_msg));
-                                    loading.set(false);
-                                }
-                            },
+                            }
                             Err(e) => {
                                 let err_msg = format!(
                                     "Failed to download package from {}: {}",
@@ -76,80 +82,7 @@ pub fn custom_package_component(props: &CustomPackageComponentProps) -> Html {
             });
         }
 
-        // Effect to set up the sendEvent callback once on mount
-        {
-            let konnektoren_js = konnektoren_js.clone();
-            let on_event = props.on_event.clone();
-            let on_command = props.on_command.clone();
-
-            use_effect(move || {
-                let on_event = on_event.clone();
-                let on_command = on_command.clone();
-                konnektoren_js
-                    .borrow_mut()
-                    .expose_send_event(move |event: JsValue| {
-                        if let Some(on_event_callback) = &on_event {
-                            let event: Event = event.try_into().unwrap();
-                            on_event_callback.emit(event);
-                        }
-                    });
-                konnektoren_js
-                    .borrow_mut()
-                    .expose_execute_command(move |command: JsValue| {
-                        if let Some(on_command_callback) = &on_command {
-                            let command: Command = command.try_into().unwrap();
-                            on_command_callback.emit(command);
-                        }
-                    });
-
-                || ()
-            });
-        }
-
-        // Effect to process the loaded package after loading is complete
-        {
-            use crate::i18n::I18nLoader;
-            use crate::prelude::I18nYmlLoader;
-            use crate::prelude::SelectedLanguage;
-
-            let konnektoren_js = konnektoren_js.clone();
-            let package = package.clone();
-            let loading = loading.clone();
-
-            use_effect_with(
-                (loading.clone(), package.clone()),
-                move |(loading, package)| {
-                    if !**loading {
-                        if let Some(loaded_package) = &**package {
-                            // Set challenge data
-                            if let Some(custom_challenge) = loaded_package.get_custom_challenge() {
-                                konnektoren_js
-                                    .borrow_mut()
-                                    .set_challenge_data(custom_challenge);
-                            }
-
-                            // Set i18n data if available
-                            if let Some(i18n_content) =
-                                loaded_package.get_file_as_string("i18n.yml")
-                            {
-                                let language = SelectedLanguage::default().get();
-                                let loader = I18nYmlLoader::new(i18n_content.as_str());
-                                let translations = loader.get(language.code()).unwrap_or_default();
-                                konnektoren_js.borrow_mut().set_i18n_data(translations);
-                            }
-
-                            // Execute JS code
-                            if let Some(js_content) = loaded_package.get_js_file() {
-                                konnektoren_js.borrow_mut().execute_js(js_content.as_str());
-                            }
-                        }
-                    }
-                    || ()
-                },
-            );
-        }
-
-        // Render the HTML content
+        // Render the package in a sandboxed frame
         return html! {
             <div class="custom-package-challenge">
                 if *loading {
@@ -160,15 +93,27 @@ pub fn custom_package_component(props: &CustomPackageComponentProps) -> Html {
                         <p>{err}</p>
                     </div>
                 } else if let Some(loaded_package) = &*package {
+                    if let Some(status) = *cache_status {
+                        <CacheStatusComponent {status} />
+                    }
                     if let Some(html_content) = loaded_package.get_html_file() {
-                        <div>
-                            if let Some(css_content) = loaded_package.get_css_file() {
-                                <style>
-                                    {css_content}
-                                </style>
-                            }
-                            {Html::from_html_unchecked(AttrValue::from(html_content))}
-                        </div>
+                        <SandboxFrame
+                            challenge={loaded_package
+                                .get_custom_challenge()
+                                .unwrap_or_else(|| props.challenge.clone())}
+                            html={html_content}
+                            css={loaded_package.get_css_file().unwrap_or_default()}
+                            js={loaded_package.get_js_file().unwrap_or_default()}
+                            i18n={loaded_package
+                                .get_file_as_string("i18n.yml")
+                                .map(|yml| {
+                                    let language = SelectedLanguage::default().get();
+                                    challenge_translations(&yml, language.code())
+                                })
+                                .unwrap_or_default()}
+                            on_command={props.on_command.clone()}
+                            on_event={props.on_event.clone()}
+                        />
                     } else {
                         <p>{"Error: HTML content not found in package"}</p>
                     }
@@ -1,17 +1,16 @@
+use super::{SandboxFrame, challenge_translations};
+use crate::i18n::SelectedLanguage;
 use konnektoren_core::challenges::{Custom, CustomChallengeResult};
 use yew::prelude::*;
 
-#[cfg(feature = "csr")]
-use crate::i18n::I18nLoader;
-#[cfg(feature = "csr")]
-use crate::prelude::I18nYmlLoader;
-
 #[derive(Properties, Clone, PartialEq)]
 pub struct CustomResultComponentProps {
     pub challenge: Custom,
     pub result: CustomChallengeResult,
 }
 
+/// Runs the results page of a custom challenge in a [`SandboxFrame`], which
+/// gets the result alongside the challenge data.
 #[function_component(CustomResultComponent)]
 pub fn custom_result(props: &CustomResultComponentProps) -> Html {
     if props.challenge.results_html.is_none() {
@@ -28,17 +27,6 @@ pub fn custom_result(props: &CustomResultComponentProps) -> Html {
     let i18n_content = use_state(|| "".to_string());
     let loading = use_state(|| true);
 
-    #[cfg(feature = "csr")]
-    let konnektoren_js = {
-        use konnektoren_core::konnektoren_js::KonnektorenJs;
-        use wasm_bindgen::JsCast;
-
-        use_mut_ref(|| {
-            let window = web_sys::window().expect("no global `window` exists");
-            KonnektorenJs::new(&window)
-        })
-    };
-
     // Effect to fetch content when the challenge changes
     {
         let html_content = html_content.clone();
@@ -66,60 +54,40 @@ pub fn custom_result(props: &CustomResultComponentProps) -> Html {
                     loading.set(false);
                 });
             }
+            #[cfg(not(feature = "csr"))]
+            let _ = (
+                html_content,
+                css_content,
+                js_content,
+                i18n_content,
+                challenge,
+                loading,
+            );
             || ()
         });
     }
 
-    // Effect to set up i18n data
-    {
-        #[cfg(feature = "csr")]
-        {
-            use crate::prelude::SelectedLanguage;
-            let konnektoren_js = konnektoren_js.clone();
-            let i18n_content = i18n_content.clone();
-
-            use_effect_with((*i18n_content).clone(), move |content| {
-                if !content.is_empty() {
-                    let language = SelectedLanguage::default().get();
-                    let loader = I18nYmlLoader::new(content);
-                    let translations = loader.get(&language.code()).unwrap_or_default();
-                    konnektoren_js.borrow_mut().set_i18n_data(translations);
-                }
-                || ()
-            });
-        }
+    if *loading {
+        return html! { <div class="custom-result" /> };
     }
 
-    // Effect to set challenge data, result data, and execute JS
-    {
-        #[cfg(feature = "csr")]
-        {
-            let konnektoren_js = konnektoren_js.clone();
-            let challenge = props.challenge.clone();
-            let result = props.result.clone();
-            let js_code = (*js_content).clone();
-            let loading = loading.clone();
-
-            use_effect_with(((*loading), js_code.clone()), move |_| {
-                if !*loading {
-                    let js = konnektoren_js.borrow_mut();
-                    js.set_challenge_data(challenge);
-                    js.set_result_data(result);
-                    js.execute_js(&js_code);
-                }
-                || ()
-            });
-        }
-    }
-
-    let parsed_html = Html::from_html_unchecked(AttrValue::from((*html_content).clone()));
+    let i18n = if i18n_content.is_empty() {
+        serde_json::Value::default()
+    } else {
+        let language = SelectedLanguage::default().get();
+        challenge_translations(&i18n_content, language.code())
+    };
 
     html! {
         <div class="custom-result">
-            <style>
-                {(*css_content).clone()}
-            </style>
-            {parsed_html}
+            <SandboxFrame
+                challenge={props.challenge.clone()}
+                html={(*html_content).clone()}
+                css={(*css_content).clone()}
+                js={(*js_content).clone()}
+                {i18n}
+                result={Some(props.result.clone())}
+            />
         </div>
     }
 }
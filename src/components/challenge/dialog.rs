@@ -6,8 +6,17 @@
 //! - [`DialogBubble`]        — single DaisyUI chat bubble (left / right)
 //! - [`DialogOptions`]       — multiple-choice buttons for a quiz turn
 //! - [`DialogComponent`]     — main orchestrator
+//!
+//! With a [`DialogGraph`] the line picked in Quiz mode decides the next
+//! turn, so a role-play can branch into several endings.
+//!
+//! With `read_aloud` (Observer) or `listen_first` (Quiz) every turn is read
+//! out with a voice per speaker, see [`speaker_voice`].
 
+#[cfg(feature = "effects")]
+use crate::effects::{ReadText, speech_voices};
 use crate::i18n::use_i18n;
+use crate::model::{DialogGraph, is_choice_turn, picked_line};
 use konnektoren_core::challenges::{
     ChallengeInput, ChallengeResult, Dialog, DialogAnswer, Speaker,
 };
@@ -54,6 +63,33 @@ fn render_scene(image: &str) -> Html {
     }
 }
 
+/// How a speaker sounds when a dialog is read aloud.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpeakerVoice {
+    /// Installed voice to use, if the browser has one for the language.
+    pub voice: Option<String>,
+    pub pitch: f32,
+    pub rate: f32,
+}
+
+/// Voice of the speaker in `slot` (their position in [`Dialog::speakers`]),
+/// given the names of the voices installed for the dialog's language.
+///
+/// The speakers get different voices when at least two are installed, and
+/// always a different pitch and rate, so they can be told apart either way.
+pub fn speaker_voice(slot: usize, voices: &[String]) -> SpeakerVoice {
+    let (pitch, rate) = if slot % 2 == 0 {
+        (1.15, 1.0)
+    } else {
+        (0.85, 0.95)
+    };
+    SpeakerVoice {
+        voice: (!voices.is_empty()).then(|| voices[slot % voices.len()].clone()),
+        pitch,
+        rate,
+    }
+}
+
 // ─── DialogSpeakerBadge ───────────────────────────────────────────────────────
 
 #[derive(Properties, PartialEq)]
@@ -97,6 +133,9 @@ pub struct DialogBubbleProps {
     pub is_end: bool,
     #[prop_or_default]
     pub state: Option<BubbleState>,
+    /// Highlights the bubble while its line is read aloud.
+    #[prop_or(false)]
+    pub speaking: bool,
 }
 
 #[function_component(DialogBubble)]
@@ -123,7 +162,12 @@ pub fn dialog_bubble(props: &DialogBubbleProps) -> Html {
                 </div>
             </div>
             <div class="chat-header dialog__turn-header">{ &props.speaker.name }</div>
-            <div class={classes!("chat-bubble", "dialog__bubble", bubble_colour)}>
+            <div class={classes!(
+                "chat-bubble",
+                "dialog__bubble",
+                bubble_colour,
+                props.speaking.then_some("dialog__bubble--speaking")
+            )}>
                 { &props.text }
             </div>
         </div>
@@ -189,6 +233,19 @@ pub struct DialogComponentProps {
     /// `false` = Observer (watch only), `true` = Quiz (pick the correct line).
     #[prop_or(false)]
     pub quiz_mode: bool,
+    /// Observer mode: read each turn aloud and move on to the next one when
+    /// it has been spoken.
+    #[prop_or(false)]
+    pub read_aloud: bool,
+    /// Quiz mode: read each turn aloud and only show its text once it has
+    /// been heard.
+    #[prop_or(false)]
+    pub listen_first: bool,
+    /// Branches of a role-play dialog. The picked line decides the next turn
+    /// and is shown as the speaker's line; Observer mode follows the correct
+    /// or else the first option.
+    #[prop_or_default]
+    pub graph: Option<DialogGraph>,
 }
 
 #[function_component(DialogComponent)]
@@ -197,12 +254,23 @@ pub fn dialog_component(props: &DialogComponentProps) -> Html {
 
     // ── state ──────────────────────────────────────────────────────────────
     let current_turn = use_state(|| 0_usize);
+    let history = use_state(Vec::<usize>::new);
     let challenge_result = use_state(|| ChallengeResult::Dialog(Vec::new()));
     let selected_option = use_state(|| None::<usize>);
     let is_answered = use_state(|| false);
+    let speaking = use_state(|| false);
+    let paused = use_state(|| false);
+    let replay = use_state(|| 0_u32);
+    let heard = use_state(|| false);
 
     let dialog = &props.challenge;
     let total_turns = dialog.turns.len();
+    let voiced = cfg!(feature = "effects")
+        && if props.quiz_mode {
+            props.listen_first
+        } else {
+            props.read_aloud
+        };
 
     // Guard: nothing to show.
     if total_turns == 0 || *current_turn >= total_turns {
@@ -210,9 +278,37 @@ pub fn dialog_component(props: &DialogComponentProps) -> Html {
     }
 
     let current_turn_data = &dialog.turns[*current_turn];
-    let is_quiz_turn = props.quiz_mode && current_turn_data.is_quiz_turn();
+    let is_quiz_turn = props.quiz_mode
+        && if props.graph.is_some() {
+            is_choice_turn(current_turn_data)
+        } else {
+            current_turn_data.is_quiz_turn()
+        };
     let show_options = is_quiz_turn && !*is_answered;
 
+    // Turn after the current one; `None` when the dialog ends with it.
+    let next_turn = match &props.graph {
+        Some(graph) => graph
+            .next_turn(dialog, *current_turn, *selected_option)
+            .filter(|next| !history.contains(next)),
+        None => Some(*current_turn + 1).filter(|next| *next < total_turns),
+    };
+
+    let answers = match &*challenge_result {
+        ChallengeResult::Dialog(answers) => answers.as_slice(),
+        _ => &[],
+    };
+    // With a graph the picked option is the speaker's line, otherwise the
+    // scripted text is.
+    let line_of = |index: usize| -> String {
+        props
+            .graph
+            .as_ref()
+            .and_then(|_| picked_line(dialog, answers, index))
+            .unwrap_or_else(|| dialog.turns[index].text.clone())
+    };
+    let current_line = line_of(*current_turn);
+
     // ── callbacks ──────────────────────────────────────────────────────────
 
     let handle_option_select = {
@@ -258,31 +354,76 @@ pub fn dialog_component(props: &DialogComponentProps) -> Html {
 
     let handle_next = {
         let current_turn = current_turn.clone();
+        let history = history.clone();
         let selected_option = selected_option.clone();
         let is_answered = is_answered.clone();
         let challenge_result = challenge_result.clone();
+        let speaking = speaking.clone();
+        let heard = heard.clone();
         let on_command = props.on_command.clone();
-        let total = total_turns;
 
-        Callback::from(move |_: MouseEvent| {
-            let next = *current_turn + 1;
-            if next >= total {
-                if let Some(cmd) = &on_command {
-                    cmd.emit(Command::Challenge(ChallengeCommand::Finish(Some(
-                        (*challenge_result).clone(),
-                    ))));
-                }
-            } else {
+        Callback::from(move |_: ()| {
+            if let Some(next) = next_turn {
+                let mut visited = (*history).clone();
+                visited.push(*current_turn);
+                history.set(visited);
                 current_turn.set(next);
                 selected_option.set(None);
                 is_answered.set(false);
+                speaking.set(false);
+                heard.set(false);
                 if let Some(cmd) = &on_command {
                     cmd.emit(Command::Challenge(ChallengeCommand::NextTask));
                 }
+            } else if let Some(cmd) = &on_command {
+                cmd.emit(Command::Challenge(ChallengeCommand::Finish(Some(
+                    (*challenge_result).clone(),
+                ))));
+            }
+        })
+    };
+
+    // ── read-aloud ─────────────────────────────────────────────────────────
+
+    let handle_speech_start = {
+        let speaking = speaking.clone();
+        Callback::from(move |_: ()| speaking.set(true))
+    };
+
+    // Observer dialogs move on by themselves; the last turn waits for Finish.
+    let handle_speech_end = {
+        let speaking = speaking.clone();
+        let heard = heard.clone();
+        let handle_next = handle_next.clone();
+        let advance = !props.quiz_mode && next_turn.is_some();
+        Callback::from(move |_: ()| {
+            speaking.set(false);
+            heard.set(true);
+            if advance {
+                handle_next.emit(());
             }
         })
     };
 
+    let handle_pause = {
+        let paused = paused.clone();
+        Callback::from(move |_: MouseEvent| paused.set(!*paused))
+    };
+
+    let handle_replay = {
+        let replay = replay.clone();
+        let paused = paused.clone();
+        Callback::from(move |_: MouseEvent| {
+            paused.set(false);
+            replay.set(*replay + 1);
+        })
+    };
+
+    let handle_show_text = {
+        let heard = heard.clone();
+        Callback::from(move |_: MouseEvent| heard.set(true))
+    };
+
     // ── helpers ────────────────────────────────────────────────────────────
 
     /// Returns `true` when `speaker_id` belongs to the second speaker slot,
@@ -297,8 +438,9 @@ pub fn dialog_component(props: &DialogComponentProps) -> Html {
     // causes bubbles to jump sides on every "Next" click.  `DialogBubble`
     // derives alignment from the speaker's fixed identity, so it is stable.
 
-    let past_bubbles = (0..*current_turn)
-        .map(|i| {
+    let past_bubbles = history
+        .iter()
+        .map(|&i| {
             let turn = &dialog.turns[i];
             let speaker = dialog
                 .speaker_by_id(&turn.speaker)
@@ -309,7 +451,7 @@ pub fn dialog_component(props: &DialogComponentProps) -> Html {
                 <DialogBubble
                     key={i}
                     speaker={speaker}
-                    text={turn.text.clone()}
+                    text={line_of(i)}
                     is_end={is_end}
                 />
             }
@@ -323,8 +465,42 @@ pub fn dialog_component(props: &DialogComponentProps) -> Html {
         .cloned()
         .unwrap_or_default();
     let current_is_end = is_end_speaker(&active_speaker.id);
+    // Quiz turns are answered from the options and read out afterwards.
+    let hide_text = voiced && props.quiz_mode && !is_quiz_turn && !*heard;
 
-    let current_content = if show_options {
+    let current_content = if hide_text {
+        // Listen first — the line is heard before it can be read.
+        html! {
+            <>
+                <div class={classes!(
+                    "chat",
+                    if current_is_end { "chat-end" } else { "chat-start" },
+                    "dialog__turn",
+                )}>
+                    <div class="chat-image avatar">
+                        <div class="dialog__avatar-circle">
+                            { render_speaker_icon(active_speaker.icon.as_deref()) }
+                        </div>
+                    </div>
+                    <div class="chat-header dialog__turn-header">{ &active_speaker.name }</div>
+                    <div class={classes!(
+                        "chat-bubble",
+                        "dialog__bubble",
+                        "dialog__bubble--pending",
+                        speaking.then_some("dialog__bubble--speaking"),
+                    )}>
+                        <i class="fa-solid fa-volume-high" aria-hidden="true"></i>
+                    </div>
+                </div>
+                <div class="dialog__listen">
+                    <p class="dialog__listen-text">{ i18n.t("Listen first, then read.") }</p>
+                    <button class="dialog__listen-btn btn btn-ghost btn-sm" onclick={handle_show_text}>
+                        { i18n.t("Show text") }
+                    </button>
+                </div>
+            </>
+        }
+    } else if show_options {
         // Quiz turn awaiting input — hide the text, show options instead.
         let options = current_turn_data.options.clone().unwrap_or_default();
 
@@ -392,7 +568,7 @@ pub fn dialog_component(props: &DialogComponentProps) -> Html {
             html! {}
         };
 
-        let is_last = *current_turn + 1 >= total_turns;
+        let is_last = next_turn.is_none();
         let btn_text = if is_last {
             i18n.t("Finish")
         } else {
@@ -403,13 +579,14 @@ pub fn dialog_component(props: &DialogComponentProps) -> Html {
             <>
                 <DialogBubble
                     speaker={active_speaker.clone()}
-                    text={current_turn_data.text.clone()}
+                    text={current_line.clone()}
                     is_end={current_is_end}
                     state={bubble_state}
+                    speaking={voiced && *speaking}
                 />
                 { correct_hint }
                 <div class="dialog__next">
-                    <button class="dialog__next-btn btn btn-primary" onclick={handle_next}>
+                    <button class="dialog__next-btn btn btn-primary" onclick={handle_next.reform(|_: MouseEvent| ())}>
                         { btn_text }
                     </button>
                 </div>
@@ -417,6 +594,66 @@ pub fn dialog_component(props: &DialogComponentProps) -> Html {
         }
     };
 
+    // ── speech ─────────────────────────────────────────────────────────────
+    //
+    // Keyed by turn so that every turn is read, even when two turns share the
+    // same line. Quiz turns are only read once the player has answered.
+
+    let speech = if voiced && !show_options {
+        #[cfg(feature = "effects")]
+        {
+            let slot = usize::from(current_is_end);
+            let voice = speaker_voice(slot, &speech_voices(&dialog.lang));
+            html! {
+                <ReadText
+                    key={*current_turn}
+                    text={current_line.clone()}
+                    lang={dialog.lang.clone()}
+                    pitch={voice.pitch}
+                    rate={voice.rate}
+                    voice={voice.voice}
+                    paused={*paused}
+                    replay={*replay}
+                    on_start={handle_speech_start}
+                    on_end={handle_speech_end}
+                />
+            }
+        }
+        #[cfg(not(feature = "effects"))]
+        {
+            let _ = (handle_speech_start, handle_speech_end);
+            html! {}
+        }
+    } else {
+        html! {}
+    };
+
+    let playback = if voiced {
+        html! {
+            <div class="dialog__playback">
+                <button
+                    class="dialog__playback-btn btn btn-ghost btn-sm"
+                    onclick={handle_pause}
+                    aria-pressed={(*paused).to_string()}
+                >
+                    if *paused {
+                        <i class="fa-solid fa-play" aria-hidden="true"></i>
+                        { i18n.t("Resume") }
+                    } else {
+                        <i class="fa-solid fa-pause" aria-hidden="true"></i>
+                        { i18n.t("Pause") }
+                    }
+                </button>
+                <button class="dialog__playback-btn btn btn-ghost btn-sm" onclick={handle_replay}>
+                    <i class="fa-solid fa-rotate-right" aria-hidden="true"></i>
+                    { i18n.t("Replay") }
+                </button>
+            </div>
+        }
+    } else {
+        html! {}
+    };
+
     // ── full layout ────────────────────────────────────────────────────────
     html! {
         <div class="dialog">
@@ -441,14 +678,37 @@ pub fn dialog_component(props: &DialogComponentProps) -> Html {
                 }).collect::<Html>() }
             </div>
 
+            { playback }
+
             <div class="dialog__chat">
                 { past_bubbles }
                 { current_content }
             </div>
+            { speech }
         </div>
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speakers_sound_different() {
+        let voices = vec!["Anna".to_string(), "Markus".to_string()];
+        let first = speaker_voice(0, &voices);
+        let second = speaker_voice(1, &voices);
+        assert_eq!(first.voice.as_deref(), Some("Anna"));
+        assert_eq!(second.voice.as_deref(), Some("Markus"));
+        assert_ne!(first.pitch, second.pitch);
+
+        let single = vec!["Anna".to_string()];
+        assert_eq!(speaker_voice(1, &single).voice.as_deref(), Some("Anna"));
+        assert_eq!(speaker_voice(1, &[]).voice, None);
+        assert_ne!(speaker_voice(0, &[]), speaker_voice(1, &[]));
+    }
+}
+
 // ─── yew-preview ─────────────────────────────────────────────────────────────
 
 #[cfg(feature = "yew-preview")]
@@ -562,6 +822,9 @@ mod preview {
             on_command: None,
             on_event: None,
             quiz_mode: false,
+            read_aloud: false,
+            listen_first: false,
+            graph: None,
         },
         (
             "Quiz mode (icons)",
@@ -570,6 +833,9 @@ mod preview {
                 on_command: None,
                 on_event: None,
                 quiz_mode: true,
+                read_aloud: false,
+                listen_first: false,
+                graph: None,
             }
         ),
         (
@@ -579,6 +845,48 @@ mod preview {
                 on_command: None,
                 on_event: None,
                 quiz_mode: false,
+                read_aloud: false,
+                listen_first: false,
+                graph: None,
+            }
+        ),
+        (
+            "Branching (goodbye skips the small talk)",
+            DialogComponentProps {
+                challenge: make_image_dialog(),
+                on_command: None,
+                on_event: None,
+                quiz_mode: true,
+                read_aloud: false,
+                listen_first: false,
+                graph: Some(DialogGraph {
+                    next_turns: [(1, vec![2, 5, 5])].into(),
+                    endings: vec![],
+                }),
+            }
+        ),
+        (
+            "Read aloud",
+            DialogComponentProps {
+                challenge: make_image_dialog(),
+                on_command: None,
+                on_event: None,
+                quiz_mode: false,
+                read_aloud: true,
+                listen_first: false,
+                graph: None,
+            }
+        ),
+        (
+            "Listen first",
+            DialogComponentProps {
+                challenge: make_image_dialog(),
+                on_command: None,
+                on_event: None,
+                quiz_mode: true,
+                read_aloud: false,
+                listen_first: true,
+                graph: None,
             }
         ),
         (
@@ -588,6 +896,9 @@ mod preview {
                 on_command: None,
                 on_event: None,
                 quiz_mode: true,
+                read_aloud: false,
+                listen_first: false,
+                graph: None,
             }
         ),
     );
@@ -3,9 +3,12 @@
 //! * **Observer mode** — no quiz turns exist; shows a simple completion message.
 //! * **Quiz mode**     — shows a recap table with one row per interactive turn,
 //!   indicating whether the player's choice was correct.
+//! * **Branching**     — with a [`DialogGraph`], shows the path taken, the
+//!   ending reached and its score.
 
 use crate::components::challenge::DialogObserverResultComponent;
 use crate::i18n::use_i18n;
+use crate::model::{DialogGraph, picked_line};
 use konnektoren_core::challenges::{ChallengeResult, ChallengeVariant, Dialog};
 use yew::prelude::*;
 
@@ -15,6 +18,8 @@ pub struct DialogResultComponentProps {
     pub challenge_result: ChallengeResult,
     #[prop_or_default]
     pub variant: Option<ChallengeVariant>,
+    #[prop_or_default]
+    pub graph: Option<DialogGraph>,
 }
 
 #[function_component(DialogResultComponent)]
@@ -32,6 +37,53 @@ pub fn dialog_result_component(props: &DialogResultComponentProps) -> Html {
         _ => vec![],
     };
 
+    if let Some(graph) = &props.graph {
+        let dialog = &props.challenge;
+        let ending = graph.reached_ending(dialog, &answers);
+        let score = graph.performance(dialog, &answers);
+        let steps = graph
+            .path(dialog, &answers)
+            .into_iter()
+            .map(|index| {
+                let turn = &dialog.turns[index];
+                let picked = picked_line(dialog, &answers, index);
+                let speaker_name = dialog
+                    .speaker_by_id(&turn.speaker)
+                    .map(|s| s.name.clone())
+                    .unwrap_or_default();
+                html! {
+                    <li
+                        key={index}
+                        class={classes!(
+                            "dialog-result__step",
+                            picked.is_some().then_some("dialog-result__step--picked")
+                        )}
+                    >
+                        <span class="dialog-result__step-speaker">{ speaker_name }</span>
+                        <span class="dialog-result__step-line">
+                            { picked.unwrap_or_else(|| turn.text.clone()) }
+                        </span>
+                    </li>
+                }
+            })
+            .collect::<Html>();
+
+        return html! {
+            <div class="dialog-result">
+                <h2 class="dialog-result__title">{ i18n.t("Challenge Result") }</h2>
+                if let Some(ending) = ending {
+                    <p class="dialog-result__ending">
+                        { format!("{}: {}", i18n.t("Ending"), ending.title) }
+                    </p>
+                }
+                <p class="dialog-result__score">{ format!("{}: {}%", i18n.t("Score"), score) }</p>
+                <ol class="dialog-result__path" aria-label={i18n.t("Your path")}>
+                    { steps }
+                </ol>
+            </div>
+        };
+    }
+
     let quiz_turns: Vec<_> = props.challenge.quiz_turns().collect();
 
     // Observer mode — nothing to grade (fallback when no variant set but no quiz turns)
@@ -121,6 +173,7 @@ pub fn dialog_result_component(props: &DialogResultComponentProps) -> Html {
 #[cfg(feature = "yew-preview")]
 mod preview {
     use super::*;
+    use crate::model::DialogEnding;
     use konnektoren_core::challenges::{ChallengeVariant, DialogAnswer};
     use yew_preview::prelude::*;
 
@@ -175,6 +228,19 @@ mod preview {
         ChallengeResult::Dialog(vec![])
     }
 
+    /// Ben says goodbye right away (turn 1 → turn 5), which skips the
+    /// small talk.
+    fn make_graph() -> DialogGraph {
+        DialogGraph {
+            next_turns: [(1, vec![2, 5, 5])].into(),
+            endings: vec![DialogEnding {
+                turn: 5,
+                title: "Goodbye".to_string(),
+                score: None,
+            }],
+        }
+    }
+
     yew_preview::create_preview!(
         DialogResultComponent,
         // Default: all correct
@@ -182,6 +248,7 @@ mod preview {
             challenge: Dialog::default(),
             challenge_result: make_all_correct(),
             variant: None,
+            graph: None,
         },
         (
             "Mixed (incorrect answers)",
@@ -189,6 +256,7 @@ mod preview {
                 challenge: Dialog::default(),
                 challenge_result: make_mixed(),
                 variant: None,
+                graph: None,
             }
         ),
         (
@@ -197,6 +265,25 @@ mod preview {
                 challenge: Dialog::default(),
                 challenge_result: make_observer_result(),
                 variant: Some(ChallengeVariant::DialogObserver),
+                graph: None,
+            }
+        ),
+        (
+            "Branching (path taken)",
+            DialogResultComponentProps {
+                challenge: Dialog::default(),
+                challenge_result: ChallengeResult::Dialog(vec![
+                    DialogAnswer {
+                        turn_index: 1,
+                        selected_option: 2,
+                    },
+                    DialogAnswer {
+                        turn_index: 5,
+                        selected_option: 0,
+                    },
+                ]),
+                variant: None,
+                graph: Some(make_graph()),
             }
         ),
     );
@@ -0,0 +1,300 @@
+//! Flashcard study mode for vocabulary lists: the learner flips each card
+//! and grades how well they knew the word.
+
+use super::{ChallengeKey, on_challenge_keydown};
+use crate::components::{ProgressBar, SwipeComponent, SwipeDirection, use_focus_on_change};
+use crate::i18n::use_i18n;
+use crate::model::{ReviewGrade, flashcard_result};
+use crate::tools::{seed_from, shuffled_indices};
+use konnektoren_core::challenges::Vocabulary;
+use konnektoren_core::commands::{ChallengeCommand, Command};
+use konnektoren_core::events::{ChallengeEvent, Event};
+use yew::prelude::*;
+
+/// Grade given with a swipe: left for again, down for hard, right for good
+/// and up for easy.
+pub fn swipe_grade(direction: &SwipeDirection) -> ReviewGrade {
+    match direction {
+        SwipeDirection::Left => ReviewGrade::Again,
+        SwipeDirection::Down => ReviewGrade::Hard,
+        SwipeDirection::Right => ReviewGrade::Good,
+        SwipeDirection::Up => ReviewGrade::Easy,
+    }
+}
+
+/// Grades the card at `position` of the deck. Only the first grade of a
+/// word counts, and only then `true` is returned; cards graded `Again` go
+/// to the back of the deck once more.
+pub fn grade_card(
+    deck: &mut Vec<usize>,
+    grades: &mut [Option<ReviewGrade>],
+    position: usize,
+    grade: ReviewGrade,
+) -> bool {
+    let Some(&item) = deck.get(position) else {
+        return false;
+    };
+    let first = grades.get(item).is_some_and(Option::is_none);
+    if let Some(slot) = grades.get_mut(item)
+        && slot.is_none()
+    {
+        *slot = Some(grade);
+    }
+    if grade == ReviewGrade::Again && first {
+        deck.push(item);
+    }
+    first
+}
+
+#[derive(Properties, PartialEq)]
+pub struct FlashcardsComponentProps {
+    pub challenge: Vocabulary,
+    #[prop_or_default]
+    pub on_event: Option<Callback<Event>>,
+    #[prop_or_default]
+    pub on_command: Option<Callback<Command>>,
+    /// Seed for the order of the cards. Defaults to one derived from the
+    /// challenge id.
+    #[prop_or_default]
+    pub seed: Option<u64>,
+}
+
+/// Shows one word at a time. "Show answer", `Space`, `Enter` or a swipe
+/// flips the card; the grade buttons, the keys `1`–`4` or another swipe
+/// grade it. Finishes with a [`flashcard_result`] once every card is graded.
+#[function_component(FlashcardsComponent)]
+pub fn flashcards_component(props: &FlashcardsComponentProps) -> Html {
+    let i18n = use_i18n();
+    let seed = props
+        .seed
+        .unwrap_or_else(|| seed_from(&[&props.challenge.id]));
+    let deck = use_state(|| shuffled_indices(props.challenge.items.len(), seed, 0));
+    let grades = use_state(|| vec![None::<ReviewGrade>; props.challenge.items.len()]);
+    let position = use_state(|| 0_usize);
+    let flipped = use_state(|| false);
+    let card_ref = use_node_ref();
+    use_focus_on_change(card_ref.clone(), *position);
+
+    let on_flip = {
+        let flipped = flipped.clone();
+        Callback::from(move |_: ()| flipped.set(!*flipped))
+    };
+
+    let on_grade = {
+        let deck = deck.clone();
+        let grades = grades.clone();
+        let position = position.clone();
+        let flipped = flipped.clone();
+        let on_event = props.on_event.clone();
+        let on_command = props.on_command.clone();
+        Callback::from(move |grade: ReviewGrade| {
+            let Some(&item) = deck.get(*position) else {
+                return;
+            };
+            let mut next_deck = (*deck).clone();
+            let mut next_grades = (*grades).clone();
+            let first = grade_card(&mut next_deck, &mut next_grades, *position, grade);
+
+            // A requeued card was already reported with its first grade
+            if first && let Some(on_event) = on_event.as_ref() {
+                let event = if grade.is_known() {
+                    ChallengeEvent::SolvedCorrect(item)
+                } else {
+                    ChallengeEvent::SolvedIncorrect(item)
+                };
+                on_event.emit(Event::Challenge(event));
+            }
+
+            let next_position = *position + 1;
+            if next_position >= next_deck.len()
+                && let Some(on_command) = on_command.as_ref()
+            {
+                on_command.emit(Command::Challenge(ChallengeCommand::Finish(Some(
+                    flashcard_result(&next_grades),
+                ))));
+            }
+            deck.set(next_deck);
+            grades.set(next_grades);
+            position.set(next_position);
+            flipped.set(false);
+        })
+    };
+
+    let on_swipe = {
+        let on_flip = on_flip.clone();
+        let on_grade = on_grade.clone();
+        let flipped = *flipped;
+        Callback::from(move |direction: SwipeDirection| {
+            if flipped {
+                on_grade.emit(swipe_grade(&direction));
+            } else {
+                on_flip.emit(());
+            }
+        })
+    };
+
+    let onkeydown = {
+        let on_flip = on_flip.clone();
+        let on_grade = on_grade.clone();
+        let flipped = *flipped;
+        on_challenge_keydown(Callback::from(move |key: ChallengeKey| match key {
+            ChallengeKey::Grab | ChallengeKey::Confirm => on_flip.emit(()),
+            ChallengeKey::Choose(index) if flipped => {
+                if let Some(grade) = ReviewGrade::ALL.get(index) {
+                    on_grade.emit(*grade);
+                }
+            }
+            _ => {}
+        }))
+    };
+
+    let Some(item) = deck
+        .get(*position)
+        .and_then(|&index| props.challenge.items.get(index))
+    else {
+        return html! {
+            <div class="flashcards">
+                <p class="flashcards__empty">{ i18n.t("No vocabulary items available.") }</p>
+            </div>
+        };
+    };
+
+    let graded = grades.iter().filter(|grade| grade.is_some()).count();
+
+    html! {
+        <div class="flashcards" {onkeydown}>
+            <ProgressBar
+                value={graded}
+                max={grades.len()}
+                label={format!("{} / {}", graded, grades.len())}
+            />
+            <SwipeComponent
+                on_swipe={on_swipe}
+                show_hints={*flipped}
+                left_icon={"fa-solid fa-rotate-left".to_string()}
+                down_icon={"fa-solid fa-face-frown-open".to_string()}
+                right_icon={"fa-solid fa-check".to_string()}
+                up_icon={"fa-solid fa-star".to_string()}
+            >
+                <div
+                    ref={card_ref}
+                    class={classes!("flashcards__card", flipped.then_some("flashcards__card--flipped"))}
+                    tabindex="-1"
+                >
+                    if *flipped {
+                        <div class="flashcards__back" aria-live="polite">
+                            <p class="flashcards__term">{ &item.text }</p>
+                            if let Some(translation) = &item.translation {
+                                <p class="flashcards__translation">{ i18n.t(translation) }</p>
+                            }
+                            if let Some(phonetic) = &item.phonetic {
+                                <p class="flashcards__phonetic">{ phonetic }</p>
+                            }
+                        </div>
+                    } else {
+                        <div class="flashcards__front">
+                            if let Some(icon) = &item.icon {
+                                if icon.starts_with("fa-") {
+                                    <i class={classes!("flashcards__icon", icon)} aria-hidden="true"></i>
+                                } else {
+                                    <img class="flashcards__icon" src={icon.clone()} alt="" />
+                                }
+                            }
+                            <p class="flashcards__term">{ &item.text }</p>
+                        </div>
+                    }
+                </div>
+            </SwipeComponent>
+            if *flipped {
+                <div class="flashcards__grades" role="group" aria-label={i18n.t("How well did you remember?")}>
+                    { for ReviewGrade::ALL.iter().enumerate().map(|(index, grade)| {
+                        let grade = *grade;
+                        html! {
+                            <button
+                                class={classes!("flashcards__grade", format!("flashcards__grade--{}", grade.name().to_lowercase()))}
+                                onclick={on_grade.reform(move |_: MouseEvent| grade)}
+                            >
+                                <kbd class="flashcards__key">{ index + 1 }</kbd>
+                                { i18n.t(grade.name()) }
+                            </button>
+                        }
+                    }) }
+                </div>
+            } else {
+                <button class="flashcards__flip" onclick={on_flip.reform(|_: MouseEvent| ())}>
+                    { i18n.t("Show answer") }
+                </button>
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn again_requeues_the_card_once() {
+        let mut deck = vec![1, 0];
+        let mut grades = vec![None; 2];
+        assert!(grade_card(&mut deck, &mut grades, 0, ReviewGrade::Again));
+        assert_eq!(deck, vec![1, 0, 1]);
+        assert!(grade_card(&mut deck, &mut grades, 1, ReviewGrade::Good));
+        // The requeued card is not graded or reported again
+        assert!(!grade_card(&mut deck, &mut grades, 2, ReviewGrade::Again));
+        assert_eq!(deck, vec![1, 0, 1]);
+        assert_eq!(
+            grades,
+            vec![Some(ReviewGrade::Good), Some(ReviewGrade::Again)]
+        );
+    }
+
+    #[test]
+    fn swipes_map_to_grades() {
+        assert_eq!(swipe_grade(&SwipeDirection::Left), ReviewGrade::Again);
+        assert_eq!(swipe_grade(&SwipeDirection::Up), ReviewGrade::Easy);
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use konnektoren_core::challenges::VocabularyItem;
+    use yew_preview::prelude::*;
+
+    fn create_test_vocabulary() -> Vocabulary {
+        let words = [
+            ("der Apfel", "the apple", "fa-solid fa-apple-whole"),
+            ("das Haus", "the house", "fa-solid fa-house"),
+            ("die Katze", "the cat", "fa-solid fa-cat"),
+        ];
+        Vocabulary {
+            id: "flashcards".to_string(),
+            name: "Basic German Vocabulary".to_string(),
+            description: "Learn essential German words".to_string(),
+            icon: None,
+            lang: "de".to_string(),
+            items: words
+                .iter()
+                .enumerate()
+                .map(|(id, (text, translation, icon))| VocabularyItem {
+                    id,
+                    text: text.to_string(),
+                    translation: Some(translation.to_string()),
+                    icon: Some(icon.to_string()),
+                    phonetic: None,
+                })
+                .collect(),
+        }
+    }
+
+    yew_preview::create_preview!(
+        FlashcardsComponent,
+        FlashcardsComponentProps {
+            challenge: create_test_vocabulary(),
+            on_event: None,
+            on_command: None,
+            seed: None,
+        },
+    );
+}
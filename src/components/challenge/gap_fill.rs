@@ -1,9 +1,15 @@
-use super::{ChallengeActions, ChallengeActionsComponent};
-use crate::components::ProgressBar;
+use super::hints::{max_revealed, revealed_prefix};
+use super::keyboard::step;
+use super::typed_answer::{AnswerMatch, match_answer};
+use super::{ChallengeActions, ChallengeActionsComponent, ChallengeKey, on_challenge_keydown};
+use crate::components::{ProgressBar, use_focus_on_change};
 use crate::i18n::use_i18n;
+use crate::model::{ChallengeCheckpoint, HintUsage};
 #[cfg(feature = "effects")]
 use crate::prelude::ReadText;
-use konnektoren_core::challenges::{ChallengeInput, ChallengeResult, GapFill, GapFillAnswer};
+use konnektoren_core::challenges::{
+    ChallengeInput, ChallengeResult, GapFill, GapFillAnswer, GapFillQuestion,
+};
 use konnektoren_core::commands::{ChallengeCommand, Command};
 use konnektoren_core::events::{ChallengeEvent, Event};
 use yew::prelude::*;
@@ -15,16 +21,126 @@ pub struct GapFillComponentProps {
     pub on_event: Option<Callback<Event>>,
     #[prop_or_default]
     pub on_command: Option<Callback<Command>>,
+    /// Saved progress to continue from.
+    #[prop_or_default]
+    pub checkpoint: Option<ChallengeCheckpoint>,
+    #[prop_or_default]
+    pub on_checkpoint: Option<Callback<ChallengeCheckpoint>>,
+    /// Type the answers instead of choosing from the options.
+    #[prop_or(false)]
+    pub typed: bool,
+    /// Hints taken so far; each one reveals another letter of every gap.
+    #[prop_or_default]
+    pub hints: HintUsage,
+    /// Called with the question index when a hint is requested. Without it
+    /// there is no hint button.
+    #[prop_or_default]
+    pub on_hint: Option<Callback<usize>>,
+}
+
+/// Answers to record for typed input and how close each one was.
+///
+/// Answers that match after normalization are recorded as the expected
+/// spelling so they score as correct, everything else is kept as typed. The
+/// text as typed is kept in the checkpoint's `typed_answers`.
+pub fn evaluate_typed_answers(
+    question: &GapFillQuestion,
+    typed: &[String],
+) -> (Vec<String>, Vec<AnswerMatch>) {
+    question
+        .gaps
+        .iter()
+        .enumerate()
+        .map(|(index, gap)| {
+            let text = typed.get(index).map(|text| text.trim()).unwrap_or_default();
+            match match_answer(text, &gap.correct) {
+                AnswerMatch::Correct => (gap.correct.clone(), AnswerMatch::Correct),
+                answer_match => (text.to_string(), answer_match),
+            }
+        })
+        .unzip()
+}
+
+/// Typed answers after `text` was entered into gap `gap_index`, or `None`
+/// once the question was checked. Checked gaps are locked, so the correct
+/// answers shown as feedback can't be copied in and checked again.
+pub fn edit_typed_answer(
+    answers: &[String],
+    checked: bool,
+    gap_index: usize,
+    text: String,
+) -> Option<Vec<String>> {
+    if checked {
+        return None;
+    }
+    let mut answers = answers.to_vec();
+    if answers.len() <= gap_index {
+        answers.resize(gap_index + 1, String::new());
+    }
+    answers[gap_index] = text;
+    Some(answers)
+}
+
+fn record_answer(
+    challenge: &GapFill,
+    challenge_result: &UseStateHandle<ChallengeResult>,
+    answer: GapFillAnswer,
+    on_event: Option<&Callback<Event>>,
+) -> ChallengeResult {
+    let task_index = answer.question_index;
+    let mut challenge_result_update = (**challenge_result).clone();
+    challenge_result_update
+        .set_input(task_index, ChallengeInput::GapFill(answer.clone()))
+        .unwrap();
+    challenge_result.set(challenge_result_update.clone());
+
+    if let Some(on_event) = on_event {
+        if challenge.check_answer(&answer) {
+            on_event.emit(Event::Challenge(ChallengeEvent::SolvedCorrect(task_index)));
+        } else {
+            on_event.emit(Event::Challenge(ChallengeEvent::SolvedIncorrect(
+                task_index,
+            )));
+        }
+    }
+
+    challenge_result_update
 }
 
 #[function_component(GapFillComponent)]
 pub fn gap_fill_component(props: &GapFillComponentProps) -> Html {
     let i18n = use_i18n();
-    let task_index = use_state(|| 0);
-    let challenge_result = use_state(|| ChallengeResult::GapFill(Vec::new()));
+    let task_index = use_state(|| {
+        props.checkpoint.as_ref().map_or(0, |checkpoint| {
+            checkpoint.resume_index(props.challenge.questions.len())
+        })
+    });
+    let container_ref = use_node_ref();
+    use_focus_on_change(container_ref.clone(), *task_index);
+    let challenge_result = use_state(|| {
+        props
+            .checkpoint
+            .as_ref()
+            .map(|checkpoint| checkpoint.challenge_result.clone())
+            .unwrap_or_else(|| ChallengeResult::GapFill(Vec::new()))
+    });
     let show_help = use_state(|| false);
     let selected_gap = use_state(|| None::<usize>);
-    let current_answers = use_state(Vec::<String>::default);
+    let current_answers = use_state(|| {
+        props
+            .checkpoint
+            .as_ref()
+            .map(|checkpoint| checkpoint.partial_answers.clone())
+            .unwrap_or_default()
+    });
+    let feedback = use_state(|| None::<Vec<AnswerMatch>>);
+    let typed_answers = use_state(|| {
+        props
+            .checkpoint
+            .as_ref()
+            .map(|checkpoint| checkpoint.typed_answers.clone())
+            .unwrap_or_default()
+    });
 
     if *task_index >= props.challenge.questions.len() {
         return html! {};
@@ -39,64 +155,162 @@ pub fn gap_fill_component(props: &GapFillComponentProps) -> Html {
         })
     };
 
-    let handle_option_selection = {
+    // Moves to the next question, or finishes after the last one
+    let advance = {
+        let task_index = task_index.clone();
+        let current_answers = current_answers.clone();
+        let feedback = feedback.clone();
+        let total_questions = props.challenge.questions.len();
+        let on_command = props.on_command.clone();
+        let on_checkpoint = props.on_checkpoint.clone();
+        let typed_answers = typed_answers.clone();
+
+        Callback::from(move |challenge_result: ChallengeResult| {
+            if *task_index < total_questions - 1 {
+                task_index.set(*task_index + 1);
+                current_answers.set(Vec::new());
+                feedback.set(None);
+                if let Some(on_checkpoint) = on_checkpoint.as_ref() {
+                    on_checkpoint.emit(
+                        ChallengeCheckpoint::new(*task_index + 1, challenge_result)
+                            .with_typed_answers((*typed_answers).clone()),
+                    );
+                }
+                if let Some(on_command) = on_command.as_ref() {
+                    on_command.emit(Command::Challenge(ChallengeCommand::NextTask));
+                }
+            } else if let Some(on_command) = on_command.as_ref() {
+                on_command.emit(Command::Challenge(ChallengeCommand::Finish(Some(
+                    challenge_result,
+                ))));
+            }
+        })
+    };
+
+    let fill_gap = {
         let task_index = task_index.clone();
         let challenge_result = challenge_result.clone();
         let current_answers = current_answers.clone();
         let selected_gap = selected_gap.clone();
         let challenge = props.challenge.clone();
         let on_event = props.on_event.clone();
-        let on_command = props.on_command.clone();
+        let on_checkpoint = props.on_checkpoint.clone();
+        let advance = advance.clone();
 
-        Callback::from(move |option: String| {
+        Callback::from(move |(gap_index, option): (usize, String)| {
             let mut answers = (*current_answers).clone();
-            if let Some(gap_index) = *selected_gap {
-                if answers.len() <= gap_index {
-                    answers.resize(gap_index + 1, String::new());
-                }
-                answers[gap_index] = option;
-                current_answers.set(answers.clone());
-                selected_gap.set(None);
-
-                // Check if all gaps are filled
-                if answers.len() == challenge.questions[*task_index].gaps.len()
-                    && !answers.iter().any(|a| a.is_empty())
-                {
-                    let answer = GapFillAnswer {
-                        question_index: *task_index,
-                        answers: answers.clone(),
-                    };
-
-                    let mut challenge_result_update = (*challenge_result).clone();
-                    challenge_result_update
-                        .set_input(*task_index, ChallengeInput::GapFill(answer.clone()))
-                        .unwrap();
-                    challenge_result.set(challenge_result_update.clone());
-
-                    if let Some(on_event) = on_event.as_ref() {
-                        if challenge.check_answer(&answer) {
-                            on_event
-                                .emit(Event::Challenge(ChallengeEvent::SolvedCorrect(*task_index)));
-                        } else {
-                            on_event.emit(Event::Challenge(ChallengeEvent::SolvedIncorrect(
-                                *task_index,
-                            )));
-                        }
-                    }
+            if answers.len() <= gap_index {
+                answers.resize(gap_index + 1, String::new());
+            }
+            answers[gap_index] = option;
+            current_answers.set(answers.clone());
+            selected_gap.set(None);
 
-                    // Move to next question or finish
-                    if *task_index < challenge.questions.len() - 1 {
-                        task_index.set(*task_index + 1);
-                        current_answers.set(Vec::new());
-                        if let Some(on_command) = on_command.as_ref() {
-                            on_command.emit(Command::Challenge(ChallengeCommand::NextTask));
-                        }
-                    } else if let Some(on_command) = on_command.as_ref() {
-                        on_command.emit(Command::Challenge(ChallengeCommand::Finish(Some(
-                            challenge_result_update,
-                        ))));
-                    }
-                }
+            // Check if all gaps are filled
+            if answers.len() == challenge.questions[*task_index].gaps.len()
+                && !answers.iter().any(|a| a.is_empty())
+            {
+                let answer = GapFillAnswer {
+                    question_index: *task_index,
+                    answers,
+                };
+                let challenge_result_update =
+                    record_answer(&challenge, &challenge_result, answer, on_event.as_ref());
+                advance.emit(challenge_result_update);
+            } else if let Some(on_checkpoint) = on_checkpoint.as_ref() {
+                on_checkpoint.emit(
+                    ChallengeCheckpoint::new(*task_index, (*challenge_result).clone())
+                        .with_partial_answers(answers),
+                );
+            }
+        })
+    };
+
+    let type_gap = {
+        let current_answers = current_answers.clone();
+        let feedback = feedback.clone();
+        Callback::from(move |(gap_index, text): (usize, String)| {
+            if let Some(answers) =
+                edit_typed_answer(&current_answers, feedback.is_some(), gap_index, text)
+            {
+                current_answers.set(answers);
+            }
+        })
+    };
+
+    // Saves a typed gap once it is committed, not on every keystroke
+    let commit_gap = {
+        let task_index = task_index.clone();
+        let challenge_result = challenge_result.clone();
+        let current_answers = current_answers.clone();
+        let feedback = feedback.clone();
+        let on_checkpoint = props.on_checkpoint.clone();
+        let typed_answers = typed_answers.clone();
+        Callback::from(move |_: ()| {
+            // Once checked, the answer is saved with the next task
+            if feedback.is_some() {
+                return;
+            }
+            if let Some(on_checkpoint) = on_checkpoint.as_ref() {
+                on_checkpoint.emit(
+                    ChallengeCheckpoint::new(*task_index, (*challenge_result).clone())
+                        .with_partial_answers((*current_answers).clone())
+                        .with_typed_answers((*typed_answers).clone()),
+                );
+            }
+        })
+    };
+
+    // Checks typed answers and shows feedback, or moves on once it is shown
+    let check_typed = {
+        let task_index = task_index.clone();
+        let challenge_result = challenge_result.clone();
+        let current_answers = current_answers.clone();
+        let feedback = feedback.clone();
+        let challenge = props.challenge.clone();
+        let on_event = props.on_event.clone();
+        let on_checkpoint = props.on_checkpoint.clone();
+        let typed_answers = typed_answers.clone();
+        let advance = advance.clone();
+
+        Callback::from(move |_: ()| {
+            if feedback.is_some() {
+                advance.emit((*challenge_result).clone());
+                return;
+            }
+            let question = &challenge.questions[*task_index];
+            let (answers, matches) = evaluate_typed_answers(question, &current_answers);
+            let answer = GapFillAnswer {
+                question_index: *task_index,
+                answers,
+            };
+            let challenge_result_update =
+                record_answer(&challenge, &challenge_result, answer, on_event.as_ref());
+            let mut typed_update = (*typed_answers).clone();
+            typed_update.insert(
+                *task_index,
+                current_answers
+                    .iter()
+                    .map(|text| text.trim().to_string())
+                    .collect(),
+            );
+            typed_answers.set(typed_update.clone());
+            if let Some(on_checkpoint) = on_checkpoint.as_ref() {
+                on_checkpoint.emit(
+                    ChallengeCheckpoint::new(*task_index + 1, challenge_result_update)
+                        .with_typed_answers(typed_update),
+                );
+            }
+            feedback.set(Some(matches));
+        })
+    };
+
+    let handle_option_selection = {
+        let selected_gap = selected_gap.clone();
+        let fill_gap = fill_gap.clone();
+        Callback::from(move |option: String| {
+            if let Some(gap_index) = *selected_gap {
+                fill_gap.emit((gap_index, option));
             }
         })
     };
@@ -107,8 +321,13 @@ pub fn gap_fill_component(props: &GapFillComponentProps) -> Html {
         let current_answers = current_answers.clone();
         let total_questions = props.challenge.questions.len();
         let on_command = props.on_command.clone();
+        let feedback = feedback.clone();
+        let check_typed = check_typed.clone();
+        let typed = props.typed;
+        let on_hint = props.on_hint.clone();
 
         Callback::from(move |action: ChallengeActions| match action {
+            ChallengeActions::Next if typed => check_typed.emit(()),
             ChallengeActions::Next => {
                 if *task_index < total_questions - 1 {
                     task_index.set(*task_index + 1);
@@ -122,6 +341,7 @@ pub fn gap_fill_component(props: &GapFillComponentProps) -> Html {
                 if *task_index > 0 {
                     task_index.set(*task_index - 1);
                     current_answers.set(Vec::new());
+                    feedback.set(None);
                     if let Some(on_command) = on_command.as_ref() {
                         on_command.emit(Command::Challenge(ChallengeCommand::PreviousTask));
                     }
@@ -130,9 +350,57 @@ pub fn gap_fill_component(props: &GapFillComponentProps) -> Html {
             ChallengeActions::Help => {
                 show_help.set(!*show_help);
             }
+            ChallengeActions::Hint => {
+                if let Some(on_hint) = on_hint.as_ref() {
+                    on_hint.emit(*task_index);
+                }
+            }
         })
     };
 
+    let hint_level = props
+        .hints
+        .level(*task_index)
+        .min(max_revealed(&current_question.gaps));
+    let hints_left = props
+        .on_hint
+        .as_ref()
+        .map(|_| max_revealed(&current_question.gaps) - hint_level);
+
+    let handle_key = {
+        let options: Vec<String> = current_question
+            .gaps
+            .iter()
+            .flat_map(|gap| &gap.options)
+            .cloned()
+            .collect();
+        let gap_count = current_question.gaps.len();
+        let selected_gap = selected_gap.clone();
+        let current_answers = current_answers.clone();
+        let handle_action = handle_action.clone();
+
+        on_challenge_keydown(Callback::from(move |key: ChallengeKey| match key {
+            ChallengeKey::Choose(index) => {
+                let gap_index = (*selected_gap).or_else(|| {
+                    (0..gap_count)
+                        .find(|gap| current_answers.get(*gap).is_none_or(String::is_empty))
+                });
+                if let (Some(gap_index), Some(option)) = (gap_index, options.get(index)) {
+                    fill_gap.emit((gap_index, option.clone()));
+                }
+            }
+            key if key.is_arrow() && gap_count > 0 => {
+                let gap_index = (*selected_gap).map_or(0, |gap| step(gap, gap_count, key));
+                selected_gap.set(Some(gap_index));
+            }
+            key => {
+                if let Some(action) = key.action() {
+                    handle_action.emit(action);
+                }
+            }
+        }))
+    };
+
     let read_text = {
         #[cfg(feature = "effects")]
         {
@@ -150,7 +418,7 @@ pub fn gap_fill_component(props: &GapFillComponentProps) -> Html {
     };
 
     html! {
-        <div class="gap-fill">
+        <div class="gap-fill" ref={container_ref} tabindex="0" onkeydown={handle_key}>
             <ProgressBar
                 value={*task_index}
                 max={props.challenge.questions.len()}
@@ -158,32 +426,84 @@ pub fn gap_fill_component(props: &GapFillComponentProps) -> Html {
             />
 
             <div class="gap-fill__sentence">
-                {render_sentence(
-                    &current_question.sentence,
-                    &current_answers,
-                    *selected_gap,
-                    handle_gap_click.clone(),
-                )}
+                if props.typed {
+                    {render_typed_sentence(
+                        &current_question.sentence,
+                        &current_answers,
+                        (*feedback).as_deref(),
+                        &i18n.t("Gap"),
+                        type_gap,
+                        commit_gap,
+                        check_typed.clone(),
+                    )}
+                } else {
+                    {render_sentence(
+                        &current_question.sentence,
+                        &current_answers,
+                        *selected_gap,
+                        handle_gap_click.clone(),
+                    )}
+                }
             </div>
 
-            <div class="gap-fill__options">
-                {current_question.gaps.iter().flat_map(|gap| &gap.options).cloned().map(|option| {
-                    let on_click = {
-                        let option = option.clone();
-                        let handle_option_selection = handle_option_selection.clone();
-                        Callback::from(move |_| handle_option_selection.emit(option.clone()))
-                    };
-
-                    html! {
-                        <button
-                            class="gap-fill__option"
-                            onclick={on_click}
-                        >
-                            {option}
-                        </button>
-                    }
-                }).collect::<Html>()}
-            </div>
+            if hint_level > 0 {
+                <ul class="gap-fill__letter-hints" aria-live="polite">
+                    {for current_question.gaps.iter().enumerate().map(|(index, gap)| html! {
+                        <li class="gap-fill__letter-hint">
+                            { format!("{} {}: {}…", i18n.t("Gap"), index + 1, revealed_prefix(gap, hint_level)) }
+                        </li>
+                    })}
+                </ul>
+            }
+
+            if let Some(feedback) = &*feedback {
+                <ul class="gap-fill__feedback" aria-live="polite">
+                    {for feedback.iter().zip(&current_question.gaps).map(|(answer_match, gap)| {
+                        let text = match answer_match {
+                            AnswerMatch::Correct => i18n.t("Correct"),
+                            AnswerMatch::AlmostCorrect => {
+                                format!("{} {}", i18n.t("Almost correct:"), gap.correct)
+                            }
+                            AnswerMatch::Incorrect => {
+                                format!("{}{}", i18n.t("Correct answer: "), gap.correct)
+                            }
+                        };
+                        html! {
+                            <li class={classes!(
+                                "gap-fill__feedback-item",
+                                format!("gap-fill__feedback-item--{}", answer_match.modifier())
+                            )}>
+                                {text}
+                            </li>
+                        }
+                    })}
+                </ul>
+            }
+
+            if props.typed {
+                <button class="gap-fill__check" onclick={check_typed.reform(|_| ())}>
+                    {if feedback.is_some() { i18n.t("Next") } else { i18n.t("Check") }}
+                </button>
+            } else {
+                <div class="gap-fill__options">
+                    {current_question.gaps.iter().flat_map(|gap| &gap.options).cloned().map(|option| {
+                        let on_click = {
+                            let option = option.clone();
+                            let handle_option_selection = handle_option_selection.clone();
+                            Callback::from(move |_| handle_option_selection.emit(option.clone()))
+                        };
+
+                        html! {
+                            <button
+                                class="gap-fill__option"
+                                onclick={on_click}
+                            >
+                                {option}
+                            </button>
+                        }
+                    }).collect::<Html>()}
+                </div>
+            }
 
             if *show_help {
                 <div class="gap-fill__hints">
@@ -199,7 +519,7 @@ pub fn gap_fill_component(props: &GapFillComponentProps) -> Html {
                 </div>
             }
 
-            <ChallengeActionsComponent on_action={handle_action} />
+            <ChallengeActionsComponent on_action={handle_action} {hints_left} />
             {read_text}
         </div>
     }
@@ -227,6 +547,16 @@ fn render_sentence(
                     Callback::from(move |_| on_gap_click.emit(gap_index))
                 };
 
+                let on_keydown = {
+                    let on_gap_click = on_gap_click.clone();
+                    Callback::from(move |event: KeyboardEvent| {
+                        if matches!(event.key().as_str(), "Enter" | " ") {
+                            event.prevent_default();
+                            on_gap_click.emit(gap_index);
+                        }
+                    })
+                };
+
                 let gap_class = classes!(
                     "gap-fill__gap",
                     is_selected.then_some("gap-fill__gap--selected"),
@@ -235,7 +565,13 @@ fn render_sentence(
                 html! {
                     <>
                         {html}
-                        <span class={gap_class} onclick={on_click}>
+                        <span
+                            class={gap_class}
+                            role="button"
+                            tabindex="0"
+                            onclick={on_click}
+                            onkeydown={on_keydown}
+                        >
                             {if gap_value.is_empty() { "___" } else { &gap_value }}
                         </span>
                     </>
@@ -247,6 +583,115 @@ fn render_sentence(
     }
 }
 
+fn render_typed_sentence(
+    sentence: &str,
+    current_answers: &[String],
+    feedback: Option<&[AnswerMatch]>,
+    gap_label: &str,
+    on_input: Callback<(usize, String)>,
+    on_commit: Callback<()>,
+    on_submit: Callback<()>,
+) -> Html {
+    let parts: Vec<&str> = sentence.split("__").collect();
+
+    html! {
+        {parts.iter().enumerate().map(|(i, part)| {
+            let html = html! { {part} };
+
+            if i < parts.len() - 1 {
+                let gap_index = i;
+                let gap_value = current_answers.get(gap_index).cloned().unwrap_or_default();
+                let answer_match = feedback.and_then(|feedback| feedback.get(gap_index));
+
+                #[cfg(feature = "csr")]
+                let oninput = {
+                    let on_input = on_input.clone();
+                    Callback::from(move |e: InputEvent| {
+                        if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                            on_input.emit((gap_index, input.value()));
+                        }
+                    })
+                };
+                #[cfg(not(feature = "csr"))]
+                let oninput = {
+                    let _ = &on_input;
+                    Callback::from(|_: InputEvent| ())
+                };
+                let onchange = on_commit.reform(|_: yew::events::Event| ());
+
+                let onkeydown = {
+                    let on_submit = on_submit.clone();
+                    Callback::from(move |event: KeyboardEvent| {
+                        if event.key() == "Enter" {
+                            event.prevent_default();
+                            on_submit.emit(());
+                        }
+                    })
+                };
+
+                html! {
+                    <>
+                        {html}
+                        <input
+                            type="text"
+                            class={classes!(
+                                "gap-fill__input",
+                                answer_match.map(|answer_match| {
+                                    format!("gap-fill__input--{}", answer_match.modifier())
+                                })
+                            )}
+                            value={gap_value}
+                            readonly={feedback.is_some()}
+                            aria-label={format!("{} {}", gap_label, gap_index + 1)}
+                            autocomplete="off"
+                            autocapitalize="off"
+                            spellcheck="false"
+                            {oninput}
+                            {onchange}
+                            {onkeydown}
+                        />
+                    </>
+                }
+            } else {
+                html
+            }
+        }).collect::<Html>()}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konnektoren_core::challenges::Gap;
+
+    #[test]
+    fn checked_gaps_are_locked() {
+        let question = GapFillQuestion {
+            sentence: "Ich bin nach Berlin __.".to_string(),
+            gaps: vec![Gap {
+                position: 0,
+                options: vec![],
+                correct: "gefahren".to_string(),
+            }],
+            hints: vec![],
+            translation: String::new(),
+            explanation: String::new(),
+        };
+
+        let typed = edit_typed_answer(&[], false, 0, "gegangen".to_string()).unwrap();
+        let (recorded, matches) = evaluate_typed_answers(&question, &typed);
+        assert_eq!(recorded, vec!["gegangen"]);
+        assert_eq!(matches, vec![AnswerMatch::Incorrect]);
+
+        // Copying the revealed answer into the gap after Check changes nothing
+        assert_eq!(
+            edit_typed_answer(&typed, true, 0, "gefahren".to_string()),
+            None
+        );
+        assert_eq!(evaluate_typed_answers(&question, &typed).0, recorded);
+    }
+}
+
 #[cfg(feature = "yew-preview")]
 mod preview {
     use super::*;
@@ -265,9 +710,27 @@ mod preview {
     yew_preview::create_preview!(
         GapFillComponent,
         GapFillComponentProps {
+            hints: HintUsage::default(),
+            on_hint: None,
             challenge: create_default_challenge(),
             on_command: None,
             on_event: None,
+            checkpoint: None,
+            on_checkpoint: None,
+            typed: false,
         },
+        (
+            "typed",
+            GapFillComponentProps {
+                hints: HintUsage::default(),
+                on_hint: None,
+                challenge: create_default_challenge(),
+                on_command: None,
+                on_event: None,
+                checkpoint: None,
+                on_checkpoint: None,
+                typed: true,
+            }
+        ),
     );
 }
@@ -1,4 +1,6 @@
+use super::hints::{HintsUsedComponent, hint_mark};
 use crate::i18n::use_i18n;
+use crate::model::HintUsage;
 use konnektoren_core::challenges::{ChallengeResult, GapFill};
 use yew::prelude::*;
 
@@ -6,6 +8,9 @@ use yew::prelude::*;
 pub struct GapFillResultComponentProps {
     pub challenge: GapFill,
     pub challenge_result: ChallengeResult,
+    /// Hints taken per question, marked on the rows.
+    #[prop_or_default]
+    pub hints: HintUsage,
 }
 
 #[function_component(GapFillResultComponent)]
@@ -45,6 +50,7 @@ pub fn gap_fill_result_component(props: &GapFillResultComponentProps) -> Html {
                         <tr class={classes!("gap-fill-result__row", format!("gap-fill-result__row--{}", modifier))}>
                             <td class="gap-fill-result__cell">
                                 {filled_sentence}
+                                { hint_mark(props.hints.level(answer.question_index), &i18n.t("Hints used")) }
                             </td>
                             <td class={classes!("gap-fill-result__cell", format!("gap-fill-result__cell--{}", modifier))}>
                                 {if is_correct {
@@ -75,6 +81,7 @@ pub fn gap_fill_result_component(props: &GapFillResultComponentProps) -> Html {
                     {for results.into_iter()}
                 </tbody>
             </table>
+            <HintsUsedComponent hints={props.hints.clone()} />
         </div>
     }
 }
@@ -182,12 +189,14 @@ mod preview {
     yew_preview::create_preview!(
         GapFillResultComponent,
         GapFillResultComponentProps {
+            hints: HintUsage::default(),
             challenge: create_test_challenge(),
             challenge_result: create_correct_result(),
         },
         (
             "All Incorrect",
             GapFillResultComponentProps {
+                hints: HintUsage::default(),
                 challenge: create_test_challenge(),
                 challenge_result: create_incorrect_result(),
             }
@@ -195,6 +204,7 @@ mod preview {
         (
             "Mixed Results",
             GapFillResultComponentProps {
+                hints: HintUsage::default(),
                 challenge: create_test_challenge(),
                 challenge_result: create_mixed_result(),
             }
@@ -202,6 +212,7 @@ mod preview {
         (
             "Empty Result",
             GapFillResultComponentProps {
+                hints: HintUsage::default(),
                 challenge: create_test_challenge(),
                 challenge_result: ChallengeResult::GapFill(vec![]),
             }
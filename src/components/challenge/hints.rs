@@ -0,0 +1,158 @@
+//! Progressive hints: each hint taken for a question reveals a bit more of
+//! its answer.
+
+use crate::i18n::use_i18n;
+use crate::model::HintUsage;
+use konnektoren_core::challenges::{Gap, MultipleChoice, MultipleChoiceOption};
+use yew::prelude::*;
+
+/// Ids of the wrong options removed by `level` hints. Two options always
+/// remain.
+pub fn eliminated_options(challenge: &MultipleChoice, index: usize, level: usize) -> Vec<usize> {
+    let Some(question) = challenge.questions.get(index) else {
+        return vec![];
+    };
+    eliminated_among(&challenge.options, question.option, level)
+}
+
+/// Ids of the wrong options among those shown removed by `level` hints, for
+/// layouts that show only some of the options. Two options always remain.
+pub fn eliminated_among(
+    options: &[MultipleChoiceOption],
+    correct: usize,
+    level: usize,
+) -> Vec<usize> {
+    options
+        .iter()
+        .map(|option| option.id)
+        .filter(|&id| id != correct)
+        .take(level.min(options.len().saturating_sub(2)))
+        .collect()
+}
+
+/// Number of hints available per multiple choice question.
+pub fn max_eliminated(challenge: &MultipleChoice) -> usize {
+    challenge.options.len().saturating_sub(2)
+}
+
+/// The first `level` letters of the gap's answer.
+pub fn revealed_prefix(gap: &Gap, level: usize) -> String {
+    gap.correct.chars().take(level).collect()
+}
+
+/// Number of hints available for a gap fill question, one per letter of
+/// its longest answer but the last.
+pub fn max_revealed(gaps: &[Gap]) -> usize {
+    gaps.iter()
+        .map(|gap| gap.correct.chars().count().saturating_sub(1))
+        .max()
+        .unwrap_or(0)
+}
+
+/// `order` with the first `fixed` positions moved to their correct element.
+pub fn fix_prefix(order: &[usize], correct_order: &[usize], fixed: usize) -> Vec<usize> {
+    let mut order = order.to_vec();
+    for (position, element) in correct_order.iter().enumerate().take(fixed) {
+        if let Some(current) = order.iter().position(|e| e == element) {
+            order.swap(position, current);
+        }
+    }
+    order
+}
+
+/// Number of hints available for an ordering item; the last element is
+/// never fixed.
+pub fn max_fixed(correct_order: &[usize]) -> usize {
+    correct_order.len().saturating_sub(1)
+}
+
+#[derive(Properties, PartialEq)]
+pub struct HintsUsedComponentProps {
+    pub hints: HintUsage,
+}
+
+/// Total number of hints taken, hidden when none were used.
+#[function_component(HintsUsedComponent)]
+pub fn hints_used_component(props: &HintsUsedComponentProps) -> Html {
+    let i18n = use_i18n();
+    if props.hints.is_empty() {
+        return html! {};
+    }
+    html! {
+        <p class="hints-used">{ format!("{}: {}", i18n.t("Hints used"), props.hints.total()) }</p>
+    }
+}
+
+/// Marks a result row whose question was answered with `level` hints.
+pub fn hint_mark(level: usize, label: &str) -> Html {
+    if level == 0 {
+        return html! {};
+    }
+    html! {
+        <span class="hints-used__mark" aria-label={format!("{}: {}", label, level)}>
+            { format!("💡{}", level) }
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konnektoren_core::challenges::Question;
+
+    fn multiple_choice() -> MultipleChoice {
+        MultipleChoice {
+            id: "articles".to_string(),
+            name: "Articles".to_string(),
+            lang: "de".to_string(),
+            options: ["der", "die", "das", "den"]
+                .iter()
+                .enumerate()
+                .map(|(id, name)| MultipleChoiceOption {
+                    id,
+                    name: name.to_string(),
+                })
+                .collect(),
+            questions: vec![Question {
+                question: "Haus".to_string(),
+                help: String::new(),
+                image: None,
+                option: 2,
+            }],
+        }
+    }
+
+    #[test]
+    fn eliminates_wrong_options_only() {
+        let challenge = multiple_choice();
+        assert!(eliminated_options(&challenge, 0, 0).is_empty());
+        assert_eq!(eliminated_options(&challenge, 0, 1), vec![0]);
+        assert_eq!(eliminated_options(&challenge, 0, 5), vec![0, 1]);
+        assert_eq!(max_eliminated(&challenge), 2);
+        assert!(eliminated_options(&challenge, 3, 1).is_empty());
+
+        let shown = &challenge.options[1..];
+        assert_eq!(eliminated_among(shown, 2, 5), vec![1]);
+    }
+
+    #[test]
+    fn reveals_letters() {
+        let gap = Gap {
+            position: 0,
+            options: vec![],
+            correct: "über".to_string(),
+        };
+        assert_eq!(revealed_prefix(&gap, 0), "");
+        assert_eq!(revealed_prefix(&gap, 2), "üb");
+        assert_eq!(max_revealed(&[gap]), 3);
+    }
+
+    #[test]
+    fn fixes_leading_elements() {
+        let correct = [0, 1, 2, 3];
+        assert_eq!(fix_prefix(&[2, 3, 0, 1], &correct, 1), vec![0, 3, 2, 1]);
+        assert_eq!(fix_prefix(&[2, 3, 0, 1], &correct, 2), vec![0, 1, 2, 3]);
+        assert_eq!(fix_prefix(&[3, 2, 1, 0], &correct, 0), vec![3, 2, 1, 0]);
+        assert_eq!(max_fixed(&correct), 3);
+    }
+}
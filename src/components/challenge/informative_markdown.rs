@@ -1,5 +1,7 @@
+use crate::components::CacheStatusComponent;
 use crate::components::challenge::informative::InformativeComponentProps;
 use crate::i18n::use_i18n;
+use crate::model::CacheStatus;
 use konnektoren_core::asset_loader::AssetLoader;
 use konnektoren_core::challenges::ChallengeResult;
 use konnektoren_core::commands::{ChallengeCommand, Command};
@@ -45,6 +47,7 @@ pub fn informative_markdown_component(props: &InformativeComponentProps) -> Html
     let loading_state = use_state(|| LoadingState::Loading);
     let language = props.language.as_deref().unwrap_or("en");
     let asset_loader = use_state(AssetLoader::default);
+    let cache_status = use_state(|| None::<CacheStatus>);
 
     let on_finish = {
         let on_command = props.on_command.clone();
@@ -97,30 +100,40 @@ pub fn informative_markdown_component(props: &InformativeComponentProps) -> Html
         });
     }
 
-    // CSR support: load content asynchronously
+    // CSR support: load content asynchronously, from the content cache when
+    // offline
     #[cfg(feature = "csr")]
     {
         let loading_state_csr = loading_state.clone();
+        let cache_status_csr = cache_status.clone();
         let markdown_path_csr = markdown_path.clone();
         let fallback_path_csr = fallback_path.clone();
         let asset_loader_csr = asset_loader.clone();
+        let repository = use_context::<crate::providers::RepositoryContext>()
+            .map(|c| c.content_cache_repository);
 
         use_effect_with((), move |_| {
             let markdown_path = markdown_path_csr.clone();
             let fallback_path = fallback_path_csr.clone();
             let loading_state = loading_state_csr.clone();
+            let cache_status = cache_status_csr.clone();
             let asset_loader = asset_loader_csr.clone();
 
             wasm_bindgen_futures::spawn_local(async move {
-                match fetch_markdown_with_loader(&asset_loader, &markdown_path).await {
-                    Ok(content) => loading_state.set(LoadingState::FetchSuccess(content)),
-                    Err(err) => {
-                        tracing::warn!("Failed to fetch markdown {}: {}", markdown_path, err);
-                        match fetch_markdown_with_loader(&asset_loader, &fallback_path).await {
-                            Ok(content) => loading_state.set(LoadingState::FetchSuccess(content)),
-                            Err(err) => loading_state.set(LoadingState::FetchError(err)),
+                let loaded =
+                    match fetch_markdown(repository.clone(), &asset_loader, &markdown_path).await {
+                        Ok(loaded) => Ok(loaded),
+                        Err(err) => {
+                            tracing::warn!("Failed to fetch markdown {}: {}", markdown_path, err);
+                            fetch_markdown(repository, &asset_loader, &fallback_path).await
                         }
+                    };
+                match loaded {
+                    Ok((content, status)) => {
+                        cache_status.set(Some(status));
+                        loading_state.set(LoadingState::FetchSuccess(content));
                     }
+                    Err(err) => loading_state.set(LoadingState::FetchError(err)),
                 }
             });
 
@@ -141,6 +154,9 @@ pub fn informative_markdown_component(props: &InformativeComponentProps) -> Html
             let content = Html::from_html_unchecked(AttrValue::from(html));
             html! {
                 <div class="informative-markdown">
+                    if let Some(status) = *cache_status {
+                        <CacheStatusComponent {status} />
+                    }
                     <div class="markdown-content">
                         {content}
                     </div>
@@ -157,30 +173,28 @@ pub fn informative_markdown_component(props: &InformativeComponentProps) -> Html
     }
 }
 
-async fn fetch_markdown_with_loader(loader: &AssetLoader, path: &str) -> Result<String, String> {
-    // Load the markdown file using the asset loader
-    let binary_data = loader
-        .load_binary(path)
+/// Loads markdown with the asset loader and keeps it in the content cache,
+/// which is used when loading fails. HTML error pages (404, etc.) are
+/// rejected before they are cached.
+#[cfg(feature = "csr")]
+async fn fetch_markdown(
+    repository: Option<std::sync::Arc<dyn crate::repository::ContentCacheRepositoryTrait>>,
+    loader: &AssetLoader,
+    path: &str,
+) -> Result<(String, CacheStatus), String> {
+    use crate::model::ContentKind;
+    use crate::tools::{load_asset, load_cached};
+
+    let load = load_asset(loader, path);
+    let (binary_data, status) = load_cached(repository, path, ContentKind::Markdown, load)
         .await
-        .map_err(|e| format!("Failed to load markdown content: {}", e))?;
+        .map_err(|e| format!("Failed to load markdown content from {}: {}", path, e))?;
 
     // Convert bytes to string
     let content = String::from_utf8(binary_data)
         .map_err(|e| format!("Failed to convert markdown content to string: {}", e))?;
 
-    // Validate that the content is not an HTML error page (404, etc.)
-    let content_lower = content.to_lowercase();
-    if content_lower.contains("<!doctype html")
-        || content_lower.contains("<html")
-        || (content_lower.contains("<script") && content_lower.contains("wasm"))
-    {
-        return Err(format!(
-            "File not found: {} (received HTML error page instead of markdown)",
-            path
-        ));
-    }
-
-    Ok(content)
+    Ok((content, status))
 }
 
 #[cfg(feature = "yew-preview")]
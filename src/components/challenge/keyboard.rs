@@ -0,0 +1,164 @@
+//! Keyboard bindings shared by the challenge components.
+//!
+//! | Key                        | Action                                              |
+//! |----------------------------|-----------------------------------------------------|
+//! | `1`–`9`                    | Choose the option with that number                  |
+//! | `ArrowUp` / `ArrowLeft`    | Move the cursor (or the grabbed element) back       |
+//! | `ArrowDown` / `ArrowRight` | Move the cursor (or the grabbed element) forward    |
+//! | `Space`                    | Grab or drop the element under the cursor           |
+//! | `Tab` / `Shift+Tab`        | Move between gaps and options (native focus order)  |
+//! | `Enter`                    | Fill the focused gap or option, otherwise next task |
+//! | `Escape`                   | Toggle help                                         |
+//!
+//! `Enter` and `Space` are left to the element when a button, gap or option has focus,
+//! so they activate it instead of skipping the task. Form controls such as the
+//! contextual choice `select`s only forward `Escape`.
+
+use super::ChallengeActions;
+use yew::prelude::*;
+
+/// A key press understood by the challenge components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChallengeKey {
+    /// A number key, zero based (`1` is option `0`).
+    Choose(usize),
+    Up,
+    Down,
+    Left,
+    Right,
+    Grab,
+    Confirm,
+    Escape,
+}
+
+impl ChallengeKey {
+    /// Maps a `KeyboardEvent::key` value to a challenge key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "ArrowUp" => Some(Self::Up),
+            "ArrowDown" => Some(Self::Down),
+            "ArrowLeft" => Some(Self::Left),
+            "ArrowRight" => Some(Self::Right),
+            " " | "Spacebar" => Some(Self::Grab),
+            "Enter" => Some(Self::Confirm),
+            "Escape" | "Esc" => Some(Self::Escape),
+            _ => match key.parse::<usize>() {
+                Ok(number @ 1..=9) => Some(Self::Choose(number - 1)),
+                _ => None,
+            },
+        }
+    }
+
+    /// Whether this is one of the arrow keys.
+    pub fn is_arrow(&self) -> bool {
+        matches!(self, Self::Up | Self::Down | Self::Left | Self::Right)
+    }
+
+    /// The challenge action bound to this key, if any.
+    pub fn action(&self) -> Option<ChallengeActions> {
+        match self {
+            Self::Confirm => Some(ChallengeActions::Next),
+            Self::Escape => Some(ChallengeActions::Help),
+            _ => None,
+        }
+    }
+}
+
+/// Moves a cursor within `0..len`, staying in bounds.
+///
+/// `Up`/`Left` move back and `Down`/`Right` move forward.
+pub fn step(index: usize, len: usize, key: ChallengeKey) -> usize {
+    match key {
+        ChallengeKey::Up | ChallengeKey::Left => index.saturating_sub(1),
+        ChallengeKey::Down | ChallengeKey::Right if index + 1 < len => index + 1,
+        _ => index,
+    }
+}
+
+#[cfg(feature = "csr")]
+fn should_handle(event: &KeyboardEvent, key: ChallengeKey) -> bool {
+    use web_sys::Element;
+
+    let Some(target) = event.target_dyn_into::<Element>() else {
+        return true;
+    };
+    let tag = target.tag_name().to_ascii_uppercase();
+    let role = target.get_attribute("role").unwrap_or_default();
+
+    match tag.as_str() {
+        "INPUT" | "SELECT" | "TEXTAREA" => key == ChallengeKey::Escape,
+        "BUTTON" => !matches!(key, ChallengeKey::Confirm | ChallengeKey::Grab),
+        _ if matches!(role.as_str(), "button" | "radio") => {
+            !matches!(key, ChallengeKey::Confirm | ChallengeKey::Grab)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(not(feature = "csr"))]
+fn should_handle(_event: &KeyboardEvent, _key: ChallengeKey) -> bool {
+    true
+}
+
+/// Creates an `onkeydown` handler that forwards recognised keys.
+pub fn on_challenge_keydown(on_key: Callback<ChallengeKey>) -> Callback<KeyboardEvent> {
+    Callback::from(move |event: KeyboardEvent| {
+        if event.ctrl_key() || event.alt_key() || event.meta_key() {
+            return;
+        }
+        if let Some(key) = ChallengeKey::from_key(&event.key())
+            && should_handle(&event, key)
+        {
+            event.prevent_default();
+            on_key.emit(key);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_keys_choose_options() {
+        assert_eq!(ChallengeKey::from_key("1"), Some(ChallengeKey::Choose(0)));
+        assert_eq!(ChallengeKey::from_key("9"), Some(ChallengeKey::Choose(8)));
+        assert_eq!(ChallengeKey::from_key("0"), None);
+        assert_eq!(ChallengeKey::from_key("10"), None);
+    }
+
+    #[test]
+    fn test_navigation_keys() {
+        assert_eq!(ChallengeKey::from_key("ArrowUp"), Some(ChallengeKey::Up));
+        assert_eq!(
+            ChallengeKey::from_key("ArrowRight"),
+            Some(ChallengeKey::Right)
+        );
+        assert!(ChallengeKey::Left.is_arrow());
+        assert!(!ChallengeKey::Grab.is_arrow());
+        assert_eq!(ChallengeKey::from_key(" "), Some(ChallengeKey::Grab));
+        assert_eq!(ChallengeKey::from_key("a"), None);
+    }
+
+    #[test]
+    fn test_enter_and_escape_map_to_actions() {
+        assert!(matches!(
+            ChallengeKey::from_key("Enter").and_then(|key| key.action()),
+            Some(ChallengeActions::Next)
+        ));
+        assert!(matches!(
+            ChallengeKey::from_key("Escape").and_then(|key| key.action()),
+            Some(ChallengeActions::Help)
+        ));
+        assert!(ChallengeKey::Grab.action().is_none());
+    }
+
+    #[test]
+    fn test_step_stays_in_bounds() {
+        assert_eq!(step(0, 3, ChallengeKey::Up), 0);
+        assert_eq!(step(1, 3, ChallengeKey::Left), 0);
+        assert_eq!(step(1, 3, ChallengeKey::Down), 2);
+        assert_eq!(step(2, 3, ChallengeKey::Right), 2);
+        assert_eq!(step(1, 3, ChallengeKey::Grab), 1);
+    }
+}
@@ -0,0 +1,585 @@
+//! Matching pairs over a two-column sort table: every term of the first
+//! column is paired with one of the shuffled values of the second.
+
+use super::keyboard::step;
+use super::{ChallengeKey, on_challenge_keydown};
+use crate::i18n::use_i18n;
+use crate::model::ChallengeCheckpoint;
+use crate::tools::{seed_from, shuffled_indices};
+use konnektoren_core::challenges::{ChallengeResult, SortTable, SortTableRow};
+use konnektoren_core::commands::{ChallengeCommand, Command};
+use konnektoren_core::events::{ChallengeEvent, Event};
+use yew::prelude::*;
+
+/// Id of the second column of a sort table that is played as matching
+/// pairs.
+pub const MATCHING_COLUMN_ID: &str = "match";
+
+/// Whether a sort table is played as matching pairs: it has two columns,
+/// the second one with the id [`MATCHING_COLUMN_ID`], and every row has a
+/// value for both.
+///
+/// `ChallengeVariant` is defined in `konnektoren-core` and has no matching
+/// variant, so the content marks the mode with the column id.
+pub fn is_matching_table(table: &SortTable) -> bool {
+    table.columns.len() == 2
+        && table.columns[1].id == MATCHING_COLUMN_ID
+        && !table.rows.is_empty()
+        && table.rows.iter().all(|row| row.values.len() == 2)
+}
+
+/// Values of the second column in the order given by `seed`.
+pub fn matching_options(table: &SortTable, seed: u64) -> Vec<String> {
+    shuffled_indices(table.rows.len(), seed, 1)
+        .into_iter()
+        .filter_map(|index| table.rows.get(index)?.values.get(1).cloned())
+        .collect()
+}
+
+/// Pairs term `term` with option `option`, taking the option away from
+/// any other term.
+pub fn pair(pairs: &mut [Option<usize>], term: usize, option: usize) {
+    for paired in pairs.iter_mut() {
+        if *paired == Some(option) {
+            *paired = None;
+        }
+    }
+    if let Some(paired) = pairs.get_mut(term) {
+        *paired = Some(option);
+    }
+}
+
+/// The pairs as sort table rows, with an empty value for unpaired terms.
+pub fn matching_rows(
+    table: &SortTable,
+    options: &[String],
+    pairs: &[Option<usize>],
+) -> Vec<SortTableRow> {
+    table
+        .rows
+        .iter()
+        .zip(pairs)
+        .map(|(row, paired)| SortTableRow {
+            id: row.id,
+            values: vec![
+                row.values.first().cloned().unwrap_or_default(),
+                paired
+                    .and_then(|option| options.get(option).cloned())
+                    .unwrap_or_default(),
+            ],
+        })
+        .collect()
+}
+
+/// One event per term once the learner finishes: correct when it was
+/// paired with its own match.
+pub fn matching_events(
+    table: &SortTable,
+    options: &[String],
+    pairs: &[Option<usize>],
+) -> Vec<ChallengeEvent> {
+    table
+        .rows
+        .iter()
+        .zip(pairs)
+        .enumerate()
+        .map(|(term, (row, paired))| {
+            let given = paired.and_then(|option| options.get(option));
+            if given.is_some() && given == row.values.get(1) {
+                ChallengeEvent::SolvedCorrect(term)
+            } else {
+                ChallengeEvent::SolvedIncorrect(term)
+            }
+        })
+        .collect()
+}
+
+/// Pairs saved in `rows`, e.g. from a checkpoint.
+pub fn pairs_from_rows(rows: &[SortTableRow], options: &[String]) -> Vec<Option<usize>> {
+    let mut pairs = vec![None; rows.len()];
+    for (term, row) in rows.iter().enumerate() {
+        let Some(value) = row.values.get(1).filter(|value| !value.is_empty()) else {
+            continue;
+        };
+        // Equal values may appear more than once; take a free one
+        if let Some(option) = (0..options.len())
+            .find(|&option| options[option] == *value && !pairs.contains(&Some(option)))
+        {
+            pairs[term] = Some(option);
+        }
+    }
+    pairs
+}
+
+/// An element of either column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchingSide {
+    Term(usize),
+    Option(usize),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MatchingComponentProps {
+    pub challenge: SortTable,
+    #[prop_or_default]
+    pub on_event: Option<Callback<Event>>,
+    #[prop_or_default]
+    pub on_command: Option<Callback<Command>>,
+    /// Saved progress to continue from.
+    #[prop_or_default]
+    pub checkpoint: Option<ChallengeCheckpoint>,
+    #[prop_or_default]
+    pub on_checkpoint: Option<Callback<ChallengeCheckpoint>>,
+    /// Seed for the order of the options. Defaults to one derived from the
+    /// challenge id.
+    #[prop_or_default]
+    pub seed: Option<u64>,
+}
+
+/// Pair terms and options by tapping one of each, by dragging an option
+/// onto a term, or with the keyboard: arrow keys to move, `Space` to pick
+/// and `Enter` to finish.
+#[function_component(MatchingComponent)]
+pub fn matching_component(props: &MatchingComponentProps) -> Html {
+    let i18n = use_i18n();
+    let challenge = &props.challenge;
+    let seed = props.seed.unwrap_or_else(|| seed_from(&[&challenge.id]));
+    let options = matching_options(challenge, seed);
+
+    let pairs = use_state(|| match props.checkpoint.as_ref() {
+        Some(ChallengeCheckpoint {
+            challenge_result: ChallengeResult::SortTable(rows),
+            ..
+        }) if rows.len() == challenge.rows.len() => pairs_from_rows(rows, &options),
+        _ => vec![None; challenge.rows.len()],
+    });
+    let selected = use_state(|| None::<MatchingSide>);
+    let focused = use_state(|| MatchingSide::Term(0));
+    let dragged = use_state(|| None::<usize>);
+    let drop_target = use_state(|| None::<usize>);
+
+    // Pairs are tentative until the learner finishes, so pairing them
+    // reports nothing that could give the right match away
+    let handle_pair = {
+        let pairs = pairs.clone();
+        let selected = selected.clone();
+        Callback::from(move |(term, option): (usize, usize)| {
+            let mut updated = (*pairs).clone();
+            pair(&mut updated, term, option);
+            pairs.set(updated);
+            selected.set(None);
+        })
+    };
+
+    let handle_unpair = {
+        let pairs = pairs.clone();
+        Callback::from(move |term: usize| {
+            let mut updated = (*pairs).clone();
+            if let Some(paired) = updated.get_mut(term) {
+                *paired = None;
+            }
+            pairs.set(updated);
+        })
+    };
+
+    let handle_select = {
+        let selected = selected.clone();
+        let focused = focused.clone();
+        let handle_pair = handle_pair.clone();
+        Callback::from(move |side: MatchingSide| {
+            focused.set(side);
+            match (*selected, side) {
+                (Some(MatchingSide::Option(option)), MatchingSide::Term(term))
+                | (Some(MatchingSide::Term(term)), MatchingSide::Option(option)) => {
+                    handle_pair.emit((term, option));
+                }
+                (Some(current), _) if current == side => selected.set(None),
+                _ => selected.set(Some(side)),
+            }
+        })
+    };
+
+    let handle_drag_start = {
+        let dragged = dragged.clone();
+        Callback::from(move |(event, option): (DragEvent, usize)| {
+            #[cfg(feature = "csr")]
+            {
+                if let Some(data_transfer) = event.data_transfer() {
+                    let _ = data_transfer.set_data("text/plain", &option.to_string());
+                }
+                dragged.set(Some(option));
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (&dragged, event, option);
+        })
+    };
+
+    let handle_touch_start = {
+        let dragged = dragged.clone();
+        Callback::from(move |option: usize| dragged.set(Some(option)))
+    };
+
+    let handle_drag_over = {
+        let drop_target = drop_target.clone();
+        Callback::from(move |(event, term): (DragEvent, usize)| {
+            #[cfg(feature = "csr")]
+            {
+                event.prevent_default();
+                drop_target.set(Some(term));
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (&drop_target, event, term);
+        })
+    };
+
+    let handle_drop = {
+        let dragged = dragged.clone();
+        let drop_target = drop_target.clone();
+        let handle_pair = handle_pair.clone();
+        Callback::from(move |(event, term): (DragEvent, usize)| {
+            #[cfg(feature = "csr")]
+            {
+                event.prevent_default();
+                if let Some(option) = *dragged {
+                    handle_pair.emit((term, option));
+                }
+                dragged.set(None);
+                drop_target.set(None);
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (&dragged, &drop_target, &handle_pair, event, term);
+        })
+    };
+
+    let handle_drag_end = {
+        let dragged = dragged.clone();
+        let drop_target = drop_target.clone();
+        Callback::from(move |_: ()| {
+            dragged.set(None);
+            drop_target.set(None);
+        })
+    };
+
+    // Touch drags end over the term under the finger, found by position
+    // because touch events keep targeting the element they started on
+    let handle_touch_end = {
+        let dragged = dragged.clone();
+        let handle_pair = handle_pair.clone();
+        let handle_drag_end = handle_drag_end.clone();
+        Callback::from(move |event: TouchEvent| {
+            #[cfg(feature = "csr")]
+            {
+                if let (Some(option), Some(touch)) = (*dragged, event.changed_touches().get(0))
+                    && let Some(term) = web_sys::window()
+                        .and_then(|window| window.document())
+                        .and_then(|document| {
+                            document.element_from_point(
+                                touch.client_x() as f32,
+                                touch.client_y() as f32,
+                            )
+                        })
+                        .and_then(|element| element.closest("[data-term-index]").ok().flatten())
+                        .and_then(|element| element.get_attribute("data-term-index"))
+                        .and_then(|index| index.parse::<usize>().ok())
+                {
+                    event.prevent_default();
+                    handle_pair.emit((term, option));
+                }
+                handle_drag_end.emit(());
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (&dragged, &handle_pair, &handle_drag_end, event);
+        })
+    };
+
+    // Checkpoint every change, but not the initial state
+    {
+        let on_checkpoint = props.on_checkpoint.clone();
+        let challenge = challenge.clone();
+        let options = options.clone();
+        let is_initial = use_mut_ref(|| true);
+        use_effect_with((*pairs).clone(), move |pairs| {
+            if !std::mem::replace(&mut *is_initial.borrow_mut(), false)
+                && let Some(on_checkpoint) = on_checkpoint.as_ref()
+            {
+                on_checkpoint.emit(ChallengeCheckpoint::new(
+                    0,
+                    ChallengeResult::SortTable(matching_rows(&challenge, &options, pairs)),
+                ));
+            }
+            || ()
+        });
+    }
+
+    let handle_finish = {
+        let on_event = props.on_event.clone();
+        let on_command = props.on_command.clone();
+        let pairs = pairs.clone();
+        let challenge = challenge.clone();
+        let options = options.clone();
+        Callback::from(move |_: ()| {
+            if let Some(on_event) = on_event.as_ref() {
+                for event in matching_events(&challenge, &options, &pairs) {
+                    on_event.emit(Event::Challenge(event));
+                }
+            }
+            let result = ChallengeResult::SortTable(matching_rows(&challenge, &options, &pairs));
+            if let Some(on_command) = on_command.as_ref() {
+                on_command.emit(Command::Challenge(ChallengeCommand::Finish(Some(result))));
+            }
+        })
+    };
+
+    let handle_key = {
+        let focused = focused.clone();
+        let handle_select = handle_select.clone();
+        let handle_finish = handle_finish.clone();
+        let len = challenge.rows.len();
+        on_challenge_keydown(Callback::from(move |key: ChallengeKey| {
+            match (key, *focused) {
+                (ChallengeKey::Up | ChallengeKey::Down, MatchingSide::Term(index)) => {
+                    focused.set(MatchingSide::Term(step(index, len, key)));
+                }
+                (ChallengeKey::Up | ChallengeKey::Down, MatchingSide::Option(index)) => {
+                    focused.set(MatchingSide::Option(step(index, len, key)));
+                }
+                (ChallengeKey::Right, MatchingSide::Term(index)) => {
+                    focused.set(MatchingSide::Option(index));
+                }
+                (ChallengeKey::Left, MatchingSide::Option(index)) => {
+                    focused.set(MatchingSide::Term(index));
+                }
+                (ChallengeKey::Grab, side) => handle_select.emit(side),
+                (ChallengeKey::Confirm, _) => handle_finish.emit(()),
+                _ => {}
+            }
+        }))
+    };
+
+    let paired_count = pairs.iter().flatten().count();
+    let pick_label = i18n.t("Pick a term, then its match");
+
+    html! {
+        <div class="matching" tabindex="0" onkeydown={handle_key} aria-label={pick_label}>
+            <h1 class="matching__title">{ &challenge.name }</h1>
+            <p class="matching__description">{ &challenge.description }</p>
+            <p class="matching__progress" aria-live="polite">
+                { format!("{} / {}", paired_count, challenge.rows.len()) }
+            </p>
+            <div class="matching__columns">
+                <ul class="matching__terms" role="listbox" aria-label={challenge.columns.first().map(|column| column.title.clone())}>
+                    { for challenge.rows.iter().enumerate().map(|(term, row)| {
+                        let side = MatchingSide::Term(term);
+                        let paired = pairs.get(term).copied().flatten();
+                        html! {
+                            <li
+                                key={row.id}
+                                class={classes!(
+                                    "matching__term",
+                                    (*selected == Some(side)).then_some("matching__term--selected"),
+                                    (*focused == side).then_some("matching__term--focused"),
+                                    (*drop_target == Some(term)).then_some("matching__term--drop-target"),
+                                    paired.is_some().then_some("matching__term--paired")
+                                )}
+                                data-term-index={term.to_string()}
+                                role="option"
+                                aria-selected={(*selected == Some(side)).to_string()}
+                                onclick={handle_select.reform(move |_: MouseEvent| side)}
+                                ondragover={handle_drag_over.reform(move |event| (event, term))}
+                                ondrop={handle_drop.reform(move |event| (event, term))}
+                            >
+                                <span class="matching__term-text">
+                                    { row.values.first().cloned().unwrap_or_default() }
+                                </span>
+                                if let Some(option) = paired {
+                                    <span class="matching__pair">
+                                        { options.get(option).cloned().unwrap_or_default() }
+                                        <button
+                                            class="matching__unpair"
+                                            aria-label={i18n.t("Remove pair")}
+                                            onclick={handle_unpair.reform(move |event: MouseEvent| {
+                                                event.stop_propagation();
+                                                term
+                                            })}
+                                        >
+                                            <i class="fa-solid fa-xmark" aria-hidden="true"></i>
+                                        </button>
+                                    </span>
+                                } else {
+                                    <span class="matching__slot" aria-hidden="true"></span>
+                                }
+                            </li>
+                        }
+                    }) }
+                </ul>
+                <ul class="matching__options" role="listbox" aria-label={challenge.columns.get(1).map(|column| column.title.clone())}>
+                    { for options.iter().enumerate().map(|(option, value)| {
+                        let side = MatchingSide::Option(option);
+                        let is_paired = pairs.contains(&Some(option));
+                        html! {
+                            <li
+                                key={option}
+                                class={classes!(
+                                    "matching__option",
+                                    (*selected == Some(side)).then_some("matching__option--selected"),
+                                    (*focused == side).then_some("matching__option--focused"),
+                                    (*dragged == Some(option)).then_some("matching__option--dragging"),
+                                    is_paired.then_some("matching__option--paired")
+                                )}
+                                draggable="true"
+                                role="option"
+                                aria-selected={(*selected == Some(side)).to_string()}
+                                onclick={handle_select.reform(move |_: MouseEvent| side)}
+                                ondragstart={handle_drag_start.reform(move |event: DragEvent| (event, option))}
+                                ondragend={handle_drag_end.reform(|_: DragEvent| ())}
+                                ontouchstart={handle_touch_start.reform(move |_: TouchEvent| option)}
+                                ontouchend={handle_touch_end.clone()}
+                            >
+                                { value }
+                            </li>
+                        }
+                    }) }
+                </ul>
+            </div>
+            <div class="matching__actions">
+                <button class="matching__finish" onclick={handle_finish.reform(|_| ())}>
+                    { i18n.t("Finish") }
+                </button>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konnektoren_core::challenges::sort_table::SortTableColumn;
+
+    fn articles() -> SortTable {
+        SortTable {
+            id: "articles".to_string(),
+            columns: ["article", MATCHING_COLUMN_ID]
+                .iter()
+                .map(|id| SortTableColumn {
+                    id: id.to_string(),
+                    title: id.to_string(),
+                    description: String::new(),
+                })
+                .collect(),
+            rows: [("der", "Tisch"), ("die", "Lampe"), ("das", "Haus")]
+                .iter()
+                .enumerate()
+                .map(|(id, (article, noun))| SortTableRow {
+                    id,
+                    values: vec![article.to_string(), noun.to_string()],
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn only_two_column_tables_match() {
+        let mut table = articles();
+        assert!(is_matching_table(&table));
+        table.rows[0].values.push("den".to_string());
+        assert!(!is_matching_table(&table));
+        assert!(!is_matching_table(&SortTable::default()));
+
+        // Plain two-column sort tables stay sort tables
+        let mut table = articles();
+        table.columns[1].id = "noun".to_string();
+        assert!(!is_matching_table(&table));
+    }
+
+    #[test]
+    fn events_are_reported_per_term_on_finish() {
+        let table = articles();
+        let options = vec!["Haus".to_string(), "Tisch".to_string(), "Lampe".to_string()];
+        let events = matching_events(&table, &options, &[Some(1), Some(0), None]);
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], ChallengeEvent::SolvedCorrect(0)));
+        assert!(matches!(events[1], ChallengeEvent::SolvedIncorrect(1)));
+        assert!(matches!(events[2], ChallengeEvent::SolvedIncorrect(2)));
+    }
+
+    #[test]
+    fn options_are_a_stable_permutation() {
+        let table = articles();
+        let mut options = matching_options(&table, 7);
+        assert_eq!(options, matching_options(&table, 7));
+        options.sort();
+        assert_eq!(options, ["Haus", "Lampe", "Tisch"]);
+    }
+
+    #[test]
+    fn pairing_moves_an_option() {
+        let mut pairs = vec![None; 3];
+        pair(&mut pairs, 0, 2);
+        pair(&mut pairs, 1, 2);
+        assert_eq!(pairs, [None, Some(2), None]);
+    }
+
+    #[test]
+    fn rows_round_trip_through_pairs() {
+        let table = articles();
+        let options = vec!["Haus".to_string(), "Tisch".to_string(), "Lampe".to_string()];
+        let pairs = vec![Some(1), None, Some(0)];
+        let rows = matching_rows(&table, &options, &pairs);
+        assert_eq!(rows[0].values, ["der", "Tisch"]);
+        assert_eq!(rows[1].values, ["die", ""]);
+        assert_eq!(pairs_from_rows(&rows, &options), pairs);
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use konnektoren_core::challenges::sort_table::SortTableColumn;
+    use yew_preview::prelude::*;
+
+    fn create_test_challenge() -> SortTable {
+        SortTable {
+            id: "word-translation".to_string(),
+            name: "Word and Translation".to_string(),
+            description: "Match every word with its translation".to_string(),
+            columns: vec![
+                SortTableColumn {
+                    id: "de".to_string(),
+                    title: "Deutsch".to_string(),
+                    description: String::new(),
+                },
+                SortTableColumn {
+                    id: MATCHING_COLUMN_ID.to_string(),
+                    title: "English".to_string(),
+                    description: String::new(),
+                },
+            ],
+            rows: [
+                ("der Apfel", "the apple"),
+                ("das Haus", "the house"),
+                ("die Katze", "the cat"),
+                ("der Hund", "the dog"),
+            ]
+            .iter()
+            .enumerate()
+            .map(|(id, (word, translation))| SortTableRow {
+                id,
+                values: vec![word.to_string(), translation.to_string()],
+            })
+            .collect(),
+        }
+    }
+
+    yew_preview::create_preview!(
+        MatchingComponent,
+        MatchingComponentProps {
+            challenge: create_test_challenge(),
+            on_event: None,
+            on_command: None,
+            checkpoint: None,
+            on_checkpoint: None,
+            seed: None,
+        },
+    );
+}
@@ -0,0 +1,95 @@
+use crate::i18n::use_i18n;
+use konnektoren_core::challenges::{ChallengeResult, SortTable};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct MatchingResultComponentProps {
+    pub challenge: SortTable,
+    pub challenge_result: ChallengeResult,
+}
+
+/// Every term with the match given, and the correct one where it was
+/// wrong.
+#[function_component(MatchingResultComponent)]
+pub fn matching_result_component(props: &MatchingResultComponentProps) -> Html {
+    let i18n = use_i18n();
+    let ChallengeResult::SortTable(rows) = &props.challenge_result else {
+        return html! {};
+    };
+
+    html! {
+        <div class="matching-result">
+            <h2 class="matching-result__title">{ i18n.t("Challenge Result") }</h2>
+            <ul class="matching-result__list">
+                { for props.challenge.rows.iter().zip(rows).map(|(expected, given)| {
+                    let term = expected.values.first().cloned().unwrap_or_default();
+                    let correct = expected.values.get(1).cloned().unwrap_or_default();
+                    let answer = given.values.get(1).cloned().unwrap_or_default();
+                    let is_correct = answer == correct;
+                    html! {
+                        <li
+                            key={expected.id}
+                            class={classes!(
+                                "matching-result__item",
+                                if is_correct { "matching-result__item--correct" } else { "matching-result__item--incorrect" }
+                            )}
+                        >
+                            <span class="matching-result__term">{ term }</span>
+                            <span class="matching-result__answer">
+                                if answer.is_empty() {
+                                    { "—" }
+                                } else {
+                                    { answer }
+                                }
+                            </span>
+                            if !is_correct {
+                                <span class="matching-result__correct">
+                                    { format!("{}: {}", i18n.t("Correct"), correct) }
+                                </span>
+                            }
+                        </li>
+                    }
+                }) }
+            </ul>
+        </div>
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use konnektoren_core::challenges::SortTableRow;
+    use yew_preview::prelude::*;
+
+    fn row(id: usize, term: &str, translation: &str) -> SortTableRow {
+        SortTableRow {
+            id,
+            values: vec![term.to_string(), translation.to_string()],
+        }
+    }
+
+    fn create_test_challenge() -> SortTable {
+        SortTable {
+            id: "word-translation".to_string(),
+            name: "Word and Translation".to_string(),
+            rows: vec![
+                row(0, "der Apfel", "the apple"),
+                row(1, "das Haus", "the house"),
+                row(2, "die Katze", "the cat"),
+            ],
+            ..Default::default()
+        }
+    }
+
+    yew_preview::create_preview!(
+        MatchingResultComponent,
+        MatchingResultComponentProps {
+            challenge: create_test_challenge(),
+            challenge_result: ChallengeResult::SortTable(vec![
+                row(0, "der Apfel", "the apple"),
+                row(1, "das Haus", "the cat"),
+                row(2, "die Katze", ""),
+            ]),
+        },
+    );
+}
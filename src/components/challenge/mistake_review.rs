@@ -0,0 +1,248 @@
+use super::ChallengeComponent;
+use super::multiple_choice::is_correct;
+use crate::i18n::use_i18n;
+use crate::providers::RepositoryContext;
+use konnektoren_core::challenges::{Challenge, ChallengeResult, ChallengeType, ChallengeVariant};
+use konnektoren_core::commands::{ChallengeCommand, Command};
+use yew::prelude::*;
+
+/// Indices of the questions answered incorrectly. Empty for challenge types
+/// without per-question answers.
+pub fn incorrect_indices(challenge: &Challenge, result: &ChallengeResult) -> Vec<usize> {
+    match (&challenge.challenge_type, result) {
+        (ChallengeType::MultipleChoice(mc), ChallengeResult::MultipleChoice(answers)) => {
+            let answered = answers.len().min(mc.questions.len());
+            (0..answered)
+                .filter(|&index| !is_correct(mc, result, index))
+                .collect()
+        }
+        (ChallengeType::GapFill(gap_fill), ChallengeResult::GapFill(answers)) => answers
+            .iter()
+            .filter(|answer| answer.question_index < gap_fill.questions.len())
+            .filter(|answer| !gap_fill.check_answer(answer))
+            .map(|answer| answer.question_index)
+            .collect(),
+        (ChallengeType::ContextualChoice(cc), ChallengeResult::ContextualChoice(answers)) => cc
+            .items
+            .iter()
+            .zip(answers)
+            .enumerate()
+            .filter(|(_, (item, answer))| {
+                !item
+                    .choices
+                    .iter()
+                    .zip(&answer.ids)
+                    .all(|(choice, &id)| choice.options.get(id) == Some(&choice.correct_answer))
+            })
+            .map(|(index, _)| index)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// A copy of `challenge` reduced to the questions answered incorrectly, with
+/// an empty result and its own id so it is recorded apart from the original.
+pub fn mistakes_challenge(challenge: &Challenge, result: &ChallengeResult) -> Option<Challenge> {
+    let indices = incorrect_indices(challenge, result);
+    if indices.is_empty() {
+        return None;
+    }
+    let keep = |index: &usize| indices.contains(index);
+
+    let mut review = challenge.clone();
+    match &mut review.challenge_type {
+        ChallengeType::MultipleChoice(mc) => retain_indices(&mut mc.questions, keep),
+        ChallengeType::GapFill(gap_fill) => retain_indices(&mut gap_fill.questions, keep),
+        ChallengeType::ContextualChoice(cc) => retain_indices(&mut cc.items, keep),
+        _ => return None,
+    }
+    review.challenge_config.id = format!("{}-mistakes", challenge.challenge_config.id);
+    review.challenge_result = ChallengeResult::default();
+    review.start_time = None;
+    review.end_time = None;
+    Some(review)
+}
+
+fn retain_indices<T>(items: &mut Vec<T>, keep: impl Fn(&usize) -> bool) {
+    let mut index = 0;
+    items.retain(|_| {
+        let retained = keep(&index);
+        index += 1;
+        retained
+    });
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MistakeReviewComponentProps {
+    /// The reduced challenge, see [`mistakes_challenge`].
+    pub challenge: Challenge,
+    /// Variant the original challenge was played with.
+    #[prop_or_default]
+    pub variant: Option<ChallengeVariant>,
+    pub on_close: Callback<()>,
+    /// Don't record the outcome.
+    #[prop_or(false)]
+    pub preview: bool,
+}
+
+/// Replays a reduced challenge outside the game controller, so no XP is
+/// awarded. The outcome is stored with the session repository's mistake
+/// reviews instead of the challenge history.
+#[function_component(MistakeReviewComponent)]
+pub fn mistake_review_component(props: &MistakeReviewComponentProps) -> Html {
+    let i18n = use_i18n();
+    let attempt_repository = use_context::<RepositoryContext>()
+        .filter(|_| !props.preview)
+        .map(|context| context.attempt_repository);
+    let start_time = use_state(chrono::Utc::now);
+
+    let on_command = {
+        let challenge = props.challenge.clone();
+        let start_time = *start_time;
+        Callback::from(move |command: Command| {
+            if let Command::Challenge(ChallengeCommand::Finish(Some(result))) = command {
+                let mut reviewed = challenge.clone();
+                reviewed.challenge_result = result;
+                reviewed.start_time = Some(start_time);
+                reviewed.end_time = Some(chrono::Utc::now());
+
+                #[cfg(feature = "csr")]
+                {
+                    if let Some(attempt_repository) = attempt_repository.clone() {
+                        wasm_bindgen_futures::spawn_local(async move {
+                            if let Err(e) = attempt_repository.add_mistake_review(&reviewed).await {
+                                tracing::error!("Failed to save mistake review: {:?}", e);
+                            }
+                        });
+                    }
+                }
+                #[cfg(not(feature = "csr"))]
+                let _ = (&attempt_repository, reviewed);
+            }
+        })
+    };
+
+    html! {
+        <div class="mistake-review">
+            <div class="mistake-review__header">
+                <h2 class="mistake-review__title">{ i18n.t("Review mistakes") }</h2>
+                <button class="mistake-review__close" onclick={props.on_close.reform(|_| ())}>
+                    { i18n.t("Back to results") }
+                </button>
+            </div>
+            <p class="mistake-review__note">{ i18n.t("This practice round does not earn XP.") }</p>
+            <ChallengeComponent challenge={props.challenge.clone()} variant={props.variant.clone()} {on_command} preview={true} />
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konnektoren_core::challenges::{
+        Gap, GapFill, GapFillAnswer, GapFillQuestion, MultipleChoice, MultipleChoiceOption,
+        Question,
+    };
+
+    fn multiple_choice() -> Challenge {
+        let question = |question: &str, option: usize| Question {
+            question: question.to_string(),
+            help: String::new(),
+            image: None,
+            option,
+        };
+        Challenge {
+            challenge_type: ChallengeType::MultipleChoice(MultipleChoice {
+                id: "articles".to_string(),
+                name: "Articles".to_string(),
+                lang: "de".to_string(),
+                options: vec![
+                    MultipleChoiceOption {
+                        id: 0,
+                        name: "der".to_string(),
+                    },
+                    MultipleChoiceOption {
+                        id: 1,
+                        name: "die".to_string(),
+                    },
+                ],
+                questions: vec![
+                    question("Tisch", 0),
+                    question("Lampe", 1),
+                    question("Hund", 0),
+                ],
+            }),
+            ..Challenge::default()
+        }
+    }
+
+    fn answers(ids: &[usize]) -> ChallengeResult {
+        ChallengeResult::MultipleChoice(
+            ids.iter()
+                .map(|&id| MultipleChoiceOption {
+                    id,
+                    name: String::new(),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn multiple_choice_mistakes() {
+        let challenge = multiple_choice();
+        let result = answers(&[0, 0, 1]);
+        assert_eq!(incorrect_indices(&challenge, &result), vec![1, 2]);
+
+        let review = mistakes_challenge(&challenge, &result).unwrap();
+        let ChallengeType::MultipleChoice(mc) = &review.challenge_type else {
+            panic!("expected multiple choice");
+        };
+        let questions: Vec<_> = mc.questions.iter().map(|q| q.question.as_str()).collect();
+        assert_eq!(questions, ["Lampe", "Hund"]);
+        assert_eq!(review.challenge_result, ChallengeResult::default());
+        assert!(review.challenge_config.id.ends_with("-mistakes"));
+    }
+
+    #[test]
+    fn no_review_without_mistakes() {
+        let challenge = multiple_choice();
+        assert!(mistakes_challenge(&challenge, &answers(&[0, 1, 0])).is_none());
+    }
+
+    #[test]
+    fn gap_fill_mistakes() {
+        let question = |correct: &str| GapFillQuestion {
+            sentence: "Ich __ nach Hause.".to_string(),
+            gaps: vec![Gap {
+                position: 0,
+                options: vec!["gehe".to_string(), "gehst".to_string()],
+                correct: correct.to_string(),
+            }],
+            hints: vec![],
+            translation: String::new(),
+            explanation: String::new(),
+        };
+        let challenge = Challenge {
+            challenge_type: ChallengeType::GapFill(GapFill {
+                id: "present".to_string(),
+                name: "Present tense".to_string(),
+                description: String::new(),
+                lang: "de".to_string(),
+                questions: vec![question("gehe"), question("gehe")],
+            }),
+            ..Challenge::default()
+        };
+        let answer = |question_index: usize, answer: &str| GapFillAnswer {
+            question_index,
+            answers: vec![answer.to_string()],
+        };
+        let result = ChallengeResult::GapFill(vec![answer(0, "gehe"), answer(1, "gehst")]);
+
+        assert_eq!(incorrect_indices(&challenge, &result), vec![1]);
+        let review = mistakes_challenge(&challenge, &result).unwrap();
+        let ChallengeType::GapFill(gap_fill) = &review.challenge_type else {
+            panic!("expected gap fill");
+        };
+        assert_eq!(gap_fill.questions.len(), 1);
+    }
+}
@@ -12,12 +12,18 @@ pub mod custom_result;
 pub mod dialog;
 pub mod dialog_observer_result;
 pub mod dialog_result;
+pub mod flashcards;
 pub mod gap_fill;
 pub mod gap_fill_result;
 pub mod help;
+pub mod hints;
 pub mod informative;
 pub mod informative_markdown;
 pub mod informative_result;
+pub mod keyboard;
+pub mod matching;
+pub mod matching_result;
+pub mod mistake_review;
 pub mod multiple_choice;
 pub mod multiple_choice_4;
 pub mod multiple_choice_circle;
@@ -26,12 +32,18 @@ pub mod options;
 pub mod ordering;
 pub mod ordering_result;
 pub mod placeholder;
+pub mod practice_feedback;
 pub mod question;
+pub mod result_actions;
 pub mod result_score;
 pub mod result_summary;
+pub mod resume_prompt;
+pub mod sandbox_frame;
 pub mod sort_table;
 pub mod sort_table_result;
+pub mod typed_answer;
 pub mod vocabulary;
+pub mod vocabulary_result;
 
 pub use actions::{ChallengeActions, ChallengeActionsComponent};
 pub use challenge::ChallengeComponent;
@@ -47,23 +59,34 @@ pub use custom_result::CustomResultComponent;
 pub use dialog::{DialogComponent, DialogComponentProps};
 pub use dialog_observer_result::DialogObserverResultComponent;
 pub use dialog_result::DialogResultComponent;
+pub use flashcards::FlashcardsComponent;
 pub use gap_fill::GapFillComponent;
 pub use gap_fill_result::GapFillResultComponent;
 pub use help::ChallengeHelpComponent;
 pub use informative::InformativeComponent;
 pub use informative_markdown::InformativeMarkdownComponent;
 pub use informative_result::InformativeResultComponent;
+pub use keyboard::{ChallengeKey, on_challenge_keydown};
+pub use matching::{MATCHING_COLUMN_ID, MatchingComponent, is_matching_table};
+pub use matching_result::MatchingResultComponent;
+pub use mistake_review::{MistakeReviewComponent, incorrect_indices, mistakes_challenge};
 pub use multiple_choice::{MultipleChoiceComponent, MultipleChoiceComponentProps};
-pub use multiple_choice_4::MultipleChoice4Component;
+pub use multiple_choice_4::{MultipleChoice4Component, MultipleChoice4ComponentProps};
 pub use multiple_choice_circle::MultipleChoiceCircleComponent;
 pub use multiple_choice_result::MultipleChoiceResultComponent;
 pub use options::OptionsComponent;
 pub use ordering::OrderingComponent;
 pub use ordering_result::OrderingResultComponent;
 pub use placeholder::PlaceholderComponent;
+pub use practice_feedback::{PracticeFeedback, PracticeFeedbackComponent};
 pub use question::QuestionComponent;
+pub use result_actions::ResultActionsComponent;
 pub use result_score::ResultScoreComponent;
 pub use result_summary::ResultSummaryComponent;
+pub use resume_prompt::ResumePromptComponent;
+pub use sandbox_frame::{SandboxFrame, challenge_translations, sandbox_document};
 pub use sort_table::SortTableComponent;
 pub use sort_table_result::SortTableResultComponent;
+pub use typed_answer::{AnswerMatch, match_answer, normalize_answer};
 pub use vocabulary::VocabularyComponent;
+pub use vocabulary_result::VocabularyResultComponent;
@@ -1,7 +1,12 @@
-use super::{ChallengeActions, ChallengeActionsComponent, OptionsComponent, QuestionComponent};
-use crate::components::ProgressBar;
+use super::hints::{eliminated_options, max_eliminated};
+use super::{
+    ChallengeActions, ChallengeActionsComponent, ChallengeKey, OptionsComponent, QuestionComponent,
+    on_challenge_keydown,
+};
 use crate::components::challenge::MultipleChoiceResultComponent;
+use crate::components::{ProgressBar, use_focus_on_change};
 use crate::i18n::use_i18n;
+use crate::model::{ChallengeCheckpoint, HintUsage};
 #[cfg(feature = "effects")]
 use crate::prelude::ReadText;
 use konnektoren_core::challenges::{
@@ -18,6 +23,18 @@ pub struct MultipleChoiceComponentProps {
     pub on_event: Option<Callback<Event>>,
     #[prop_or_default]
     pub on_command: Option<Callback<Command>>,
+    /// Saved progress to continue from.
+    #[prop_or_default]
+    pub checkpoint: Option<ChallengeCheckpoint>,
+    #[prop_or_default]
+    pub on_checkpoint: Option<Callback<ChallengeCheckpoint>>,
+    /// Hints taken so far; each one removes a wrong option.
+    #[prop_or_default]
+    pub hints: HintUsage,
+    /// Called with the question index when a hint is requested. Without it
+    /// there is no hint button.
+    #[prop_or_default]
+    pub on_hint: Option<Callback<usize>>,
 }
 
 pub fn is_correct(
@@ -36,11 +53,20 @@ pub fn is_correct(
     }
 }
 
+/// Id of the option chosen for question `index`, if any.
+pub fn selected_option(challenge_result: &ChallengeResult, index: usize) -> Option<usize> {
+    match challenge_result {
+        ChallengeResult::MultipleChoice(mc) => mc.get(index).map(|option| option.id),
+        _ => None,
+    }
+}
+
 pub fn create_handle_action(
     task_index: UseStateHandle<usize>,
     show_help: UseStateHandle<bool>,
     total_tasks: usize,
     on_command: Option<Callback<Command>>,
+    on_hint: Option<Callback<usize>>,
 ) -> Callback<ChallengeActions> {
     Callback::from(move |action: ChallengeActions| match action {
         ChallengeActions::Next => {
@@ -66,9 +92,33 @@ pub fn create_handle_action(
         ChallengeActions::Help => {
             show_help.set(!*show_help);
         }
+        ChallengeActions::Hint => {
+            if let Some(on_hint) = on_hint.as_ref() {
+                on_hint.emit(*task_index);
+            }
+        }
     })
 }
 
+pub fn create_handle_key(
+    options: Vec<MultipleChoiceOption>,
+    handle_option_selection: Callback<MultipleChoiceOption>,
+    handle_action: Callback<ChallengeActions>,
+) -> Callback<KeyboardEvent> {
+    on_challenge_keydown(Callback::from(move |key: ChallengeKey| match key {
+        ChallengeKey::Choose(index) => {
+            if let Some(option) = options.get(index) {
+                handle_option_selection.emit(option.clone());
+            }
+        }
+        key => {
+            if let Some(action) = key.action() {
+                handle_action.emit(action);
+            }
+        }
+    }))
+}
+
 pub fn create_handle_option_selection(
     task_index: UseStateHandle<usize>,
     challenge: MultipleChoice,
@@ -76,6 +126,7 @@ pub fn create_handle_option_selection(
     total_tasks: usize,
     on_command: Option<Callback<Command>>,
     on_event: Option<Callback<Event>>,
+    on_checkpoint: Option<Callback<ChallengeCheckpoint>>,
 ) -> Callback<MultipleChoiceOption> {
     Callback::from(move |option: MultipleChoiceOption| {
         let mut challenge_result_update = (*challenge_result).clone();
@@ -100,6 +151,13 @@ pub fn create_handle_option_selection(
             let next_task_index = *task_index + 1;
             task_index.set(next_task_index);
 
+            if let Some(on_checkpoint) = on_checkpoint.as_ref() {
+                on_checkpoint.emit(ChallengeCheckpoint::new(
+                    next_task_index,
+                    challenge_result_update.clone(),
+                ));
+            }
+
             if let Some(on_command) = on_command.as_ref() {
                 on_command.emit(Command::Challenge(ChallengeCommand::NextTask));
             }
@@ -114,8 +172,20 @@ pub fn create_handle_option_selection(
 #[function_component(MultipleChoiceComponent)]
 pub fn multiple_choice_component(props: &MultipleChoiceComponentProps) -> Html {
     let i18n = use_i18n();
-    let task_index = use_state(|| 0);
-    let challenge_result = use_state(ChallengeResult::default);
+    let task_index = use_state(|| {
+        props.checkpoint.as_ref().map_or(0, |checkpoint| {
+            checkpoint.resume_index(props.challenge.questions.len())
+        })
+    });
+    let container_ref = use_node_ref();
+    use_focus_on_change(container_ref.clone(), *task_index);
+    let challenge_result = use_state(|| {
+        props
+            .checkpoint
+            .as_ref()
+            .map(|checkpoint| checkpoint.challenge_result.clone())
+            .unwrap_or_default()
+    });
     let show_help = use_state(|| false);
 
     if *task_index >= props.challenge.questions.len() {
@@ -127,8 +197,19 @@ pub fn multiple_choice_component(props: &MultipleChoiceComponentProps) -> Html {
         show_help.clone(),
         props.challenge.questions.len(),
         props.on_command.clone(),
+        props.on_hint.clone(),
     );
 
+    let eliminated = eliminated_options(
+        &props.challenge,
+        *task_index,
+        props.hints.level(*task_index),
+    );
+    let hints_left = props
+        .on_hint
+        .as_ref()
+        .map(|_| max_eliminated(&props.challenge).saturating_sub(eliminated.len()));
+
     let handle_option_selection = create_handle_option_selection(
         task_index.clone(),
         props.challenge.clone(),
@@ -136,6 +217,22 @@ pub fn multiple_choice_component(props: &MultipleChoiceComponentProps) -> Html {
         props.challenge.questions.len(),
         props.on_command.clone(),
         props.on_event.clone(),
+        props.on_checkpoint.clone(),
+    );
+    // Eliminated options can't be chosen, not even with the keyboard
+    let handle_option_selection = {
+        let eliminated = eliminated.clone();
+        Callback::from(move |option: MultipleChoiceOption| {
+            if !eliminated.contains(&option.id) {
+                handle_option_selection.emit(option);
+            }
+        })
+    };
+
+    let handle_key = create_handle_key(
+        props.challenge.options.clone(),
+        handle_option_selection.clone(),
+        handle_action.clone(),
     );
 
     let read_text = {
@@ -155,7 +252,7 @@ pub fn multiple_choice_component(props: &MultipleChoiceComponentProps) -> Html {
     };
 
     html! {
-        <div class="multiple-choice">
+        <div class="multiple-choice" ref={container_ref} tabindex="0" onkeydown={handle_key}>
             <ProgressBar
                 value={*task_index}
                 max={props.challenge.questions.len()}
@@ -168,11 +265,14 @@ pub fn multiple_choice_component(props: &MultipleChoiceComponentProps) -> Html {
             <OptionsComponent
                 options={props.challenge.options.clone()}
                 on_select={handle_option_selection}
+                selected={selected_option(&challenge_result, *task_index)}
+                {eliminated}
             />
-            <ChallengeActionsComponent on_action={handle_action} />
+            <ChallengeActionsComponent on_action={handle_action} {hints_left} />
             <MultipleChoiceResultComponent
                 challenge={props.challenge.clone()}
                 challenge_result={(*challenge_result).clone()}
+                hints={props.hints.clone()}
             />
             {read_text}
         </div>
@@ -208,16 +308,24 @@ mod preview {
     yew_preview::create_preview!(
         MultipleChoiceComponent,
         MultipleChoiceComponentProps {
+            hints: HintUsage::default(),
+            on_hint: None,
             challenge: create_default_challenge(),
             on_command: None,
             on_event: None,
+            checkpoint: None,
+            on_checkpoint: None,
         },
         (
             "Articles",
             MultipleChoiceComponentProps {
+                hints: HintUsage::default(),
+                on_hint: None,
                 challenge: create_articles_challenge(),
                 on_event: None,
                 on_command: None,
+                checkpoint: None,
+                on_checkpoint: None,
             }
         ),
     );
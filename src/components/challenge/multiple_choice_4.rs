@@ -1,18 +1,49 @@
-use super::{OptionsComponent, QuestionComponent};
-use crate::components::ProgressBar;
+use super::hints::eliminated_among;
+use super::{ChallengeActionsComponent, OptionsComponent, QuestionComponent};
 use crate::components::challenge::multiple_choice::{
-    MultipleChoiceComponentProps, create_handle_option_selection,
+    create_handle_action, create_handle_key, create_handle_option_selection, selected_option,
 };
+use crate::components::{ProgressBar, use_focus_on_change};
 use crate::i18n::{use_i18n, use_selected_language};
+use crate::model::{ChallengeCheckpoint, HintUsage};
 #[cfg(feature = "effects")]
 use crate::prelude::ReadText;
-use konnektoren_core::challenges::{ChallengeResult, MultipleChoice, MultipleChoiceOption};
-use rand::prelude::{SeedableRng, SliceRandom, StdRng};
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
+use crate::tools::{SeededRng, seed_from};
+use konnektoren_core::challenges::{MultipleChoice, MultipleChoiceOption};
+use konnektoren_core::commands::Command;
+use konnektoren_core::events::Event;
 use yew::prelude::*;
 
-fn get_4_options(challenge: &MultipleChoice, question_index: usize) -> Vec<MultipleChoiceOption> {
+#[derive(Properties, PartialEq, Default)]
+pub struct MultipleChoice4ComponentProps {
+    pub challenge: MultipleChoice,
+    #[prop_or_default]
+    pub on_event: Option<Callback<Event>>,
+    #[prop_or_default]
+    pub on_command: Option<Callback<Command>>,
+    /// Saved progress to continue from.
+    #[prop_or_default]
+    pub checkpoint: Option<ChallengeCheckpoint>,
+    #[prop_or_default]
+    pub on_checkpoint: Option<Callback<ChallengeCheckpoint>>,
+    /// Hints taken so far; each one removes a wrong option of the four shown.
+    #[prop_or_default]
+    pub hints: HintUsage,
+    /// Called with the question index when a hint is requested. Without it
+    /// there is no hint button.
+    #[prop_or_default]
+    pub on_hint: Option<Callback<usize>>,
+    /// Seed that picks and shuffles the four options of each question.
+    /// Defaults to one derived from the challenge id.
+    #[prop_or_default]
+    pub seed: Option<u64>,
+}
+
+fn get_4_options(
+    challenge: &MultipleChoice,
+    question_index: usize,
+    seed: u64,
+) -> Vec<MultipleChoiceOption> {
     let question = &challenge.questions[question_index];
     let correct_id = question.option;
 
@@ -27,16 +58,11 @@ fn get_4_options(challenge: &MultipleChoice, question_index: usize) -> Vec<Multi
         .cloned()
         .collect();
 
-    // --- Deterministic seeding ---
-    // Hash challenge id and question index together for the seed
-    let mut hasher = DefaultHasher::new();
-    challenge.id.hash(&mut hasher);
-    question_index.hash(&mut hasher);
-    let seed = hasher.finish();
-    let mut rng = StdRng::seed_from_u64(seed);
+    // Every question draws from its own stream of the seed
+    let mut rng = SeededRng::for_stream(seed, question_index as u64);
 
     // Shuffle distractors and pick 3
-    distractors.shuffle(&mut rng);
+    rng.shuffle(&mut distractors);
     let mut selected_options = Vec::new();
 
     if let Some(correct) = correct_option.cloned() {
@@ -45,25 +71,61 @@ fn get_4_options(challenge: &MultipleChoice, question_index: usize) -> Vec<Multi
     selected_options.extend(distractors.into_iter().take(3));
 
     // Shuffle the final 4 options so the correct answer is not always first
-    selected_options.shuffle(&mut rng);
+    rng.shuffle(&mut selected_options);
 
     selected_options
 }
 
 #[function_component(MultipleChoice4Component)]
-pub fn multiple_choice_4_component(props: &MultipleChoiceComponentProps) -> Html {
+pub fn multiple_choice_4_component(props: &MultipleChoice4ComponentProps) -> Html {
     let i18n = use_i18n();
     let selected_language = use_selected_language();
     let lang_code = selected_language.get().code();
 
-    let task_index = use_state(|| 0);
-    let challenge_result = use_state(ChallengeResult::default);
+    let task_index = use_state(|| {
+        props.checkpoint.as_ref().map_or(0, |checkpoint| {
+            checkpoint.resume_index(props.challenge.questions.len())
+        })
+    });
+    let container_ref = use_node_ref();
+    use_focus_on_change(container_ref.clone(), *task_index);
+    let challenge_result = use_state(|| {
+        props
+            .checkpoint
+            .as_ref()
+            .map(|checkpoint| checkpoint.challenge_result.clone())
+            .unwrap_or_default()
+    });
     let show_help = use_state(|| false);
 
     if *task_index >= props.challenge.questions.len() {
         return html! {};
     }
 
+    let seed = props
+        .seed
+        .unwrap_or_else(|| seed_from(&[&props.challenge.id]));
+    let options = get_4_options(&props.challenge, *task_index, seed);
+
+    let handle_action = create_handle_action(
+        task_index.clone(),
+        show_help.clone(),
+        props.challenge.questions.len(),
+        props.on_command.clone(),
+        props.on_hint.clone(),
+    );
+
+    // Hints remove wrong options from the four shown
+    let eliminated = eliminated_among(
+        &options,
+        props.challenge.questions[*task_index].option,
+        props.hints.level(*task_index),
+    );
+    let hints_left = props
+        .on_hint
+        .as_ref()
+        .map(|_| options.len().saturating_sub(2) - eliminated.len());
+
     let handle_option_selection = create_handle_option_selection(
         task_index.clone(),
         props.challenge.clone(),
@@ -71,7 +133,17 @@ pub fn multiple_choice_4_component(props: &MultipleChoiceComponentProps) -> Html
         props.challenge.questions.len(),
         props.on_command.clone(),
         props.on_event.clone(),
+        props.on_checkpoint.clone(),
     );
+    // Eliminated options can't be chosen, not even with the keyboard
+    let handle_option_selection = {
+        let eliminated = eliminated.clone();
+        Callback::from(move |option: MultipleChoiceOption| {
+            if !eliminated.contains(&option.id) {
+                handle_option_selection.emit(option);
+            }
+        })
+    };
 
     let read_text = {
         #[cfg(feature = "effects")]
@@ -89,7 +161,11 @@ pub fn multiple_choice_4_component(props: &MultipleChoiceComponentProps) -> Html
         }
     };
 
-    let options = get_4_options(&props.challenge, *task_index);
+    let handle_key = create_handle_key(
+        options.clone(),
+        handle_option_selection.clone(),
+        handle_action.clone(),
+    );
 
     // Prepare translated question and help
     let question = {
@@ -100,7 +176,7 @@ pub fn multiple_choice_4_component(props: &MultipleChoiceComponentProps) -> Html
     };
 
     html! {
-        <div class="multiple-choice-4">
+        <div class="multiple-choice-4" ref={container_ref} tabindex="0" onkeydown={handle_key}>
             <ProgressBar
                 value={*task_index}
                 max={props.challenge.questions.len()}
@@ -114,8 +190,11 @@ pub fn multiple_choice_4_component(props: &MultipleChoiceComponentProps) -> Html
                 <OptionsComponent
                     options={options}
                     on_select={handle_option_selection}
+                    selected={selected_option(&challenge_result, *task_index)}
+                    {eliminated}
                 />
             </div>
+            <ChallengeActionsComponent on_action={handle_action} {hints_left} />
             {read_text}
         </div>
     }
@@ -140,10 +219,15 @@ mod preview {
 
     yew_preview::create_preview!(
         MultipleChoice4Component,
-        MultipleChoiceComponentProps {
+        MultipleChoice4ComponentProps {
+            seed: None,
+            hints: HintUsage::default(),
+            on_hint: None,
             challenge: create_default_challenge(),
             on_command: None,
             on_event: None,
+            checkpoint: None,
+            on_checkpoint: None,
         },
     );
 }
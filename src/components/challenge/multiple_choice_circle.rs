@@ -1,23 +1,55 @@
-use super::{OptionsComponent, QuestionComponent};
-use crate::components::ProgressBar;
+use super::hints::{eliminated_options, max_eliminated};
+use super::{ChallengeActionsComponent, OptionsComponent, QuestionComponent};
 use crate::components::challenge::multiple_choice::{
-    MultipleChoiceComponentProps, create_handle_option_selection,
+    MultipleChoiceComponentProps, create_handle_action, create_handle_key,
+    create_handle_option_selection, selected_option,
 };
+use crate::components::{ProgressBar, use_focus_on_change};
 #[cfg(feature = "effects")]
 use crate::prelude::ReadText;
-use konnektoren_core::challenges::ChallengeResult;
+use konnektoren_core::challenges::MultipleChoiceOption;
 use yew::prelude::*;
 
 #[function_component(MultipleChoiceCircleComponent)]
 pub fn multiple_choice_circle_component(props: &MultipleChoiceComponentProps) -> Html {
-    let task_index = use_state(|| 0);
-    let challenge_result = use_state(ChallengeResult::default);
+    let task_index = use_state(|| {
+        props.checkpoint.as_ref().map_or(0, |checkpoint| {
+            checkpoint.resume_index(props.challenge.questions.len())
+        })
+    });
+    let container_ref = use_node_ref();
+    use_focus_on_change(container_ref.clone(), *task_index);
+    let challenge_result = use_state(|| {
+        props
+            .checkpoint
+            .as_ref()
+            .map(|checkpoint| checkpoint.challenge_result.clone())
+            .unwrap_or_default()
+    });
     let show_help = use_state(|| false);
 
     if *task_index >= props.challenge.questions.len() {
         return html! {};
     }
 
+    let handle_action = create_handle_action(
+        task_index.clone(),
+        show_help.clone(),
+        props.challenge.questions.len(),
+        props.on_command.clone(),
+        props.on_hint.clone(),
+    );
+
+    let eliminated = eliminated_options(
+        &props.challenge,
+        *task_index,
+        props.hints.level(*task_index),
+    );
+    let hints_left = props
+        .on_hint
+        .as_ref()
+        .map(|_| max_eliminated(&props.challenge).saturating_sub(eliminated.len()));
+
     let handle_option_selection = create_handle_option_selection(
         task_index.clone(),
         props.challenge.clone(),
@@ -25,6 +57,22 @@ pub fn multiple_choice_circle_component(props: &MultipleChoiceComponentProps) ->
         props.challenge.questions.len(),
         props.on_command.clone(),
         props.on_event.clone(),
+        props.on_checkpoint.clone(),
+    );
+    // Eliminated options can't be chosen, not even with the keyboard
+    let handle_option_selection = {
+        let eliminated = eliminated.clone();
+        Callback::from(move |option: MultipleChoiceOption| {
+            if !eliminated.contains(&option.id) {
+                handle_option_selection.emit(option);
+            }
+        })
+    };
+
+    let handle_key = create_handle_key(
+        props.challenge.options.clone(),
+        handle_option_selection.clone(),
+        handle_action.clone(),
     );
 
     let read_text = {
@@ -44,7 +92,7 @@ pub fn multiple_choice_circle_component(props: &MultipleChoiceComponentProps) ->
     };
 
     html! {
-        <div class="multiple-choice-circle">
+        <div class="multiple-choice-circle" ref={container_ref} tabindex="0" onkeydown={handle_key}>
             <ProgressBar
                 value={*task_index}
                 max={props.challenge.questions.len()}
@@ -54,12 +102,15 @@ pub fn multiple_choice_circle_component(props: &MultipleChoiceComponentProps) ->
                 <OptionsComponent
                     options={props.challenge.options.clone()}
                     on_select={handle_option_selection}
+                    selected={selected_option(&challenge_result, *task_index)}
+                    {eliminated}
                 />
                 <QuestionComponent
                     question={props.challenge.questions[*task_index].clone()}
                     help={*show_help}
                 />
             </div>
+            <ChallengeActionsComponent on_action={handle_action} {hints_left} />
             {read_text}
         </div>
     }
@@ -68,6 +119,7 @@ pub fn multiple_choice_circle_component(props: &MultipleChoiceComponentProps) ->
 #[cfg(feature = "yew-preview")]
 mod preview {
     use super::*;
+    use crate::model::HintUsage;
     use konnektoren_core::prelude::{ChallengeType, Game, MultipleChoiceOption, Question};
     use yew_preview::prelude::*;
 
@@ -152,24 +204,36 @@ mod preview {
     yew_preview::create_preview!(
         MultipleChoiceCircleComponent,
         MultipleChoiceComponentProps {
+            hints: HintUsage::default(),
+            on_hint: None,
             challenge: create_default_challenge(),
             on_command: None,
             on_event: None,
+            checkpoint: None,
+            on_checkpoint: None,
         },
         (
             "Articles",
             MultipleChoiceComponentProps {
+                hints: HintUsage::default(),
+                on_hint: None,
                 challenge: create_articles_challenge(),
                 on_command: None,
                 on_event: None,
+                checkpoint: None,
+                on_checkpoint: None,
             }
         ),
         (
             "Spanish",
             MultipleChoiceComponentProps {
+                hints: HintUsage::default(),
+                on_hint: None,
                 challenge: create_spanish_challenge(),
                 on_command: None,
                 on_event: None,
+                checkpoint: None,
+                on_checkpoint: None,
             }
         )
     );
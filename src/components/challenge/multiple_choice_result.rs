@@ -1,4 +1,6 @@
+use super::hints::{HintsUsedComponent, hint_mark};
 use crate::i18n::use_i18n;
+use crate::model::HintUsage;
 use konnektoren_core::challenges::{ChallengeResult, MultipleChoice};
 use yew::prelude::*;
 
@@ -6,6 +8,9 @@ use yew::prelude::*;
 pub struct MultipleChoiceResultComponentProps {
     pub challenge: MultipleChoice,
     pub challenge_result: ChallengeResult,
+    /// Hints taken per question, marked on the rows.
+    #[prop_or_default]
+    pub hints: HintUsage,
 }
 
 #[function_component(MultipleChoiceResultComponent)]
@@ -17,7 +22,8 @@ pub fn multiple_choice_result_component(props: &MultipleChoiceResultComponentPro
             .questions
             .iter()
             .zip(options.iter())
-            .map(|(question, option)| {
+            .enumerate()
+            .map(|(index, (question, option))| {
                 let is_correct = question.option == option.id;
                 let modifier = if is_correct { "correct" } else { "incorrect" };
                 let correct_name = props
@@ -32,6 +38,7 @@ pub fn multiple_choice_result_component(props: &MultipleChoiceResultComponentPro
                     <tr class={classes!("multiple-choice-result__row", format!("multiple-choice-result__row--{}", modifier))}>
                         <td class="multiple-choice-result__cell">
                             { &question.question }
+                            { hint_mark(props.hints.level(index), &i18n.t("Hints used")) }
                         </td>
                         <td class={classes!("multiple-choice-result__cell", format!("multiple-choice-result__cell--{}", modifier))}>
                             if is_correct {
@@ -64,6 +71,7 @@ pub fn multiple_choice_result_component(props: &MultipleChoiceResultComponentPro
                     {for results.into_iter()}
                 </tbody>
             </table>
+            <HintsUsedComponent hints={props.hints.clone()} />
         </div>
     }
 }
@@ -119,6 +127,7 @@ mod preview {
     yew_preview::create_preview_with_tests!(
         component: MultipleChoiceResultComponent,
         default_props: MultipleChoiceResultComponentProps {
+            hints: HintUsage::default(),
             challenge: articles_challenge(),
             challenge_result: {
                 let ch = articles_challenge();
@@ -129,6 +138,7 @@ mod preview {
             (
                 "Mixed results",
                 MultipleChoiceResultComponentProps {
+                    hints: HintUsage::default(),
                     challenge: articles_challenge(),
                     challenge_result: {
                         let ch = articles_challenge();
@@ -139,6 +149,7 @@ mod preview {
             (
                 "All incorrect",
                 MultipleChoiceResultComponentProps {
+                    hints: HintUsage::default(),
                     challenge: articles_challenge(),
                     challenge_result: {
                         let ch = articles_challenge();
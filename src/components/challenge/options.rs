@@ -1,3 +1,4 @@
+use crate::i18n::use_i18n;
 use yew::prelude::*;
 
 use konnektoren_core::challenges::MultipleChoiceOption;
@@ -6,33 +7,70 @@ use konnektoren_core::challenges::MultipleChoiceOption;
 pub struct OptionsComponentProps {
     pub options: Vec<MultipleChoiceOption>,
     pub on_select: Option<Callback<MultipleChoiceOption>>,
+    /// Id of the currently chosen option, if any.
+    #[prop_or_default]
+    pub selected: Option<usize>,
+    /// Ids of options removed by hints; shown struck through and not
+    /// selectable.
+    #[prop_or_default]
+    pub eliminated: Vec<usize>,
 }
 
 #[function_component(OptionsComponent)]
 pub fn options_component(props: &OptionsComponentProps) -> Html {
+    let i18n = use_i18n();
+
     html! {
-        <div class="multiple-choice-options">
-            {for props.options.iter().map(|option| render_option(option, &props.on_select))}
+        <div class="multiple-choice-options" role="radiogroup" aria-label={i18n.t("Answer options")}>
+            {for props.options.iter().map(|option| {
+                render_option(
+                    option,
+                    props.selected == Some(option.id),
+                    props.eliminated.contains(&option.id),
+                    &props.on_select,
+                )
+            })}
         </div>
     }
 }
 
 fn render_option(
     option: &MultipleChoiceOption,
+    is_selected: bool,
+    is_eliminated: bool,
     on_select: &Option<Callback<MultipleChoiceOption>>,
 ) -> Html {
     let option_clone = option.clone();
-    let on_select = on_select.clone();
-    let on_select = Callback::from(move |_| {
+    let on_select = on_select.clone().filter(|_| !is_eliminated);
+    let on_select = Callback::from(move |_: ()| {
         let option = option_clone.clone();
         if let Some(on_select) = on_select.as_ref() {
             on_select.emit(option);
         }
     });
 
+    let on_keydown = {
+        let on_select = on_select.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if matches!(e.key().as_str(), "Enter" | " ") {
+                e.prevent_default();
+                on_select.emit(());
+            }
+        })
+    };
+
     html! {
         <div class="multiple-choice-option">
-            <span id={option.id.to_string()} onclick={on_select} >
+            <span
+                id={option.id.to_string()}
+                class={classes!(is_selected.then_some("selected"), is_eliminated.then_some("eliminated"))}
+                role="radio"
+                tabindex={if is_eliminated { "-1" } else { "0" }}
+                aria-checked={is_selected.to_string()}
+                aria-disabled={is_eliminated.then_some("true")}
+                onclick={on_select.reform(|_| ())}
+                onkeydown={on_keydown}
+            >
                 {&option.name}
             </span>
         </div>
@@ -47,6 +85,7 @@ mod preview {
     yew_preview::create_preview!(
         OptionsComponent,
         OptionsComponentProps {
+            eliminated: vec![],
             options: vec![
                 MultipleChoiceOption {
                     id: 1,
@@ -62,6 +101,7 @@ mod preview {
                 },
             ],
             on_select: None,
+            selected: None,
         },
     );
 }
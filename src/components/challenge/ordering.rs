@@ -1,10 +1,13 @@
-use super::{ChallengeActions, ChallengeActionsComponent};
-use crate::components::ProgressBar;
+use super::hints::{fix_prefix, max_fixed};
+use super::keyboard::step;
+use super::{ChallengeActions, ChallengeActionsComponent, ChallengeKey, on_challenge_keydown};
+use crate::components::{ProgressBar, use_focus_on_change};
 use crate::i18n::use_i18n;
+use crate::model::{ChallengeCheckpoint, HintUsage};
+use crate::tools::{seed_from, shuffled_indices};
 use konnektoren_core::challenges::{ChallengeInput, ChallengeResult, Ordering, OrderingResult};
 use konnektoren_core::commands::{ChallengeCommand, Command};
 use konnektoren_core::events::{ChallengeEvent, Event};
-use rand::prelude::{SliceRandom, thread_rng};
 use yew::prelude::*;
 
 // Props for the OrderingElement component
@@ -13,7 +16,9 @@ struct OrderingElementProps {
     index: usize,
     element: String,
     is_selected: bool,
+    is_focused: bool,
     is_dragging: bool,
+    is_fixed: bool,
     show_drop_indicator: bool,
     on_click: Callback<usize>,
     on_drag_start: Callback<DragEvent>,
@@ -45,9 +50,15 @@ fn ordering_element(props: &OrderingElementProps) -> Html {
                 class={classes!(
                     "ordering__element",
                     props.is_dragging.then_some("ordering__element--dragging"),
-                    props.is_selected.then_some("ordering__element--selected")
+                    props.is_selected.then_some("ordering__element--selected"),
+                    props.is_focused.then_some("ordering__element--focused"),
+                    props.is_fixed.then_some("ordering__element--fixed")
                 )}
-                draggable="true"
+                id={format!("ordering-element-{}", props.index)}
+                role="option"
+                aria-selected={props.is_selected.to_string()}
+                aria-disabled={props.is_fixed.then_some("true")}
+                draggable={(!props.is_fixed).to_string()}
                 data-index={props.index.to_string()}
                 onclick={onclick}
                 ondragstart={props.on_drag_start.clone()}
@@ -68,9 +79,14 @@ fn ordering_element(props: &OrderingElementProps) -> Html {
 #[derive(Properties, PartialEq)]
 struct OrderingListProps {
     elements: Vec<String>,
+    label: AttrValue,
+    list_ref: NodeRef,
     selected_index: Option<usize>,
+    focused_index: Option<usize>,
     dragged_index: Option<usize>,
     drop_target_index: Option<usize>,
+    /// Number of leading elements fixed by hints.
+    fixed: usize,
     on_click: Callback<usize>,
     on_drag_start: Callback<DragEvent>,
     on_drag_over: Callback<DragEvent>,
@@ -85,10 +101,18 @@ struct OrderingListProps {
 #[function_component(OrderingList)]
 fn ordering_list(props: &OrderingListProps) -> Html {
     html! {
-        <div class="ordering__elements-list">
+        <div
+            class="ordering__elements-list"
+            ref={props.list_ref.clone()}
+            role="listbox"
+            tabindex="0"
+            aria-label={props.label.clone()}
+            aria-activedescendant={props.focused_index.map(|index| format!("ordering-element-{}", index))}
+        >
             {props.elements.iter().enumerate().map(|(index, element)| {
                 let is_dragging = props.dragged_index == Some(index);
                 let is_selected = props.selected_index == Some(index);
+                let is_focused = props.focused_index == Some(index);
                 let show_drop_indicator = props.drop_target_index == Some(index);
 
                 html! {
@@ -97,7 +121,9 @@ fn ordering_list(props: &OrderingListProps) -> Html {
                         {index}
                         element={element.clone()}
                         {is_selected}
+                        {is_focused}
                         {is_dragging}
+                        is_fixed={index < props.fixed}
                         {show_drop_indicator}
                         on_click={props.on_click.clone()}
                         on_drag_start={props.on_drag_start.clone()}
@@ -121,28 +147,82 @@ pub struct OrderingComponentProps {
     pub on_event: Option<Callback<Event>>,
     #[prop_or_default]
     pub on_command: Option<Callback<Command>>,
+    /// Saved progress to continue from.
+    #[prop_or_default]
+    pub checkpoint: Option<ChallengeCheckpoint>,
+    #[prop_or_default]
+    pub on_checkpoint: Option<Callback<ChallengeCheckpoint>>,
+    /// Hints taken so far; each one fixes another leading element in its
+    /// correct position.
+    #[prop_or_default]
+    pub hints: HintUsage,
+    /// Called with the item index when a hint is requested. Without it there
+    /// is no hint button.
+    #[prop_or_default]
+    pub on_hint: Option<Callback<usize>>,
+    /// Seed for the shuffled order of the elements. Defaults to one derived
+    /// from the challenge id.
+    #[prop_or_default]
+    pub seed: Option<u64>,
 }
 
 #[function_component(OrderingComponent)]
 pub fn ordering_component(props: &OrderingComponentProps) -> Html {
     let i18n = use_i18n();
-    let current_item = use_state(|| 0);
+    let current_item = use_state(|| {
+        props.checkpoint.as_ref().map_or(0, |checkpoint| {
+            checkpoint.resume_index(props.challenge.items.len())
+        })
+    });
     let dragged_index = use_state(|| None::<usize>);
     let drop_target_index = use_state(|| None::<usize>);
     let selected_index = use_state(|| None::<usize>);
-    // Initialize with shuffled order
+    let focused_index = use_state(|| 0);
+    let list_ref = use_node_ref();
+    use_focus_on_change(list_ref.clone(), *current_item);
+    let seed = props
+        .seed
+        .unwrap_or_else(|| seed_from(&[&props.challenge.id]));
+    // Initialize with shuffled order, the same for an item every time
     let current_order = use_state(|| {
-        if let Some(item) = props.challenge.items.first() {
-            let mut indices: Vec<usize> = (0..item.elements.len()).collect();
-            use rand::seq::SliceRandom;
-            use rand::thread_rng;
-            indices.shuffle(&mut thread_rng());
-            indices
-        } else {
-            vec![]
-        }
+        props
+            .challenge
+            .items
+            .get(*current_item)
+            .map_or_else(Vec::new, |item| {
+                shuffled_indices(item.elements.len(), seed, *current_item as u64)
+            })
+    });
+    let challenge_result = use_state(|| {
+        props
+            .checkpoint
+            .as_ref()
+            .map(|checkpoint| checkpoint.challenge_result.clone())
+            .unwrap_or_else(|| ChallengeResult::Ordering(vec![]))
     });
-    let challenge_result = use_state(|| ChallengeResult::Ordering(vec![]));
+    // Leading elements put in place by hints, locked against moving
+    let fixed = props.challenge.items.get(*current_item).map_or(0, |item| {
+        props
+            .hints
+            .level(*current_item)
+            .min(max_fixed(&item.correct_order))
+    });
+
+    {
+        let current_order = current_order.clone();
+        let correct_order = props
+            .challenge
+            .items
+            .get(*current_item)
+            .map(|item| item.correct_order.clone())
+            .unwrap_or_default();
+        use_effect_with((*current_item, fixed), move |(_, fixed)| {
+            if *fixed > 0 {
+                current_order.set(fix_prefix(&current_order, &correct_order, *fixed));
+            }
+            || ()
+        });
+    }
 
     // Helper function to get ordered elements
     let get_ordered_elements = {
@@ -161,6 +241,9 @@ pub fn ordering_component(props: &OrderingComponentProps) -> Html {
         Callback::from(move |index: usize| {
             #[cfg(feature = "csr")]
             {
+                if index < fixed {
+                    return;
+                }
                 if let Some(selected_idx) = *selected_index {
                     if selected_idx != index {
                         // Swap elements
@@ -250,7 +333,8 @@ pub fn ordering_component(props: &OrderingComponentProps) -> Html {
                             .get_attribute("data-index")
                             .unwrap_or_default()
                             .parse::<usize>(),
-                    ) {
+                    ) && source_idx.min(target_idx) >= fixed
+                    {
                         // Swap positions in current_order
                         let mut new_order = (*current_order).clone();
                         new_order.swap(source_idx, target_idx);
@@ -308,7 +392,7 @@ pub fn ordering_component(props: &OrderingComponentProps) -> Html {
 
                             // If we have both indices, perform the swap
                             if let Some(source_idx) = *dragged_index {
-                                if source_idx != target_idx {
+                                if source_idx != target_idx && source_idx.min(target_idx) >= fixed {
                                     let mut new_order = (*current_order).clone();
                                     new_order.swap(source_idx, target_idx);
                                     current_order.set(new_order);
@@ -336,10 +420,14 @@ pub fn ordering_component(props: &OrderingComponentProps) -> Html {
     let handle_action = {
         let current_item = current_item.clone();
         let current_order = current_order.clone();
+        let selected_index = selected_index.clone();
+        let focused_index = focused_index.clone();
         let challenge = props.challenge.clone();
         let challenge_result = challenge_result.clone();
         let on_command = props.on_command.clone();
         let on_event = props.on_event.clone();
+        let on_checkpoint = props.on_checkpoint.clone();
+        let on_hint = props.on_hint.clone();
 
         Callback::from(move |action: ChallengeActions| match action {
             ChallengeActions::Next => {
@@ -371,25 +459,36 @@ pub fn ordering_component(props: &OrderingComponentProps) -> Html {
                 challenge_result_update
                     .set_input(current_index, ChallengeInput::Ordering(result))
                     .unwrap();
-                challenge_result.set(challenge_result_update);
+                challenge_result.set(challenge_result_update.clone());
 
                 if next_index < challenge.items.len() {
                     // Initialize order for next item
                     if let Some(next_item) = challenge.items.get(next_index) {
-                        let mut indices: Vec<usize> = (0..next_item.elements.len()).collect();
-                        indices.shuffle(&mut thread_rng());
-                        current_order.set(indices);
+                        current_order.set(shuffled_indices(
+                            next_item.elements.len(),
+                            seed,
+                            next_index as u64,
+                        ));
                     }
 
                     // Move to next item
                     current_item.set(next_index);
+                    selected_index.set(None);
+                    focused_index.set(0);
+
+                    if let Some(on_checkpoint) = on_checkpoint.as_ref() {
+                        on_checkpoint.emit(ChallengeCheckpoint::new(
+                            next_index,
+                            challenge_result_update,
+                        ));
+                    }
 
                     if let Some(on_command) = on_command.as_ref() {
                         on_command.emit(Command::Challenge(ChallengeCommand::NextTask));
                     }
                 } else if let Some(on_command) = on_command.as_ref() {
                     on_command.emit(Command::Challenge(ChallengeCommand::Finish(Some(
-                        (*challenge_result).clone(),
+                        challenge_result_update,
                     ))));
                 }
             }
@@ -399,12 +498,16 @@ pub fn ordering_component(props: &OrderingComponentProps) -> Html {
 
                     // Initialize order for previous item
                     if let Some(prev_item) = challenge.items.get(prev_index) {
-                        let mut indices: Vec<usize> = (0..prev_item.elements.len()).collect();
-                        indices.shuffle(&mut thread_rng());
-                        current_order.set(indices);
+                        current_order.set(shuffled_indices(
+                            prev_item.elements.len(),
+                            seed,
+                            prev_index as u64,
+                        ));
                     }
 
                     current_item.set(prev_index);
+                    selected_index.set(None);
+                    focused_index.set(0);
 
                     if let Some(on_command) = on_command.as_ref() {
                         on_command.emit(Command::Challenge(ChallengeCommand::PreviousTask));
@@ -412,15 +515,64 @@ pub fn ordering_component(props: &OrderingComponentProps) -> Html {
                 }
             }
             ChallengeActions::Help => {}
+            ChallengeActions::Hint => {
+                selected_index.set(None);
+                if let Some(on_hint) = on_hint.as_ref() {
+                    on_hint.emit(*current_item);
+                }
+            }
         })
     };
 
+    let handle_key = {
+        let current_order = current_order.clone();
+        let selected_index = selected_index.clone();
+        let focused_index = focused_index.clone();
+        let handle_action = handle_action.clone();
+
+        on_challenge_keydown(Callback::from(move |key: ChallengeKey| match key {
+            key if key.is_arrow() => {
+                let target = step(*focused_index, current_order.len(), key);
+                // Fixed elements stay in place
+                if selected_index.is_some() && target < fixed {
+                    return;
+                }
+                // Move the grabbed element along with the cursor
+                if let Some(grabbed) = *selected_index
+                    && grabbed != target
+                {
+                    let mut new_order = (*current_order).clone();
+                    new_order.swap(grabbed, target);
+                    current_order.set(new_order);
+                    selected_index.set(Some(target));
+                }
+                focused_index.set(target);
+            }
+            ChallengeKey::Grab => {
+                if selected_index.is_some() {
+                    selected_index.set(None);
+                } else if *focused_index >= fixed {
+                    selected_index.set(Some(*focused_index));
+                }
+            }
+            key => {
+                if let Some(action) = key.action() {
+                    handle_action.emit(action);
+                }
+            }
+        }))
+    };
+
     let current_ordering_item = props.challenge.items.get(*current_item);
     if let Some(item) = current_ordering_item {
         let ordered_elements = get_ordered_elements(&item.elements);
+        let hints_left = props
+            .on_hint
+            .as_ref()
+            .map(|_| max_fixed(&item.correct_order) - fixed);
 
         html! {
-            <div class="ordering">
+            <div class="ordering" onkeydown={handle_key}>
                 <h2 class="ordering__title">{&props.challenge.name}</h2>
                 <ProgressBar
                     value={*current_item}
@@ -430,9 +582,13 @@ pub fn ordering_component(props: &OrderingComponentProps) -> Html {
                 <div class="ordering__elements">
                     <OrderingList
                         elements={ordered_elements}
+                        label={i18n.t("Order the elements")}
+                        list_ref={list_ref.clone()}
                         selected_index={*selected_index}
+                        focused_index={Some(*focused_index)}
                         dragged_index={*dragged_index}
                         drop_target_index={*drop_target_index}
+                        {fixed}
                         on_click={handle_click}
                         on_drag_start={handle_drag_start}
                         on_drag_over={handle_drag_over}
@@ -443,7 +599,7 @@ pub fn ordering_component(props: &OrderingComponentProps) -> Html {
                         on_touch_end={handle_touch_end}
                     />
                 </div>
-                <ChallengeActionsComponent on_action={handle_action} />
+                <ChallengeActionsComponent on_action={handle_action} {hints_left} />
             </div>
         }
     } else {
@@ -488,9 +644,14 @@ mod preview {
     yew_preview::create_preview!(
         OrderingComponent,
         OrderingComponentProps {
+            seed: None,
+            hints: HintUsage::default(),
+            on_hint: None,
             challenge: create_test_challenge(),
             on_command: None,
             on_event: None,
+            checkpoint: None,
+            on_checkpoint: None,
         },
     );
 }
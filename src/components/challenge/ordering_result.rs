@@ -1,4 +1,6 @@
+use super::hints::{HintsUsedComponent, hint_mark};
 use crate::i18n::use_i18n;
+use crate::model::HintUsage;
 use konnektoren_core::challenges::{ChallengeResult, Ordering};
 use yew::prelude::*;
 
@@ -6,6 +8,9 @@ use yew::prelude::*;
 pub struct OrderingResultComponentProps {
     pub challenge: Ordering,
     pub challenge_result: ChallengeResult,
+    /// Hints taken per question, marked on the rows.
+    #[prop_or_default]
+    pub hints: HintUsage,
 }
 
 #[function_component(OrderingResultComponent)]
@@ -17,7 +22,8 @@ pub fn ordering_result_component(props: &OrderingResultComponentProps) -> Html {
             .items
             .iter()
             .zip(results.iter())
-            .map(|(item, result)| {
+            .enumerate()
+            .map(|(index, (item, result))| {
                 let is_correct = item.correct_order == result.order;
                 let modifier = if is_correct { "correct" } else { "incorrect" };
 
@@ -37,6 +43,7 @@ pub fn ordering_result_component(props: &OrderingResultComponentProps) -> Html {
                     <tr class={classes!("ordering-result__row", format!("ordering-result__row--{}", modifier))}>
                         <td class="ordering-result__cell">
                             {ordered_elements.join(" → ")}
+                            { hint_mark(props.hints.level(index), &i18n.t("Hints used")) }
                         </td>
                         <td class={classes!("ordering-result__cell", format!("ordering-result__cell--{}", modifier))}>
                             {if is_correct {
@@ -74,6 +81,7 @@ pub fn ordering_result_component(props: &OrderingResultComponentProps) -> Html {
                     {for results}
                 </tbody>
             </table>
+            <HintsUsedComponent hints={props.hints.clone()} />
         </div>
     }
 }
@@ -126,12 +134,14 @@ mod preview {
     yew_preview::create_preview!(
         OrderingResultComponent,
         OrderingResultComponentProps {
+            hints: HintUsage::default(),
             challenge: create_test_challenge(),
             challenge_result: create_test_result(),
         },
         (
             "Empty Result",
             OrderingResultComponentProps {
+                hints: HintUsage::default(),
                 challenge: create_test_challenge(),
                 challenge_result: ChallengeResult::Ordering(vec![]),
             }
@@ -139,6 +149,7 @@ mod preview {
         (
             "All Correct",
             OrderingResultComponentProps {
+                hints: HintUsage::default(),
                 challenge: create_test_challenge(),
                 challenge_result: ChallengeResult::Ordering(vec![
                     OrderingResult {
@@ -153,6 +164,7 @@ mod preview {
         (
             "All Incorrect",
             OrderingResultComponentProps {
+                hints: HintUsage::default(),
                 challenge: create_test_challenge(),
                 challenge_result: ChallengeResult::Ordering(vec![
                     OrderingResult {
@@ -0,0 +1,182 @@
+use crate::i18n::use_i18n;
+use konnektoren_core::challenges::{Challenge, ChallengeType};
+use yew::prelude::*;
+
+/// Feedback on a single answer given in practice mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PracticeFeedback {
+    pub index: usize,
+    pub correct: bool,
+    /// The expected answer, shown for incorrect answers.
+    pub solution: Option<String>,
+    pub explanation: Option<String>,
+}
+
+impl PracticeFeedback {
+    /// Looks up the solution and explanation of question `index`.
+    pub fn new(challenge: &Challenge, index: usize, correct: bool) -> Self {
+        let non_empty = |text: &str| Some(text.to_string()).filter(|text| !text.is_empty());
+
+        let (solution, explanation) = match &challenge.challenge_type {
+            ChallengeType::MultipleChoice(mc) => match mc.questions.get(index) {
+                Some(question) => (
+                    mc.options
+                        .iter()
+                        .find(|option| option.id == question.option)
+                        .map(|option| option.name.clone()),
+                    non_empty(&question.help),
+                ),
+                None => (None, None),
+            },
+            ChallengeType::GapFill(gap_fill) => match gap_fill.questions.get(index) {
+                Some(question) => (
+                    Some(
+                        question
+                            .gaps
+                            .iter()
+                            .map(|gap| gap.correct.clone())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                    non_empty(&question.explanation),
+                ),
+                None => (None, None),
+            },
+            ChallengeType::ContextualChoice(cc) => (
+                cc.items.get(index).map(|item| {
+                    item.choices
+                        .iter()
+                        .map(|choice| choice.correct_answer.clone())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                }),
+                None,
+            ),
+            _ => (None, None),
+        };
+
+        Self {
+            index,
+            correct,
+            solution: solution.filter(|_| !correct),
+            explanation,
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PracticeFeedbackComponentProps {
+    pub feedback: PracticeFeedback,
+}
+
+#[function_component(PracticeFeedbackComponent)]
+pub fn practice_feedback_component(props: &PracticeFeedbackComponentProps) -> Html {
+    let i18n = use_i18n();
+    let feedback = &props.feedback;
+    let modifier = if feedback.correct {
+        "correct"
+    } else {
+        "incorrect"
+    };
+
+    html! {
+        <div class={classes!("practice-feedback", format!("practice-feedback--{}", modifier))}>
+            <p class="practice-feedback__verdict">
+                { format!("{} {}: ", i18n.t("Question"), feedback.index + 1) }
+                if feedback.correct {
+                    { i18n.t("Correct") }
+                } else {
+                    { i18n.t("Incorrect") }
+                }
+            </p>
+            if let Some(solution) = &feedback.solution {
+                <p class="practice-feedback__solution">
+                    { i18n.t("Correct answer: ") }{ solution }
+                </p>
+            }
+            if let Some(explanation) = &feedback.explanation {
+                <p class="practice-feedback__explanation">{ explanation }</p>
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konnektoren_core::challenges::{MultipleChoice, MultipleChoiceOption, Question};
+
+    fn challenge() -> Challenge {
+        Challenge {
+            challenge_type: ChallengeType::MultipleChoice(MultipleChoice {
+                id: "articles".to_string(),
+                name: "Articles".to_string(),
+                lang: "de".to_string(),
+                options: vec![
+                    MultipleChoiceOption {
+                        id: 0,
+                        name: "der".to_string(),
+                    },
+                    MultipleChoiceOption {
+                        id: 1,
+                        name: "die".to_string(),
+                    },
+                ],
+                questions: vec![Question {
+                    question: "Tisch".to_string(),
+                    help: "Most nouns ending in -isch are masculine.".to_string(),
+                    image: None,
+                    option: 0,
+                }],
+            }),
+            ..Challenge::default()
+        }
+    }
+
+    #[test]
+    fn incorrect_answer_shows_solution() {
+        let feedback = PracticeFeedback::new(&challenge(), 0, false);
+        assert_eq!(feedback.solution.as_deref(), Some("der"));
+        assert!(feedback.explanation.is_some());
+    }
+
+    #[test]
+    fn correct_answer_hides_solution() {
+        let feedback = PracticeFeedback::new(&challenge(), 0, true);
+        assert!(feedback.solution.is_none());
+        assert!(
+            PracticeFeedback::new(&challenge(), 5, false)
+                .solution
+                .is_none()
+        );
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use yew_preview::prelude::*;
+
+    yew_preview::create_preview!(
+        PracticeFeedbackComponent,
+        PracticeFeedbackComponentProps {
+            feedback: PracticeFeedback {
+                index: 0,
+                correct: false,
+                solution: Some("bin".to_string()),
+                explanation: Some("Movement verbs use 'sein' as auxiliary".to_string()),
+            },
+        },
+        (
+            "correct",
+            PracticeFeedbackComponentProps {
+                feedback: PracticeFeedback {
+                    index: 1,
+                    correct: true,
+                    solution: None,
+                    explanation: None,
+                },
+            }
+        ),
+    );
+}
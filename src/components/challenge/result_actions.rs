@@ -0,0 +1,49 @@
+use crate::i18n::use_i18n;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ResultActionsComponentProps {
+    pub on_retry: Callback<()>,
+    /// Shown only when the result contains mistakes and the caller can review them.
+    #[prop_or_default]
+    pub on_review_mistakes: Option<Callback<()>>,
+}
+
+#[function_component(ResultActionsComponent)]
+pub fn result_actions_component(props: &ResultActionsComponentProps) -> Html {
+    let i18n = use_i18n();
+
+    html! {
+        <div class="result-actions">
+            <button class="result-actions__button result-actions__button--retry" onclick={props.on_retry.reform(|_| ())}>
+                { i18n.t("Retry") }
+            </button>
+            if let Some(on_review_mistakes) = &props.on_review_mistakes {
+                <button class="result-actions__button result-actions__button--review" onclick={on_review_mistakes.reform(|_| ())}>
+                    { i18n.t("Review mistakes") }
+                </button>
+            }
+        </div>
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use yew_preview::prelude::*;
+
+    yew_preview::create_preview!(
+        ResultActionsComponent,
+        ResultActionsComponentProps {
+            on_retry: Callback::noop(),
+            on_review_mistakes: None,
+        },
+        (
+            "with-mistakes",
+            ResultActionsComponentProps {
+                on_retry: Callback::noop(),
+                on_review_mistakes: Some(Callback::noop()),
+            }
+        ),
+    );
+}
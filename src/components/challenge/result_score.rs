@@ -1,17 +1,25 @@
+use super::hints::HintsUsedComponent;
 use crate::i18n::use_i18n;
-use konnektoren_core::challenges::{
-    Challenge, ChallengeResult, ChallengeType, ChallengeVariant, Performance,
-};
+use crate::model::{HintUsage, challenge_performance, question_count};
+use konnektoren_core::challenges::{Challenge, ChallengeResult, ChallengeType, ChallengeVariant};
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct ResultScoreComponentProps {
     pub challenge: Challenge,
     pub challenge_result: ChallengeResult,
+    /// Hints taken; each one lowers the score.
+    #[prop_or_default]
+    pub hints: HintUsage,
 }
 
-fn is_unscored(challenge: &Challenge) -> bool {
+fn is_unscored(challenge: &Challenge, result: &ChallengeResult) -> bool {
     matches!(challenge.challenge_type, ChallengeType::Informative(_))
+        // Vocabulary lists only have a score when studied with flashcards
+        || matches!(
+            (&challenge.challenge_type, result),
+            (ChallengeType::Vocabulary(_), ChallengeResult::Informative)
+        )
         || matches!(
             challenge.challenge_config.variant,
             Some(ChallengeVariant::DialogObserver)
@@ -24,7 +32,7 @@ fn is_unscored(challenge: &Challenge) -> bool {
 pub fn result_score_component(props: &ResultScoreComponentProps) -> Html {
     let i18n = use_i18n();
 
-    if is_unscored(&props.challenge) {
+    if is_unscored(&props.challenge, &props.challenge_result) {
         return html! {
             <div class="result-score result-score--completed">
                 <span class="result-score__label">{ i18n.t("Completed!") }</span>
@@ -32,7 +40,10 @@ pub fn result_score_component(props: &ResultScoreComponentProps) -> Html {
         };
     }
 
-    let performance = props.challenge.performance(&props.challenge_result);
+    let performance = props.hints.apply_penalty(
+        challenge_performance(&props.challenge, &props.challenge_result),
+        question_count(&props.challenge),
+    );
     let modifier = match performance {
         p if p >= 90 => "excellent",
         p if p >= 70 => "good",
@@ -50,6 +61,7 @@ pub fn result_score_component(props: &ResultScoreComponentProps) -> Html {
         <div class={classes!("result-score", format!("result-score--{}", modifier))}>
             <span class="result-score__percentage">{ format!("{}%", performance) }</span>
             <span class="result-score__label">{ label }</span>
+            <HintsUsedComponent hints={props.hints.clone()} />
         </div>
     }
 }
@@ -125,6 +137,7 @@ mod preview {
         {
             let (challenge, challenge_result) = scored_challenge(100);
             ResultScoreComponentProps {
+                hints: HintUsage::default(),
                 challenge,
                 challenge_result,
             }
@@ -132,6 +145,7 @@ mod preview {
         ("Good (70%)", {
             let (challenge, challenge_result) = scored_challenge(70);
             ResultScoreComponentProps {
+                hints: HintUsage::default(),
                 challenge,
                 challenge_result,
             }
@@ -139,6 +153,7 @@ mod preview {
         ("Fair (50%)", {
             let (challenge, challenge_result) = scored_challenge(50);
             ResultScoreComponentProps {
+                hints: HintUsage::default(),
                 challenge,
                 challenge_result,
             }
@@ -146,6 +161,7 @@ mod preview {
         ("Needs improvement (30%)", {
             let (challenge, challenge_result) = scored_challenge(30);
             ResultScoreComponentProps {
+                hints: HintUsage::default(),
                 challenge,
                 challenge_result,
             }
@@ -153,6 +169,7 @@ mod preview {
         ("Observer dialog (unscored)", {
             let (challenge, challenge_result) = observer_challenge();
             ResultScoreComponentProps {
+                hints: HintUsage::default(),
                 challenge,
                 challenge_result,
             }
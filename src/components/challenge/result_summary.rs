@@ -1,19 +1,26 @@
 use crate::components::ChallengeTimerComponent;
 use crate::components::challenge::ResultScoreComponent;
 use crate::i18n::use_i18n;
-use konnektoren_core::challenges::{Challenge, ChallengeResult, Performance};
+use crate::model::{HintUsage, challenge_performance, question_count};
+use konnektoren_core::challenges::{Challenge, ChallengeResult};
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct ResultSummaryComponentProps {
     pub challenge: Challenge,
     pub challenge_result: ChallengeResult,
+    /// Hints taken; each one lowers the score.
+    #[prop_or_default]
+    pub hints: HintUsage,
 }
 
 #[function_component(ResultSummaryComponent)]
 pub fn result_summary_component(props: &ResultSummaryComponentProps) -> Html {
     let i18n = use_i18n();
-    let performance = props.challenge.performance(&props.challenge_result);
+    let performance = props.hints.apply_penalty(
+        challenge_performance(&props.challenge, &props.challenge_result),
+        question_count(&props.challenge),
+    );
 
     html! {
         <details class="result-summary">
@@ -21,6 +28,7 @@ pub fn result_summary_component(props: &ResultSummaryComponentProps) -> Html {
                 <ResultScoreComponent
                     challenge={props.challenge.clone()}
                     challenge_result={props.challenge_result.clone()}
+                    hints={props.hints.clone()}
                 />
             </summary>
 
@@ -114,12 +122,14 @@ mod preview {
     yew_preview::create_preview!(
         ResultSummaryComponent,
         ResultSummaryComponentProps {
+            hints: HintUsage::default(),
             challenge: create_test_challenge(),
             challenge_result: create_test_result(),
         },
         (
             "Empty Result",
             ResultSummaryComponentProps {
+                hints: HintUsage::default(),
                 challenge: create_test_challenge(),
                 challenge_result: ChallengeResult::Ordering(vec![]),
             }
@@ -127,6 +137,7 @@ mod preview {
         (
             "All Correct",
             ResultSummaryComponentProps {
+                hints: HintUsage::default(),
                 challenge: create_test_challenge(),
                 challenge_result: ChallengeResult::Ordering(vec![
                     OrderingResult {
@@ -141,6 +152,7 @@ mod preview {
         (
             "All Incorrect",
             ResultSummaryComponentProps {
+                hints: HintUsage::default(),
                 challenge: create_test_challenge(),
                 challenge_result: ChallengeResult::Ordering(vec![
                     OrderingResult {
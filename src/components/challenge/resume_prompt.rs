@@ -0,0 +1,49 @@
+use crate::i18n::use_i18n;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ResumePromptComponentProps {
+    /// Zero based index of the task the learner stopped at.
+    pub task_index: usize,
+    pub on_continue: Callback<()>,
+    pub on_restart: Callback<()>,
+}
+
+#[function_component(ResumePromptComponent)]
+pub fn resume_prompt_component(props: &ResumePromptComponentProps) -> Html {
+    let i18n = use_i18n();
+
+    html! {
+        <div class="resume-prompt" role="dialog" aria-labelledby="resume-prompt-title">
+            <h2 id="resume-prompt-title" class="resume-prompt__title">
+                { i18n.t("You have an unfinished attempt") }
+            </h2>
+            <p class="resume-prompt__progress">
+                {format!("{} {}", i18n.t("Question"), props.task_index + 1)}
+            </p>
+            <div class="resume-prompt__buttons">
+                <button class="resume-prompt__button resume-prompt__button--continue" onclick={props.on_continue.reform(|_| ())}>
+                    { i18n.t("Continue where you left off") }
+                </button>
+                <button class="resume-prompt__button resume-prompt__button--restart" onclick={props.on_restart.reform(|_| ())}>
+                    { i18n.t("Restart") }
+                </button>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use yew_preview::prelude::*;
+
+    yew_preview::create_preview!(
+        ResumePromptComponent,
+        ResumePromptComponentProps {
+            task_index: 4,
+            on_continue: Callback::noop(),
+            on_restart: Callback::noop(),
+        },
+    );
+}
@@ -0,0 +1,304 @@
+//! Runs the HTML, CSS and JS of a custom challenge in a sandboxed frame.
+//!
+//! The frame gets `sandbox="allow-scripts"` without `allow-same-origin`, so
+//! the challenge can't reach the app's storage, cookies or DOM. The
+//! [`SandboxInit`] with the challenge data and translations is written into
+//! the frame's document, so nothing is ever posted to the frame. The
+//! challenge talks to the app only through `postMessage`, with
+//! [`SandboxMessage`](crate::model::SandboxMessage)s, which are validated
+//! before they become commands or events.
+
+use crate::i18n::{I18nLoader, I18nYmlLoader};
+use crate::model::SandboxInit;
+use konnektoren_core::challenges::{Custom, CustomChallengeResult};
+use konnektoren_core::commands::Command;
+use konnektoren_core::events::Event;
+use serde::Serialize;
+use yew::prelude::*;
+
+/// Content security policy of the frame: inline code only, and images,
+/// media and fonts from `data:` URLs or the allowed origins. Anything else,
+/// including requests the challenge could leak data with, is blocked.
+fn sandbox_csp(asset_origins: &[String]) -> String {
+    let sources: String = std::iter::once("data:")
+        .chain(asset_origins.iter().filter_map(|o| csp_origin(o)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "default-src 'none'; script-src 'unsafe-inline'; style-src 'unsafe-inline'; \
+         img-src {sources}; media-src {sources}; font-src {sources}; form-action 'none'"
+    )
+}
+
+/// `origin` if it is a plain `http(s)://host[:port]`, so it can't add
+/// further sources or directives to the policy.
+fn csp_origin(origin: &str) -> Option<&str> {
+    let origin = origin.trim_end_matches('/');
+    let host = origin
+        .strip_prefix("https://")
+        .or_else(|| origin.strip_prefix("http://"))?;
+    (!host.is_empty()
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':')))
+    .then_some(origin)
+}
+
+/// Runs in the frame before the challenge. Provides the `window.konnektoren`
+/// API of unsandboxed challenges on top of `postMessage`, reads the data the
+/// app wrote into the document and then starts the challenge script.
+const BRIDGE_JS: &str = r#"(function () {
+  var post = function (message) { window.parent.postMessage(message, "*"); };
+  var actions = { NextTask: "next_task", PreviousTask: "previous_task", Finish: "finish" };
+  var events = { SolvedCorrect: "solved_correct", SolvedIncorrect: "solved_incorrect" };
+  window.konnektoren = {
+    challenge: null,
+    i18n: {},
+    result: null,
+    sendEvent: function (event) {
+      var type = event && events[event.action];
+      if (!type || typeof event.index !== "number") {
+        console.warn("Unsupported event", event);
+        return;
+      }
+      post({ type: type, index: event.index });
+    },
+    executeCommand: function (command) {
+      var type = command && actions[command.action];
+      if (!type) {
+        console.warn("Unsupported command", command);
+        return;
+      }
+      post(type === "finish" && command.result ? { type: type, result: command.result } : { type: type });
+    }
+  };
+  window.addEventListener("DOMContentLoaded", function () {
+    var init = JSON.parse(document.getElementById("konnektoren-init").textContent);
+    window.konnektoren.challenge = init.challenge;
+    window.konnektoren.i18n = init.i18n;
+    window.konnektoren.result = init.result || null;
+    var source = document.getElementById("konnektoren-challenge");
+    var script = document.createElement("script");
+    script.textContent = source ? source.textContent : "";
+    document.body.appendChild(script);
+  });
+})();"#;
+
+/// Escapes closing `tag`s in `content`, so embedded code can't end its
+/// `<script>` or `<style>` element early.
+fn escape_end_tag(content: &str, tag: &str) -> String {
+    let needle = format!("</{tag}");
+    let lower = content.to_ascii_lowercase();
+    let mut escaped = String::with_capacity(content.len());
+    let mut last = 0;
+    for (index, _) in lower.match_indices(&needle) {
+        escaped.push_str(&content[last..index]);
+        escaped.push_str("<\\/");
+        last = index + 2;
+    }
+    escaped.push_str(&content[last..]);
+    escaped
+}
+
+/// The `srcdoc` of a sandboxed challenge. The challenge script is stored as
+/// plain text beside the `init` data, a [`SandboxInit`], and run by the
+/// bridge once the document is loaded. Images, media and fonts may only
+/// come from `asset_origins` besides `data:` URLs.
+pub fn sandbox_document(
+    html: &str,
+    css: &str,
+    js: &str,
+    init: &impl Serialize,
+    asset_origins: &[String],
+) -> String {
+    // `<` only occurs in strings, where the escape keeps the JSON intact.
+    let init = serde_json::to_string(init)
+        .unwrap_or_else(|_| "{}".to_string())
+        .replace('<', "\\u003c");
+    format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+         <meta http-equiv=\"Content-Security-Policy\" content=\"{}\">\
+         <style>{}</style><script>{BRIDGE_JS}</script></head>\
+         <body>{html}<script type=\"application/json\" id=\"konnektoren-init\">{init}</script>\
+         <script type=\"text/plain\" id=\"konnektoren-challenge\">{}</script></body></html>",
+        sandbox_csp(asset_origins),
+        escape_end_tag(css, "style"),
+        escape_end_tag(js, "script"),
+    )
+}
+
+/// Translations of a challenge's `i18n.yml` for `language`; empty when the
+/// file is invalid.
+pub fn challenge_translations(yml: &str, language: &str) -> serde_json::Value {
+    match I18nYmlLoader::try_new(yml) {
+        Ok(loader) => loader.get(language).unwrap_or_default(),
+        Err(err) => {
+            tracing::warn!("Invalid translations of custom challenge: {}", err);
+            serde_json::Value::default()
+        }
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct SandboxFrameProps {
+    pub challenge: Custom,
+    pub html: String,
+    #[prop_or_default]
+    pub css: String,
+    #[prop_or_default]
+    pub js: String,
+    /// Translations for the selected language, see [`challenge_translations`].
+    #[prop_or_default]
+    pub i18n: serde_json::Value,
+    /// Result shown by a results page.
+    #[prop_or_default]
+    pub result: Option<CustomChallengeResult>,
+    /// Origins the challenge may load images, media and fonts from, besides
+    /// the app's own.
+    #[prop_or_default]
+    pub asset_origins: Vec<String>,
+    #[prop_or_default]
+    pub on_command: Option<Callback<Command>>,
+    #[prop_or_default]
+    pub on_event: Option<Callback<Event>>,
+}
+
+#[function_component(SandboxFrame)]
+pub fn sandbox_frame(props: &SandboxFrameProps) -> Html {
+    let frame_ref = use_node_ref();
+    let init = SandboxInit {
+        challenge: props.challenge.clone(),
+        i18n: props.i18n.clone(),
+        result: props.result.clone(),
+    };
+    #[cfg(feature = "csr")]
+    let app_origin = gloo::utils::window().location().origin().ok();
+    #[cfg(not(feature = "csr"))]
+    let app_origin = None::<String>;
+    let asset_origins: Vec<String> = app_origin
+        .into_iter()
+        .chain(props.asset_origins.iter().cloned())
+        .collect();
+    let document = use_memo(
+        (
+            props.html.clone(),
+            props.css.clone(),
+            props.js.clone(),
+            init,
+            asset_origins,
+        ),
+        |(html, css, js, init, asset_origins)| sandbox_document(html, css, js, init, asset_origins),
+    );
+
+    #[cfg(feature = "csr")]
+    {
+        let frame_ref = frame_ref.clone();
+        use_effect_with(
+            (props.on_command.clone(), props.on_event.clone()),
+            move |(on_command, on_event)| {
+                use crate::model::SandboxMessage;
+                use gloo::events::EventListener;
+                use gloo::utils::window;
+                use wasm_bindgen::JsCast;
+                use web_sys::{HtmlIFrameElement, MessageEvent};
+
+                let on_command = on_command.clone();
+                let on_event = on_event.clone();
+                let listener = EventListener::new(&window(), "message", move |event| {
+                    let Some(event) = event.dyn_ref::<MessageEvent>() else {
+                        return;
+                    };
+                    let Some(frame_window) = frame_ref
+                        .cast::<HtmlIFrameElement>()
+                        .and_then(|frame| frame.content_window())
+                    else {
+                        return;
+                    };
+                    // Only the challenge's own frame may use the bridge.
+                    if !event
+                        .source()
+                        .is_some_and(|source| js_sys::Object::is(&source, &frame_window))
+                    {
+                        return;
+                    }
+
+                    let json = js_sys::JSON::stringify(&event.data())
+                        .ok()
+                        .and_then(|json| json.as_string())
+                        .unwrap_or_default();
+                    match SandboxMessage::parse(&json) {
+                        Ok(message) => {
+                            if let Some(challenge_event) = message.to_event() {
+                                if let Some(on_event) = on_event.as_ref() {
+                                    on_event.emit(challenge_event);
+                                }
+                            } else if let (Some(command), Some(on_command)) =
+                                (message.into_command(), on_command.as_ref())
+                            {
+                                on_command.emit(command);
+                            }
+                        }
+                        Err(err) => {
+                            tracing::warn!("Rejected message from custom challenge: {}", err);
+                        }
+                    }
+                });
+                move || drop(listener)
+            },
+        );
+    }
+
+    html! {
+        <iframe
+            ref={frame_ref}
+            class="sandbox-frame"
+            sandbox="allow-scripts"
+            srcdoc={(*document).clone()}
+            title={props.challenge.name.clone()}
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_code_cannot_close_its_element() {
+        let document = sandbox_document(
+            "<p>Hi</p>",
+            "p { color: red } </STYLE><b>styled</b>",
+            "const s = '</script><img src=x onerror=alert(1)>';",
+            &serde_json::json!({ "i18n": { "name": "</script><b>name</b>" } }),
+            &[],
+        );
+        assert_eq!(document.matches("</style>").count(), 1);
+        assert_eq!(document.matches("</script>").count(), 3);
+        assert!(document.contains("\\u003c/script>\\u003cb>name"));
+        assert!(document.contains("<\\/STYLE>"));
+        assert!(document.contains("'<\\/script>"));
+    }
+
+    #[test]
+    fn assets_only_load_from_allowed_origins() {
+        let origins = [
+            "https://konnektoren.help/".to_string(),
+            "https://evil.example; connect-src *".to_string(),
+            "javascript:alert(1)".to_string(),
+        ];
+        let csp = sandbox_csp(&origins);
+        assert!(csp.contains("img-src data: https://konnektoren.help;"));
+        assert!(csp.contains("font-src data: https://konnektoren.help;"));
+        assert!(!csp.contains("https:;") && !csp.contains("evil") && !csp.contains("javascript"));
+        assert!(sandbox_csp(&[]).contains("media-src data:;"));
+    }
+
+    #[test]
+    fn invalid_translations_are_empty() {
+        assert_eq!(
+            challenge_translations("i18n:\n  hello:\n    de: Hallo", "de")["hello"],
+            "Hallo"
+        );
+        assert!(challenge_translations("i18n: [x]", "de").is_null());
+    }
+}
@@ -1,4 +1,8 @@
+use super::keyboard::step;
+use super::{ChallengeKey, on_challenge_keydown};
 use crate::i18n::use_i18n;
+use crate::model::ChallengeCheckpoint;
+use crate::tools::{SeededRng, seed_from};
 use konnektoren_core::challenges::{ChallengeResult, SortTable, SortTableRow};
 use konnektoren_core::commands::{ChallengeCommand, Command};
 use konnektoren_core::events::Event;
@@ -11,13 +15,19 @@ pub struct SortTableComponentProps {
     pub on_command: Option<Callback<Command>>,
     #[prop_or_default]
     pub on_event: Option<Callback<Event>>,
+    /// Saved progress to continue from.
+    #[prop_or_default]
+    pub checkpoint: Option<ChallengeCheckpoint>,
+    #[prop_or_default]
+    pub on_checkpoint: Option<Callback<ChallengeCheckpoint>>,
+    /// Seed for the shuffled columns. Defaults to one derived from the
+    /// challenge id.
+    #[prop_or_default]
+    pub seed: Option<u64>,
 }
 
-fn shuffle(rows: &[SortTableRow]) -> Vec<SortTableRow> {
-    use rand::seq::SliceRandom;
-    use rand::thread_rng;
-    let mut rng = thread_rng();
-
+/// Shuffles every column but the first, each with its own stream of `seed`.
+fn shuffle(rows: &[SortTableRow], seed: u64) -> Vec<SortTableRow> {
     let mut rows = rows.to_owned();
     let mut columns: Vec<Vec<String>> = vec![vec![]; rows[0].values.len()];
 
@@ -27,8 +37,8 @@ fn shuffle(rows: &[SortTableRow]) -> Vec<SortTableRow> {
         }
     }
 
-    for columns in columns.iter_mut().skip(1) {
-        columns.shuffle(&mut rng);
+    for (col_idx, column) in columns.iter_mut().enumerate().skip(1) {
+        SeededRng::for_stream(seed, col_idx as u64).shuffle(column);
     }
 
     for (row_idx, row) in rows.iter_mut().enumerate() {
@@ -49,9 +59,26 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
         ..
     } = props;
 
-    let rows = use_state(|| shuffle(&challenge.rows));
+    let rows = use_state(|| match props.checkpoint.as_ref() {
+        Some(ChallengeCheckpoint {
+            challenge_result: ChallengeResult::SortTable(rows),
+            ..
+        }) if rows.len() == challenge.rows.len() => rows.clone(),
+        _ => shuffle(
+            &challenge.rows,
+            props.seed.unwrap_or_else(|| seed_from(&[&challenge.id])),
+        ),
+    });
     let dragged_cell = use_state(|| None::<(usize, usize)>);
     let selected_cell = use_state(|| None::<(usize, usize)>);
+    let focused_cell = use_state(|| {
+        let row = props.checkpoint.as_ref().map_or(0, |checkpoint| {
+            checkpoint.resume_index(challenge.rows.len())
+        });
+        (row, 0)
+    });
+    // Row the last answer was placed in, saved with the checkpoint
+    let last_row = use_mut_ref(|| focused_cell.0);
 
     let handle_drag_start = {
         let dragged_cell = dragged_cell.clone();
@@ -97,6 +124,7 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
 
     let handle_touch_move = {
         let rows = rows.clone();
+        let last_row = last_row.clone();
         let dragged_cell = dragged_cell.clone();
         Callback::from(move |e: TouchEvent| {
             #[cfg(feature = "csr")]
@@ -137,6 +165,7 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
                                                 .clone();
                                             updated_rows[target_row_index].values
                                                 [target_col_index] = src_value;
+                                            *last_row.borrow_mut() = target_row_index;
                                             rows.set(updated_rows);
                                         }
                                     }
@@ -151,6 +180,7 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
 
     let handle_cell_click = {
         let rows = rows.clone();
+        let last_row = last_row.clone();
         let selected_cell = selected_cell.clone();
         Callback::from(move |(row_index, col_index): (usize, usize)| {
             #[cfg(feature = "csr")]
@@ -168,6 +198,7 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
                             updated_rows[selected_row_index].values[selected_col_index] =
                                 updated_rows[row_index].values[col_index].clone();
                             updated_rows[row_index].values[col_index] = temp;
+                            *last_row.borrow_mut() = row_index;
                             rows.set(updated_rows);
                         }
                     }
@@ -181,6 +212,7 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
 
     let handle_drop = {
         let rows = rows.clone();
+        let last_row = last_row.clone();
         let dragged_cell = dragged_cell.clone();
         Callback::from(
             move |(e, target_row_index, target_col_index): (DragEvent, usize, usize)| {
@@ -202,6 +234,7 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
                                 updated_rows[source_row_index].values[source_col_index] =
                                     updated_rows[target_row_index].values[target_col_index].clone();
                                 updated_rows[target_row_index].values[target_col_index] = temp;
+                                *last_row.borrow_mut() = target_row_index;
                                 rows.set(updated_rows);
                             }
                         }
@@ -211,10 +244,27 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
         )
     };
 
+    // Checkpoint every rearrangement, but not the initial shuffle
+    {
+        let on_checkpoint = props.on_checkpoint.clone();
+        let is_initial = use_mut_ref(|| true);
+        use_effect_with((*rows).clone(), move |rows| {
+            if !std::mem::replace(&mut *is_initial.borrow_mut(), false)
+                && let Some(on_checkpoint) = on_checkpoint.as_ref()
+            {
+                on_checkpoint.emit(ChallengeCheckpoint::new(
+                    *last_row.borrow(),
+                    ChallengeResult::SortTable(rows.clone()),
+                ));
+            }
+            || ()
+        });
+    }
+
     let handle_finish = {
         let on_command = on_command.clone();
         let rows = rows.clone();
-        Callback::from(move |_| {
+        Callback::from(move |_: ()| {
             let result = ChallengeResult::SortTable((*rows).clone());
             if let Some(on_command) = on_command.as_ref() {
                 let command = Command::Challenge(ChallengeCommand::Finish(Some(result)));
@@ -223,8 +273,31 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
         })
     };
 
+    let handle_key = {
+        let rows = rows.clone();
+        let focused_cell = focused_cell.clone();
+        let handle_cell_click = handle_cell_click.clone();
+        let handle_finish = handle_finish.clone();
+
+        on_challenge_keydown(Callback::from(move |key: ChallengeKey| {
+            let (row_index, col_index) = *focused_cell;
+            match key {
+                ChallengeKey::Up | ChallengeKey::Down => {
+                    focused_cell.set((step(row_index, rows.len(), key), col_index));
+                }
+                ChallengeKey::Left | ChallengeKey::Right => {
+                    let columns = rows.get(row_index).map_or(0, |row| row.values.len());
+                    focused_cell.set((row_index, step(col_index, columns, key)));
+                }
+                ChallengeKey::Grab => handle_cell_click.emit((row_index, col_index)),
+                ChallengeKey::Confirm => handle_finish.emit(()),
+                _ => {}
+            }
+        }))
+    };
+
     html! {
-        <div class="sort-table">
+        <div class="sort-table" tabindex="0" onkeydown={handle_key}>
             <h1 class="sort-table__title">{ &challenge.name }</h1>
             <p class="sort-table__description">{ &challenge.description }</p>
             <table class="sort-table__table">
@@ -249,13 +322,15 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
                                         Some((selected_row, selected_col)) if selected_row == row_index && selected_col == col_index);
                                     let is_dragging = matches!(*dragged_cell,
                                         Some((drag_row, drag_col)) if drag_row == row_index && drag_col == col_index);
+                                    let is_focused = *focused_cell == (row_index, col_index);
 
                                     html! {
                                         <td
                                             class={classes!(
                                                 "sort-table__body-cell",
                                                 is_selected.then_some("sort-table__body-cell--selected"),
-                                                is_dragging.then_some("sort-table__body-cell--dragging")
+                                                is_dragging.then_some("sort-table__body-cell--dragging"),
+                                                is_focused.then_some("sort-table__body-cell--focused")
                                             )}
                                             draggable="true"
                                             data-row-index={row_index.to_string()}
@@ -277,7 +352,7 @@ pub fn sort_table_comp(props: &SortTableComponentProps) -> Html {
                 </tbody>
             </table>
             <div class="sort-table__actions">
-                <button class="sort-table__actions-button" onclick={handle_finish}>
+                <button class="sort-table__actions-button" onclick={handle_finish.reform(|_| ())}>
                     { i18n.t("Finish") }
                 </button>
             </div>
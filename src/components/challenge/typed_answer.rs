@@ -0,0 +1,119 @@
+//! Fuzzy matching for typed answers.
+//!
+//! Answers are compared after [`normalize_answer`], so case, extra whitespace
+//! and the ASCII spellings of umlauts (`ae`, `oe`, `ue`, `ss`) don't matter.
+//! A normalized answer one edit away from the solution is "almost correct".
+
+/// How close a typed answer is to the expected one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerMatch {
+    Correct,
+    AlmostCorrect,
+    Incorrect,
+}
+
+impl AnswerMatch {
+    /// Modifier used for BEM class names, e.g. `gap-fill__input--almost`.
+    pub fn modifier(&self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::AlmostCorrect => "almost",
+            Self::Incorrect => "incorrect",
+        }
+    }
+}
+
+/// Lowercases, replaces umlauts and `ß` with their ASCII spellings and
+/// collapses whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use konnektoren_yew::components::challenge::typed_answer::normalize_answer;
+/// assert_eq!(normalize_answer("  Straße  "), normalize_answer("strasse"));
+/// ```
+pub fn normalize_answer(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    for word in text.split_whitespace() {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        for c in word.chars().flat_map(char::to_lowercase) {
+            match c {
+                'ä' => normalized.push_str("ae"),
+                'ö' => normalized.push_str("oe"),
+                'ü' => normalized.push_str("ue"),
+                'ß' => normalized.push_str("ss"),
+                c => normalized.push(c),
+            }
+        }
+    }
+    normalized
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Compares a typed answer with the expected one.
+pub fn match_answer(typed: &str, expected: &str) -> AnswerMatch {
+    let typed = normalize_answer(typed);
+    let expected = normalize_answer(expected);
+
+    if typed.is_empty() {
+        AnswerMatch::Incorrect
+    } else if typed == expected {
+        AnswerMatch::Correct
+    } else if edit_distance(&typed, &expected) == 1 {
+        AnswerMatch::AlmostCorrect
+    } else {
+        AnswerMatch::Incorrect
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_answer() {
+        assert_eq!(normalize_answer("  Der   Apfel "), "der apfel");
+        assert_eq!(normalize_answer("Größe"), "groesse");
+        assert_eq!(normalize_answer("GRÜN"), "gruen");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("haus", "haus"), 0);
+        assert_eq!(edit_distance("haus", "hause"), 1);
+        assert_eq!(edit_distance("haus", "maus"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_match_answer() {
+        assert_eq!(match_answer("gefahren", "gefahren"), AnswerMatch::Correct);
+        assert_eq!(match_answer("Schoen", "schön"), AnswerMatch::Correct);
+        assert_eq!(match_answer("strasse", "Straße"), AnswerMatch::Correct);
+        assert_eq!(
+            match_answer("gefaren", "gefahren"),
+            AnswerMatch::AlmostCorrect
+        );
+        assert_eq!(match_answer("gegangen", "gefahren"), AnswerMatch::Incorrect);
+        assert_eq!(match_answer("  ", "a"), AnswerMatch::Incorrect);
+    }
+}
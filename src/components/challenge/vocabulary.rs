@@ -1,8 +1,9 @@
-use super::{ChallengeActions, ChallengeActionsComponent};
+use super::{ChallengeActions, ChallengeActionsComponent, FlashcardsComponent};
 use crate::components::ProgressBar;
 #[cfg(feature = "effects")]
 use crate::effects::ReadText;
 use crate::i18n::use_i18n;
+use crate::model::VocabularyAudio;
 use konnektoren_core::challenges::{ChallengeResult, Vocabulary, VocabularyItem};
 use konnektoren_core::commands::{ChallengeCommand, Command};
 use konnektoren_core::events::Event;
@@ -47,6 +48,16 @@ pub struct VocabularyComponentProps {
     pub on_event: Option<Callback<Event>>,
     #[prop_or_default]
     pub on_command: Option<Callback<Command>>,
+    /// Start with flashcards instead of the word list.
+    #[prop_or_default]
+    pub flashcards: bool,
+    /// Seed for the order of the flashcards.
+    #[prop_or_default]
+    pub seed: Option<u64>,
+    /// Recorded pronunciations of the items, read by speech synthesis
+    /// otherwise.
+    #[prop_or_default]
+    pub audio: Option<VocabularyAudio>,
 }
 
 #[derive(Properties, PartialEq)]
@@ -54,19 +65,49 @@ pub struct VocabularyCardProps {
     pub item: VocabularyItem,
     pub lang: String,
     pub on_read: Callback<()>,
+    /// Recorded pronunciation of the item. Speech synthesis reads the text
+    /// when there is none or it fails to play.
+    #[prop_or_default]
+    pub audio: Option<String>,
 }
 
 #[function_component(VocabularyCard)]
 fn vocabulary_card(props: &VocabularyCardProps) -> Html {
     let i18n = use_i18n();
     let read_triggered = use_state(|| false);
+    #[cfg(feature = "csr")]
+    let volume = crate::providers::use_settings().sound_volume;
+
+    use_effect_with(props.audio.clone(), |audio| {
+        #[cfg(feature = "csr")]
+        if let Some(url) = audio {
+            crate::tools::preload_audio(url);
+        }
+        #[cfg(not(feature = "csr"))]
+        let _ = audio;
+        || ()
+    });
 
     let handle_read = {
         let on_read = props.on_read.clone();
         let read_triggered = read_triggered.clone();
+        let audio = props.audio.clone();
         Callback::from(move |_| {
-            read_triggered.set(true);
             on_read.emit(());
+            #[cfg(feature = "csr")]
+            if let Some(url) = audio.clone() {
+                let read_triggered = read_triggered.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Err(e) = crate::tools::play_audio(&url, volume).await {
+                        tracing::warn!("{}, reading the text instead", e);
+                        read_triggered.set(true);
+                    }
+                });
+                return;
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = &audio;
+            read_triggered.set(true);
         })
     };
 
@@ -115,8 +156,9 @@ fn vocabulary_card(props: &VocabularyCardProps) -> Html {
                 class="vocabulary-card__read-button"
                 onclick={handle_read}
                 title={i18n.t("Read aloud")}
+                aria-label={i18n.t("Read aloud")}
             >
-                <i class="fa-solid fa-volume-high"></i>
+                <i class="fa-solid fa-volume-high" aria-hidden="true"></i>
             </button>
 
             <div class="vocabulary-card__header">
@@ -153,6 +195,8 @@ pub struct VocabularyPageProps {
     pub items: Vec<VocabularyItem>,
     pub lang: String,
     pub current_page: usize,
+    #[prop_or_default]
+    pub audio: Option<VocabularyAudio>,
 }
 
 #[function_component(VocabularyPage)]
@@ -175,12 +219,18 @@ fn vocabulary_page(props: &VocabularyPageProps) -> Html {
             {for items_to_show.iter().map(|item| {
                 let item_clone = item.clone();
                 let lang_clone = props.lang.clone();
+                let audio = props
+                    .audio
+                    .as_ref()
+                    .and_then(|audio| audio.file(item.id))
+                    .map(str::to_string);
 
                 html! {
                     <VocabularyCard
                         item={item_clone}
                         lang={lang_clone}
                         on_read={Callback::from(|_| {})}
+                        {audio}
                     />
                 }
             })}
@@ -193,6 +243,7 @@ pub fn vocabulary_component(props: &VocabularyComponentProps) -> Html {
     let i18n = use_i18n();
     let current_page = use_state(|| 0);
     let show_help = use_state(|| false);
+    let flashcards = use_state(|| props.flashcards);
 
     const ITEMS_PER_PAGE: usize = 10;
 
@@ -209,6 +260,39 @@ pub fn vocabulary_component(props: &VocabularyComponentProps) -> Html {
         };
     }
 
+    let toggle_flashcards = {
+        let flashcards = flashcards.clone();
+        Callback::from(move |_: MouseEvent| flashcards.set(!*flashcards))
+    };
+    let mode_button = html! {
+        <button class="vocabulary__mode" onclick={toggle_flashcards}>
+            if *flashcards {
+                <i class="fa-solid fa-list" aria-hidden="true"></i>
+                { i18n.t("Word list") }
+            } else {
+                <i class="fa-solid fa-clone" aria-hidden="true"></i>
+                { i18n.t("Flashcards") }
+            }
+        </button>
+    };
+
+    if *flashcards {
+        return html! {
+            <div class="vocabulary vocabulary--flashcards">
+                <div class="vocabulary__header">
+                    <h2 class="vocabulary__title">{&props.challenge.name}</h2>
+                    {mode_button}
+                </div>
+                <FlashcardsComponent
+                    challenge={props.challenge.clone()}
+                    on_event={props.on_event.clone()}
+                    on_command={props.on_command.clone()}
+                    seed={props.seed}
+                />
+            </div>
+        };
+    }
+
     let current_page_val = *current_page;
     let items_on_current_page =
         calculate_items_on_page(current_page_val, total_items, ITEMS_PER_PAGE);
@@ -254,6 +338,7 @@ pub fn vocabulary_component(props: &VocabularyComponentProps) -> Html {
             ChallengeActions::Help => {
                 show_help.set(!*show_help);
             }
+            ChallengeActions::Hint => {}
         })
     };
 
@@ -271,6 +356,7 @@ pub fn vocabulary_component(props: &VocabularyComponentProps) -> Html {
                     </div>
                 }
                 <p class="vocabulary__description">{&props.challenge.description}</p>
+                {mode_button}
             </div>
 
             <ProgressBar
@@ -291,6 +377,7 @@ pub fn vocabulary_component(props: &VocabularyComponentProps) -> Html {
                     items={props.challenge.items.clone()}
                     lang={props.challenge.lang.clone()}
                     current_page={current_page_val}
+                    audio={props.audio.clone()}
                 />
             </div>
 
@@ -610,13 +697,30 @@ mod preview {
     yew_preview::create_preview!(
         VocabularyComponent,
         VocabularyComponentProps {
+            flashcards: false,
+            seed: None,
+            audio: None,
             challenge: create_test_vocabulary(),
             on_command: None,
             on_event: None,
         },
+        (
+            "Flashcards",
+            VocabularyComponentProps {
+                challenge: create_test_vocabulary(),
+                on_command: None,
+                on_event: None,
+                flashcards: true,
+                seed: None,
+                audio: None,
+            }
+        ),
         (
             "Small Vocabulary",
             VocabularyComponentProps {
+                flashcards: false,
+                seed: None,
+                audio: None,
                 challenge: create_small_vocabulary(),
                 on_command: None,
                 on_event: None,
@@ -625,6 +729,9 @@ mod preview {
         (
             "Sixteen Items",
             VocabularyComponentProps {
+                flashcards: false,
+                seed: None,
+                audio: None,
                 challenge: create_sixteen_item_vocabulary(),
                 on_command: None,
                 on_event: None,
@@ -633,6 +740,9 @@ mod preview {
         (
             "Summer with GIFs",
             VocabularyComponentProps {
+                flashcards: false,
+                seed: None,
+                audio: None,
                 challenge: create_gif_vocabulary(),
                 on_command: None,
                 on_event: None,
@@ -641,6 +751,9 @@ mod preview {
         (
             "Empty Vocabulary",
             VocabularyComponentProps {
+                flashcards: false,
+                seed: None,
+                audio: None,
                 challenge: Vocabulary {
                     id: "empty".to_string(),
                     name: "Empty Vocabulary".to_string(),
@@ -656,6 +769,9 @@ mod preview {
         (
             "Single Item",
             VocabularyComponentProps {
+                flashcards: false,
+                seed: None,
+                audio: None,
                 challenge: Vocabulary {
                     id: "single".to_string(),
                     name: "Single Item".to_string(),
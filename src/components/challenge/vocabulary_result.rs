@@ -0,0 +1,95 @@
+use crate::i18n::use_i18n;
+use crate::model::flashcard_grades;
+use konnektoren_core::challenges::{ChallengeResult, Vocabulary};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct VocabularyResultComponentProps {
+    pub challenge: Vocabulary,
+    /// Flashcard grades to show next to the words, if the list was studied
+    /// with flashcards.
+    #[prop_or_default]
+    pub challenge_result: ChallengeResult,
+}
+
+#[function_component(VocabularyResultComponent)]
+pub fn vocabulary_result_component(props: &VocabularyResultComponentProps) -> Html {
+    let i18n = use_i18n();
+    let grades = flashcard_grades(&props.challenge, &props.challenge_result);
+
+    html! {
+        <div class="vocabulary-result">
+            <h2 class="vocabulary-result__title">{ i18n.t("Challenge Result") }</h2>
+            <p class="vocabulary-result__summary">
+                {format!("{} {} {}", i18n.t("You reviewed"), props.challenge.items.len(), i18n.t("words"))}
+            </p>
+            <ul class="vocabulary-result__list">
+                {for props.challenge.items.iter().enumerate().map(|(index, item)| html! {
+                    <li class="vocabulary-result__item" key={item.id}>
+                        <span class="vocabulary-result__text">{&item.text}</span>
+                        if let Some(translation) = &item.translation {
+                            <span class="vocabulary-result__translation">{translation}</span>
+                        }
+                        if let Some(grade) = grades.get(index) {
+                            <span class={classes!("vocabulary-result__grade", format!("vocabulary-result__grade--{}", grade.name().to_lowercase()))}>
+                                { i18n.t(grade.name()) }
+                            </span>
+                        }
+                    </li>
+                })}
+            </ul>
+        </div>
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use crate::model::{ReviewGrade, flashcard_result};
+    use konnektoren_core::challenges::VocabularyItem;
+    use yew_preview::prelude::*;
+
+    fn create_test_vocabulary() -> Vocabulary {
+        Vocabulary {
+            id: "test-vocabulary".to_string(),
+            name: "Basic German Vocabulary".to_string(),
+            description: "Learn essential German words".to_string(),
+            icon: None,
+            lang: "de".to_string(),
+            items: vec![
+                VocabularyItem {
+                    id: 0,
+                    text: "der Apfel".to_string(),
+                    translation: Some("the apple".to_string()),
+                    icon: None,
+                    phonetic: None,
+                },
+                VocabularyItem {
+                    id: 1,
+                    text: "das Haus".to_string(),
+                    translation: Some("the house".to_string()),
+                    icon: None,
+                    phonetic: None,
+                },
+            ],
+        }
+    }
+
+    yew_preview::create_preview!(
+        VocabularyResultComponent,
+        VocabularyResultComponentProps {
+            challenge: create_test_vocabulary(),
+            challenge_result: ChallengeResult::Informative,
+        },
+        (
+            "Flashcards",
+            VocabularyResultComponentProps {
+                challenge: create_test_vocabulary(),
+                challenge_result: flashcard_result(&[
+                    Some(ReviewGrade::Good),
+                    Some(ReviewGrade::Again),
+                ]),
+            }
+        ),
+    );
+}
@@ -0,0 +1,121 @@
+//! Offline availability of challenge assets: a badge with the
+//! [`CacheStatus`] of a loaded challenge, and a component that downloads the
+//! assets of the next challenges in a game path ahead of time.
+
+use crate::i18n::use_i18n;
+use crate::model::CacheStatus;
+use crate::providers::RepositoryContext;
+use konnektoren_core::game::Game;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct CacheStatusProps {
+    pub status: CacheStatus,
+}
+
+#[function_component(CacheStatusComponent)]
+pub fn cache_status(props: &CacheStatusProps) -> Html {
+    let i18n = use_i18n();
+
+    html! {
+        <span
+            class={classes!(
+                "cache-status",
+                format!("cache-status--{}", props.status.modifier())
+            )}
+            role="status"
+        >
+            { i18n.t(props.status.label()) }
+        </span>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ContentPrefetchProps {
+    pub game: Game,
+    /// Index of the current game path.
+    #[prop_or_default]
+    pub game_path: usize,
+    /// Index of the current challenge in the game path.
+    pub challenge_index: usize,
+    /// Number of following challenges to download.
+    #[prop_or(2)]
+    pub count: usize,
+    #[prop_or_default]
+    pub language: Option<String>,
+}
+
+/// Downloads the packages and markdown of the challenges after
+/// `challenge_index` into the content cache, so they can be played offline.
+/// Renders nothing, and does nothing without a [`RepositoryContext`].
+#[function_component(ContentPrefetchComponent)]
+pub fn content_prefetch(props: &ContentPrefetchProps) -> Html {
+    let repository = use_context::<RepositoryContext>().map(|c| c.content_cache_repository);
+
+    {
+        let game = props.game.clone();
+        let language = props.language.clone().unwrap_or_else(|| "en".to_string());
+        use_effect_with(
+            (
+                props.game_path,
+                props.challenge_index,
+                props.count,
+                language,
+            ),
+            move |(game_path, challenge_index, count, language)| {
+                #[cfg(feature = "csr")]
+                if let Some(repository) = repository {
+                    let content = crate::model::upcoming_content(
+                        &game,
+                        *game_path,
+                        *challenge_index,
+                        *count,
+                        language,
+                    );
+                    if !content.is_empty() {
+                        wasm_bindgen_futures::spawn_local(crate::tools::prefetch_content(
+                            repository, content,
+                        ));
+                    }
+                }
+                #[cfg(not(feature = "csr"))]
+                let _ = (
+                    repository,
+                    game,
+                    game_path,
+                    challenge_index,
+                    count,
+                    language,
+                );
+                || ()
+            },
+        );
+    }
+
+    html! {}
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use yew_preview::prelude::*;
+
+    yew_preview::create_preview!(
+        CacheStatusComponent,
+        CacheStatusProps {
+            status: CacheStatus::Fresh,
+        },
+        (
+            "Offline copy",
+            CacheStatusProps {
+                status: CacheStatus::Offline,
+            }
+        ),
+        (
+            "Not cached",
+            CacheStatusProps {
+                status: CacheStatus::Uncached,
+            }
+        ),
+    );
+}
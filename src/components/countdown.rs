@@ -0,0 +1,102 @@
+use crate::components::LiveRegion;
+use crate::components::timer::format_duration;
+use crate::i18n::use_i18n;
+use crate::model::TimeLimit;
+use chrono::{DateTime, Utc};
+use yew::prelude::*;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct CountdownProps {
+    pub time_limit: TimeLimit,
+    /// Called once when the time has run out.
+    pub on_timeout: Callback<()>,
+}
+
+/// Counts down from the time limit, starting when mounted. Remount it with a
+/// new `key` to restart.
+#[function_component(CountdownComponent)]
+pub fn countdown_component(props: &CountdownProps) -> Html {
+    let i18n = use_i18n();
+    let started = use_state(Utc::now);
+    let now = use_state(Utc::now);
+
+    {
+        let now = now.clone();
+        use_effect_with((), move |_| {
+            let interval = gloo::timers::callback::Interval::new(250, move || {
+                now.set(Utc::now());
+            });
+            move || drop(interval)
+        });
+    }
+
+    let remaining = remaining(&props.time_limit, *started, *now);
+    let expired = remaining <= chrono::Duration::zero();
+    let warning = props.time_limit.is_warning(remaining);
+
+    {
+        let on_timeout = props.on_timeout.clone();
+        use_effect_with(expired, move |expired| {
+            if *expired {
+                on_timeout.emit(());
+            }
+            || ()
+        });
+    }
+
+    let announcement = if expired {
+        i18n.t("Time is up")
+    } else if warning {
+        i18n.t("Time is almost up")
+    } else {
+        String::new()
+    };
+
+    html! {
+        <div
+            class={classes!(
+                "countdown-timer",
+                warning.then_some("countdown-timer--warning"),
+                expired.then_some("countdown-timer--expired")
+            )}
+            role="timer"
+            aria-label={i18n.t("Time left")}
+        >
+            <i class="countdown-timer__icon fa-solid fa-hourglass-half" aria-hidden="true"></i>
+            <span class="countdown-timer__text">{ format_duration(remaining, false) }</span>
+            <LiveRegion message={announcement} assertive={expired} />
+        </div>
+    }
+}
+
+fn remaining(
+    time_limit: &TimeLimit,
+    started: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> chrono::Duration {
+    // Round up so the countdown shows 00:00 only once it is over
+    let remaining = time_limit.remaining(now - started);
+    let seconds = (remaining.num_milliseconds() + 999) / 1000;
+    chrono::Duration::seconds(seconds)
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use yew_preview::prelude::*;
+
+    yew_preview::create_preview!(
+        CountdownComponent,
+        CountdownProps {
+            time_limit: TimeLimit::per_challenge(90),
+            on_timeout: Callback::noop(),
+        },
+        (
+            "warning",
+            CountdownProps {
+                time_limit: TimeLimit::per_question(8),
+                on_timeout: Callback::noop(),
+            }
+        ),
+    );
+}
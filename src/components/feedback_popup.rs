@@ -1,4 +1,4 @@
-use crate::components::ChallengeReviewComponent;
+use crate::components::{ChallengeReviewComponent, use_focus_trap};
 use crate::i18n::use_i18n;
 use gloo::timers::callback::Timeout;
 use yew::prelude::*;
@@ -19,6 +19,7 @@ pub fn feedback_popup(props: &FeedbackPopupProps) -> Html {
     let show = use_state(|| props.show);
     let expanded = use_state(|| props.expanded);
     let i18n = use_i18n();
+    let content_ref = use_node_ref();
 
     // Sync state with props when they change
     {
@@ -68,6 +69,12 @@ pub fn feedback_popup(props: &FeedbackPopupProps) -> Html {
         })
     };
 
+    let trap_focus = {
+        let expanded = expanded.clone();
+        let close = Callback::from(move |_| expanded.set(false));
+        use_focus_trap(content_ref.clone(), *show && *expanded, close)
+    };
+
     if !*show {
         return html! {};
     }
@@ -87,7 +94,14 @@ pub fn feedback_popup(props: &FeedbackPopupProps) -> Html {
                 <span class="feedback__bubble-text">{i18n.t("Feedback")}</span>
             </button>
 
-            <div class="feedback__content" role="dialog" aria-labelledby="feedback-title">
+            <div
+                class="feedback__content"
+                ref={content_ref}
+                role="dialog"
+                aria-modal={expanded.to_string()}
+                aria-labelledby="feedback-title"
+                onkeydown={trap_focus}
+            >
                 <button
                     class="feedback__close"
                     onclick={on_close}
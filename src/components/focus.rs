@@ -0,0 +1,123 @@
+//! Focus management hooks for dialogs, dropdowns and challenge tasks.
+
+use yew::prelude::*;
+
+/// Elements that can receive keyboard focus.
+pub const FOCUSABLE_SELECTOR: &str = "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+#[cfg(feature = "csr")]
+fn focusable_elements(container: &NodeRef) -> Vec<web_sys::HtmlElement> {
+    use wasm_bindgen::JsCast;
+
+    let Some(container) = container.cast::<web_sys::Element>() else {
+        return vec![];
+    };
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return vec![];
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+/// Keeps keyboard focus inside `container` while `active` is true.
+///
+/// On activation the first focusable element receives focus, and on
+/// deactivation focus returns to the element that had it before. The returned
+/// callback belongs on the container's `onkeydown`: it wraps `Tab` and
+/// `Shift+Tab` around the container and emits `on_escape` for `Escape`.
+#[cfg(feature = "csr")]
+#[hook]
+pub fn use_focus_trap(
+    container: NodeRef,
+    active: bool,
+    on_escape: Callback<()>,
+) -> Callback<KeyboardEvent> {
+    {
+        let container = container.clone();
+        use_effect_with(active, move |active| {
+            let previous = active
+                .then(|| gloo::utils::document().active_element())
+                .flatten();
+            if *active && let Some(first) = focusable_elements(&container).first() {
+                let _ = first.focus();
+            }
+            move || {
+                use wasm_bindgen::JsCast;
+                if let Some(previous) =
+                    previous.and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+                {
+                    let _ = previous.focus();
+                }
+            }
+        });
+    }
+
+    Callback::from(move |event: KeyboardEvent| match event.key().as_str() {
+        "Escape" => {
+            event.prevent_default();
+            on_escape.emit(());
+        }
+        "Tab" => {
+            let elements = focusable_elements(&container);
+            let (Some(first), Some(last)) = (elements.first(), elements.last()) else {
+                return;
+            };
+            let active = gloo::utils::document().active_element();
+            let is_active = |element: &web_sys::HtmlElement| {
+                let node: &web_sys::Node = element;
+                active
+                    .as_ref()
+                    .is_some_and(|active| active.is_same_node(Some(node)))
+            };
+            if event.shift_key() && is_active(first) {
+                event.prevent_default();
+                let _ = last.focus();
+            } else if !event.shift_key() && is_active(last) {
+                event.prevent_default();
+                let _ = first.focus();
+            }
+        }
+        _ => {}
+    })
+}
+
+#[cfg(not(feature = "csr"))]
+#[hook]
+pub fn use_focus_trap(
+    _container: NodeRef,
+    _active: bool,
+    _on_escape: Callback<()>,
+) -> Callback<KeyboardEvent> {
+    Callback::noop()
+}
+
+/// Moves focus to `target` whenever `dependency` changes after the first render.
+///
+/// Used to bring the next question into focus when a challenge advances, so
+/// screen readers announce it and keyboard users keep their place.
+#[cfg(feature = "csr")]
+#[hook]
+pub fn use_focus_on_change<T>(target: NodeRef, dependency: T)
+where
+    T: PartialEq + 'static,
+{
+    let first_render = use_mut_ref(|| true);
+    use_effect_with(dependency, move |_| {
+        if std::mem::replace(&mut *first_render.borrow_mut(), false) {
+            return;
+        }
+        if let Some(element) = target.cast::<web_sys::HtmlElement>() {
+            let _ = element.focus();
+        }
+    });
+}
+
+#[cfg(not(feature = "csr"))]
+#[hook]
+pub fn use_focus_on_change<T>(_target: NodeRef, _dependency: T)
+where
+    T: PartialEq + 'static,
+{
+}
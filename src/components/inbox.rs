@@ -1,3 +1,5 @@
+use crate::components::use_focus_trap;
+use crate::i18n::use_i18n;
 use crate::model::Inbox;
 use chrono::Utc;
 use yew::prelude::*;
@@ -10,6 +12,7 @@ pub struct InboxProps {
 
 #[function_component(InboxComponent)]
 pub fn inbox_component(props: &InboxProps) -> Html {
+    let i18n = use_i18n();
     let is_open = use_state(|| false);
 
     #[cfg(feature = "csr")]
@@ -31,6 +34,22 @@ pub fn inbox_component(props: &InboxProps) -> Html {
         })
     };
 
+    let on_icon_keydown = {
+        let is_open = is_open.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if matches!(e.key().as_str(), "Enter" | " ") {
+                e.prevent_default();
+                is_open.set(!*is_open);
+            }
+        })
+    };
+
+    let close_inbox = {
+        let is_open = is_open.clone();
+        Callback::from(move |_| is_open.set(false))
+    };
+    let trap_focus = use_focus_trap(inbox_ref.clone(), *is_open, close_inbox);
+
     let mark_as_read = props.on_read_message.clone();
 
     // Handle outside click
@@ -80,13 +99,20 @@ pub fn inbox_component(props: &InboxProps) -> Html {
     html! {
         <div class="inbox-component">
             if *is_open {
-                <div class="inbox-component__content" ref={inbox_ref}>
+                <div
+                    class="inbox-component__content"
+                    ref={inbox_ref}
+                    role="dialog"
+                    aria-modal="true"
+                    aria-labelledby="inbox-title"
+                    onkeydown={trap_focus}
+                >
                     <div class="inbox-component__header">
-                        <h2 class="inbox-component__title">{"Inbox"}</h2>
+                        <h2 id="inbox-title" class="inbox-component__title">{ i18n.t("Inbox") }</h2>
                         <button
                             class="inbox-component__close"
                             onclick={toggle_inbox.clone()}
-                            aria-label="Close inbox"
+                            aria-label={i18n.t("Close inbox")}
                         >
                             {"×"}
                         </button>
@@ -95,15 +121,28 @@ pub fn inbox_component(props: &InboxProps) -> Html {
                         if props.inbox.messages.is_empty() {
                             <div class="inbox-component__empty">
                                 <i class="fa-solid fa-inbox text-4xl mb-4"></i>
-                                <p>{"No messages yet"}</p>
+                                <p>{ i18n.t("No messages yet") }</p>
                             </div>
                         } else {
                             {for props.inbox.messages.iter().map(|message| {
                                 let is_read = props.inbox.read_messages.as_ref()
                                     .map(|read| read.contains(&message.id.clone().unwrap_or_default()))
                                     .unwrap_or(false);
-                                let mark_as_read = mark_as_read.clone();
                                 let message_id = message.id.clone().unwrap_or_default();
+                                let on_click = {
+                                    let mark_as_read = mark_as_read.clone();
+                                    let message_id = message_id.clone();
+                                    Callback::from(move |_| mark_as_read.emit(message_id.clone()))
+                                };
+                                let on_keydown = {
+                                    let mark_as_read = mark_as_read.clone();
+                                    Callback::from(move |e: KeyboardEvent| {
+                                        if matches!(e.key().as_str(), "Enter" | " ") {
+                                            e.prevent_default();
+                                            mark_as_read.emit(message_id.clone());
+                                        }
+                                    })
+                                };
 
                                 html! {
                                     <div
@@ -111,7 +150,10 @@ pub fn inbox_component(props: &InboxProps) -> Html {
                                             "inbox-component__message",
                                             (!is_read).then_some("inbox-component__message--unread")
                                         )}
-                                        onclick={Callback::from(move |_| mark_as_read.emit(message_id.clone()))}
+                                        role="button"
+                                        tabindex="0"
+                                        onclick={on_click}
+                                        onkeydown={on_keydown}
                                     >
                                         <div class="inbox-component__message-header">
                                             <span class="inbox-component__message-sender">{&message.sender}</span>
@@ -127,8 +169,17 @@ pub fn inbox_component(props: &InboxProps) -> Html {
                     </div>
                 </div>
             }
-            <div class="inbox-component__icon" onclick={toggle_inbox}>
-                <i class="fa-solid fa-envelope"></i>
+            <div
+                class="inbox-component__icon"
+                role="button"
+                tabindex="0"
+                aria-label={i18n.t("Inbox")}
+                aria-haspopup="dialog"
+                aria-expanded={is_open.to_string()}
+                onclick={toggle_inbox}
+                onkeydown={on_icon_keydown}
+            >
+                <i class="fa-solid fa-envelope" aria-hidden="true"></i>
                 if unread_count > 0 {
                     <span class="inbox-component__unread-count">{unread_count}</span>
                 }
@@ -0,0 +1,37 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct LiveRegionProps {
+    pub message: AttrValue,
+    #[prop_or(false)]
+    pub assertive: bool,
+}
+
+/// Visually hidden region whose changes are read out by screen readers.
+#[function_component(LiveRegion)]
+pub fn live_region(props: &LiveRegionProps) -> Html {
+    html! {
+        <div
+            class="live-region"
+            role={if props.assertive { "alert" } else { "status" }}
+            aria-live={if props.assertive { "assertive" } else { "polite" }}
+            aria-atomic="true"
+        >
+            { &props.message }
+        </div>
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use yew_preview::prelude::*;
+
+    yew_preview::create_preview!(
+        LiveRegion,
+        LiveRegionProps {
+            message: "Question 1: Correct".into(),
+            assertive: false,
+        },
+    );
+}
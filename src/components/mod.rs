@@ -16,16 +16,20 @@ pub mod challenge_presence;
 pub mod challenge_rating;
 pub mod challenge_review;
 pub mod challenge_timer;
+pub mod countdown;
 #[cfg(feature = "chat")]
 pub mod chat;
+pub mod content_cache;
 pub mod domain_selector;
 pub mod faq;
 pub mod feedback_popup;
+pub mod focus;
 pub mod game_path;
 #[cfg(feature = "gdrive")]
 pub mod gdrive_backup;
 pub mod inbox;
 pub mod leaderboard;
+pub mod live_region;
 pub mod logo;
 mod map;
 #[cfg(feature = "marketplace")]
@@ -36,6 +40,7 @@ pub mod navigation;
 pub mod profile;
 pub mod progress_bar;
 mod rating_stars;
+pub mod review_queue;
 pub mod seo;
 mod settings;
 pub mod share_page;
@@ -68,16 +73,20 @@ pub use challenge_presence::ChallengePresenceComponent;
 pub use challenge_rating::ChallengeRatingComponent;
 pub use challenge_review::ChallengeReviewComponent;
 pub use challenge_timer::ChallengeTimerComponent;
+pub use countdown::CountdownComponent;
 #[cfg(feature = "chat")]
 pub use chat::ChatComponent;
+pub use content_cache::{CacheStatusComponent, ContentPrefetchComponent};
 pub use domain_selector::{DomainSelectorComponent, DomainSelectorProps, DomainSelectorStyle};
 pub use faq::{FaqItem, FaqItemProps, FaqList, FaqListProps, FaqTag, FaqTagProps};
 pub use feedback_popup::FeedbackPopup;
+pub use focus::{FOCUSABLE_SELECTOR, use_focus_on_change, use_focus_trap};
 pub use game_path::GamePathComponent;
 #[cfg(feature = "gdrive")]
 pub use gdrive_backup::GDriveBackupComponent;
 pub use inbox::InboxComponent;
 pub use leaderboard::LeaderboardComp;
+pub use live_region::LiveRegion;
 pub use logo::Logo;
 pub use map::*;
 #[cfg(feature = "marketplace")]
@@ -91,6 +100,9 @@ pub use profile::ProfileConfigComponent;
 pub use profile::ProfilePointsComponent;
 pub use progress_bar::ProgressBar;
 pub use rating_stars::RatingStarsComponent;
+pub use review_queue::{
+    ReviewBadgeComponent, ReviewQueueComponent, use_review_due_count, use_review_schedule,
+};
 pub use seo::{SeoComponent, SeoConfig};
 pub use settings::*;
 pub use share_page::SharePageComp;
@@ -1,25 +1,41 @@
+use crate::model::{DEFAULT_MUSIC_URL, Playlist};
 use crate::providers::use_settings;
 use uuid::Uuid;
 use yew::prelude::*;
 
-const MUSIC_URL: &str = "https://konnektoren.help/assets/fanfare-3-rpg.ogg";
-
 #[derive(Properties, Clone, PartialEq)]
 pub struct MusicComponentProps {
     #[prop_or_default]
     pub id: Option<String>,
+    /// Single track to play instead of the playlist.
     #[prop_or_default]
     pub url: Option<String>,
     #[prop_or_default]
     pub repeat: Option<bool>,
+    #[prop_or_default]
+    pub playlist: Option<Playlist>,
+    /// Current route path, which picks the tracks of the playlist.
+    #[prop_or_default]
+    pub route: Option<String>,
+    /// Id of the current game path, which picks the tracks of the playlist
+    /// before the route does.
+    #[prop_or_default]
+    pub game_path: Option<String>,
+    /// Duration of the crossfade between two tracks.
+    #[prop_or(2000)]
+    pub crossfade_ms: u32,
 }
 
 impl Default for MusicComponentProps {
     fn default() -> Self {
         Self {
             id: None,
-            url: Some(MUSIC_URL.to_string()),
+            url: None,
             repeat: Some(true),
+            playlist: None,
+            route: None,
+            game_path: None,
+            crossfade_ms: 2000,
         }
     }
 }
@@ -28,40 +44,67 @@ impl Default for MusicComponentProps {
 pub fn music_component(props: &MusicComponentProps) -> Html {
     let settings = use_settings();
 
-    let audio_ref = use_node_ref();
+    let tracks = match &props.url {
+        Some(url) => vec![url.clone()],
+        None => props
+            .playlist
+            .clone()
+            .unwrap_or_default()
+            .tracks_for(props.route.as_deref(), props.game_path.as_deref())
+            .to_vec(),
+    };
+    let repeat = props.repeat.unwrap_or(true);
 
     #[cfg(feature = "csr")]
     {
-        use web_sys::HtmlAudioElement;
+        use crate::tools::MusicPlayer;
+        use gloo::events::EventListener;
+        use gloo::utils::document;
 
-        let audio_ref = audio_ref.clone();
-        let music_url = props
-            .url
-            .clone()
-            .unwrap_or(MusicComponentProps::default().url.unwrap());
-        let repeat = props
-            .repeat
-            .unwrap_or(MusicComponentProps::default().repeat.unwrap());
-        let settings = settings.clone();
-        use_effect_with((audio_ref, settings), move |(audio_ref, settings)| {
-            if let Some(audio_element) = audio_ref.cast::<HtmlAudioElement>() {
-                audio_element.set_src(&music_url);
-                audio_element.set_loop(repeat);
-                audio_element.set_autoplay(true);
-                audio_element.set_volume(settings.music_volume as f64);
+        let player = use_memo((), |_| MusicPlayer::new());
+
+        {
+            let player = player.clone();
+            use_effect_with(settings.music_volume, move |volume| {
+                player.set_volume(*volume);
+                || ()
+            });
+        }
 
-                let _ = audio_element.play();
+        {
+            let player = player.clone();
+            use_effect_with(
+                (tracks, repeat, props.crossfade_ms),
+                move |(tracks, repeat, crossfade_ms)| {
+                    player.play(tracks.clone(), *repeat, *crossfade_ms);
+                    || ()
+                },
+            );
+        }
+
+        use_effect_with((), move |_| {
+            let document = document();
+            player.set_hidden(document.hidden());
+            let listener = {
+                let player = player.clone();
+                let page = document.clone();
+                EventListener::new(&document, "visibilitychange", move |_| {
+                    player.set_hidden(page.hidden());
+                })
+            };
+            move || {
+                drop(listener);
+                player.stop();
             }
-            || ()
         });
     }
+    #[cfg(not(feature = "csr"))]
+    let _ = (&settings, tracks, repeat);
 
     let id = props.id.clone().unwrap_or(Uuid::new_v4().to_string());
 
     html! {
-        <div {id} class="music-component">
-            <audio ref={audio_ref} />
-        </div>
+        <div {id} class="music-component"></div>
     }
 }
 
@@ -70,19 +113,45 @@ mod preview {
     use super::*;
     use yew_preview::prelude::*;
 
+    fn playlist() -> Playlist {
+        Playlist {
+            routes: [(
+                "/map".to_string(),
+                vec![
+                    "https://konnektoren.help/assets/fanfare-3-rpg.ogg".to_string(),
+                    DEFAULT_MUSIC_URL.to_string(),
+                ],
+            )]
+            .into(),
+            ..Playlist::default()
+        }
+    }
+
     yew_preview::create_preview!(
         MusicComponent,
         MusicComponentProps {
             id: Some("music-component".to_string()),
-            url: Some(MUSIC_URL.to_string()),
+            url: Some(DEFAULT_MUSIC_URL.to_string()),
             repeat: Some(false),
+            ..MusicComponentProps::default()
         },
         (
             "repeat",
             MusicComponentProps {
                 id: Some("music-component".to_string()),
-                url: Some(MUSIC_URL.to_string()),
+                url: Some(DEFAULT_MUSIC_URL.to_string()),
                 repeat: Some(true),
+                ..MusicComponentProps::default()
+            }
+        ),
+        (
+            "playlist",
+            MusicComponentProps {
+                id: Some("music-component".to_string()),
+                playlist: Some(playlist()),
+                route: Some("/map".to_string()),
+                crossfade_ms: 3000,
+                ..MusicComponentProps::default()
             }
         )
     );
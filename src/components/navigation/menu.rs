@@ -1,4 +1,5 @@
 use super::NavItem;
+use crate::components::use_focus_trap;
 use crate::i18n::use_i18n;
 use yew::prelude::*;
 use yew_router::prelude::*;
@@ -21,6 +22,12 @@ pub fn navigation_menu<Route: yew_router::Routable + std::fmt::Debug + 'static>(
     #[cfg(not(feature = "csr"))]
     let dropdown_ref = NodeRef::default(); // Placeholder for SSR
 
+    let close_group = {
+        let active_group = active_group.clone();
+        Callback::from(move |_| active_group.set(None))
+    };
+    let trap_focus = use_focus_trap(dropdown_ref.clone(), active_group.is_some(), close_group);
+
     // Toggle group function
     let toggle_group = {
         let active_group = active_group.clone();
@@ -102,16 +109,21 @@ pub fn navigation_menu<Route: yew_router::Routable + std::fmt::Debug + 'static>(
         <div class="navigation-wrapper">
             {
                 if let Some(active_idx) = *active_group {
-                    if let Some(&(_, NavItem::Group { items, .. })) = group_items.get(active_idx) {
+                    if let Some(&(_, NavItem::Group { name, items, .. })) = group_items.get(active_idx) {
                         html! {
-                            <div class="navigation-dropdown" ref={dropdown_ref}>
-                                <nav>
+                            <div
+                                id="navigation-dropdown"
+                                class="navigation-dropdown"
+                                ref={dropdown_ref}
+                                onkeydown={trap_focus}
+                            >
+                                <nav aria-label={i18n.t(name)}>
                                     {
                                         items.iter().map(|item| {
                                             match item {
                                                 NavItem::Route { name, route, icon } => html! {
                                                     <Link<Route> to={route.clone()}>
-                                                        <i class={*icon}></i>
+                                                        <i class={*icon} aria-hidden="true"></i>
                                                         <span>{ i18n.t(name) }</span>
                                                     </Link<Route>>
                                                 },
@@ -141,7 +153,7 @@ pub fn navigation_menu<Route: yew_router::Routable + std::fmt::Debug + 'static>(
                         match item {
                             NavItem::Route { name, route, icon } => html! {
                                 <Link<Route> to={route.clone()} >
-                                    <i class={*icon}></i>
+                                    <i class={*icon} aria-hidden="true"></i>
                                     <span>{ i18n.t(name) }</span>
                                 </Link<Route>>
                             },
@@ -157,8 +169,11 @@ pub fn navigation_menu<Route: yew_router::Routable + std::fmt::Debug + 'static>(
                                     <button
                                         class={if is_active { "nav-group active" } else { "nav-group" }}
                                         onclick={toggle_group(group_idx)}
+                                        aria-haspopup="true"
+                                        aria-expanded={is_active.to_string()}
+                                        aria-controls="navigation-dropdown"
                                     >
-                                        <i class={*icon}></i>
+                                        <i class={*icon} aria-hidden="true"></i>
                                         <span>{ i18n.t(name) }</span>
                                     </button>
                                 }
@@ -10,11 +10,19 @@ pub struct ProgressBarProps {
 #[function_component(ProgressBar)]
 pub fn progress_bar(props: &ProgressBarProps) -> Html {
     html! {
-        <div class="progress-bar">
+        <div
+            class="progress-bar"
+            role="progressbar"
+            aria-valuenow={props.value.to_string()}
+            aria-valuemin="0"
+            aria-valuemax={props.max.unwrap_or(100).to_string()}
+            aria-valuetext={props.label.clone()}
+        >
             <progress
                 class="progress-bar__fill"
                 value={props.value.to_string()}
                 max={props.max.unwrap_or(100).to_string()}
+                aria-hidden="true"
             >
                 <span class="progress-bar__label">{&props.label}</span>
             </progress>
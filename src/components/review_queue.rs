@@ -0,0 +1,252 @@
+use crate::i18n::use_i18n;
+use crate::model::{ReviewGrade, ReviewItem, ReviewSchedule, current_day};
+use crate::providers::review_provider::{save_schedule, use_synced_review_schedule};
+use crate::providers::{RepositoryContext, ReviewContext};
+use konnektoren_core::challenges::ChallengeHistory;
+use yew::prelude::*;
+
+/// The review schedule of the enclosing
+/// [`ReviewProvider`](crate::providers::ReviewProvider), or, without one,
+/// a schedule this component loads and syncs itself. `None` until loaded.
+#[hook]
+pub fn use_review_schedule(
+    challenge_history: ChallengeHistory,
+) -> UseStateHandle<Option<ReviewSchedule>> {
+    let context = use_context::<ReviewContext>();
+    let own = use_synced_review_schedule(challenge_history, context.is_none());
+    context.map_or(own, |context| context.schedule)
+}
+
+/// Number of review items due today, e.g. for a badge in the navigation.
+/// Place badges inside a `ReviewProvider` so they share one schedule.
+#[hook]
+pub fn use_review_due_count(challenge_history: ChallengeHistory) -> usize {
+    use_review_schedule(challenge_history)
+        .as_ref()
+        .map_or(0, |schedule| schedule.due_count(current_day()))
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReviewQueueProps {
+    pub challenge_history: ChallengeHistory,
+    /// Maximum number of items in one session.
+    #[prop_or(20)]
+    pub limit: usize,
+    /// Called with the number of items still due whenever it changes.
+    #[prop_or_default]
+    pub on_due_count: Option<Callback<usize>>,
+}
+
+#[function_component(ReviewQueueComponent)]
+pub fn review_queue(props: &ReviewQueueProps) -> Html {
+    let i18n = use_i18n();
+    let review_repository = use_context::<RepositoryContext>().map(|c| c.review_repository);
+    let schedule = use_review_schedule(props.challenge_history.clone());
+    let queue = use_state(Vec::<ReviewItem>::new);
+    let position = use_state(|| 0usize);
+    let revealed = use_state(|| false);
+
+    let due_count = schedule
+        .as_ref()
+        .map_or(0, |schedule| schedule.due_count(current_day()));
+
+    {
+        let on_due_count = props.on_due_count.clone();
+        use_effect_with(due_count, move |due_count| {
+            if let Some(on_due_count) = on_due_count.as_ref() {
+                on_due_count.emit(*due_count);
+            }
+            || ()
+        });
+    }
+
+    // The session is built once per load of the schedule, so grading an
+    // item does not reshuffle the queue.
+    {
+        let queue = queue.clone();
+        let position = position.clone();
+        let limit = props.limit;
+        let loaded = schedule.as_ref().map(|schedule| schedule.synced.clone());
+        use_effect_with(loaded, {
+            let schedule = schedule.clone();
+            move |_| {
+                if let Some(schedule) = schedule.as_ref() {
+                    let mut items = schedule.due_items(current_day());
+                    items.truncate(limit);
+                    queue.set(items);
+                    position.set(0);
+                }
+                || ()
+            }
+        });
+    }
+
+    let on_reveal = {
+        let revealed = revealed.clone();
+        Callback::from(move |_: MouseEvent| revealed.set(true))
+    };
+
+    let on_grade = {
+        let schedule = schedule.clone();
+        let queue = queue.clone();
+        let position = position.clone();
+        let revealed = revealed.clone();
+        Callback::from(move |grade: ReviewGrade| {
+            let (Some(item), Some(updated)) = (queue.get(*position), schedule.as_ref()) else {
+                return;
+            };
+            let mut updated = updated.clone();
+            updated.review(&item.id, grade, current_day());
+            if let Some(review_repository) = review_repository.clone() {
+                save_schedule(review_repository, updated.clone());
+            }
+            schedule.set(Some(updated));
+            position.set(*position + 1);
+            revealed.set(false);
+        })
+    };
+
+    let Some(item) = queue.get(*position) else {
+        return html! {
+            <div class="review-queue">
+                <h2 class="review-queue__title">{ i18n.t("Review") }</h2>
+                <p class="review-queue__empty">
+                    if queue.is_empty() {
+                        { i18n.t("Nothing to review today") }
+                    } else {
+                        { format!("{} {} {}", i18n.t("You reviewed"), queue.len(), i18n.t("questions")) }
+                    }
+                </p>
+            </div>
+        };
+    };
+
+    html! {
+        <div class="review-queue">
+            <div class="review-queue__header">
+                <h2 class="review-queue__title">{ i18n.t("Review") }</h2>
+                <span class="review-queue__progress">
+                    { format!("{} / {}", *position + 1, queue.len()) }
+                </span>
+            </div>
+            <div class="review-queue__card">
+                <p class="review-queue__prompt">{ &item.prompt }</p>
+                if *revealed {
+                    <p class="review-queue__answer" aria-live="polite">{ &item.answer }</p>
+                    <div class="review-queue__grades" role="group" aria-label={i18n.t("How well did you remember?")}>
+                        { for ReviewGrade::ALL.iter().map(|grade| {
+                            let grade = *grade;
+                            html! {
+                                <button
+                                    class={classes!("review-queue__grade", format!("review-queue__grade--{}", grade.name().to_lowercase()))}
+                                    onclick={on_grade.reform(move |_: MouseEvent| grade)}
+                                >
+                                    { i18n.t(grade.name()) }
+                                </button>
+                            }
+                        }) }
+                    </div>
+                } else {
+                    <button class="review-queue__reveal" onclick={on_reveal}>
+                        { i18n.t("Show answer") }
+                    </button>
+                }
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReviewBadgeProps {
+    pub count: usize,
+}
+
+/// Number of due reviews, hidden when nothing is due.
+#[function_component(ReviewBadgeComponent)]
+pub fn review_badge(props: &ReviewBadgeProps) -> Html {
+    let i18n = use_i18n();
+    if props.count == 0 {
+        return html! {};
+    }
+    html! {
+        <span class="review-badge" aria-label={format!("{} {}", props.count, i18n.t("reviews due"))}>
+            { props.count }
+        </span>
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use konnektoren_core::challenges::{
+        Challenge, ChallengeConfig, ChallengeResult, ChallengeType, MultipleChoice,
+        MultipleChoiceOption, Question,
+    };
+    use yew_preview::prelude::*;
+
+    fn history_with_mistakes() -> ChallengeHistory {
+        let options = vec![
+            MultipleChoiceOption {
+                id: 0,
+                name: "der".to_string(),
+            },
+            MultipleChoiceOption {
+                id: 1,
+                name: "die".to_string(),
+            },
+            MultipleChoiceOption {
+                id: 2,
+                name: "das".to_string(),
+            },
+        ];
+        let question = |question: &str, option: usize| Question {
+            question: question.to_string(),
+            help: String::new(),
+            image: None,
+            option,
+        };
+        let mut history = ChallengeHistory::new();
+        history.add_challenge(Challenge {
+            challenge_type: ChallengeType::MultipleChoice(MultipleChoice {
+                id: "articles".to_string(),
+                name: "Articles".to_string(),
+                lang: "de".to_string(),
+                options: options.clone(),
+                questions: vec![
+                    question("Haus", 2),
+                    question("Tisch", 0),
+                    question("Lampe", 1),
+                ],
+            }),
+            challenge_config: ChallengeConfig {
+                id: "articles".to_string(),
+                ..Default::default()
+            },
+            challenge_result: ChallengeResult::MultipleChoice(vec![
+                options[0].clone(),
+                options[0].clone(),
+                options[2].clone(),
+            ]),
+            start_time: None,
+            end_time: None,
+        });
+        history
+    }
+
+    yew_preview::create_preview!(
+        ReviewQueueComponent,
+        ReviewQueueProps {
+            challenge_history: history_with_mistakes(),
+            limit: 20,
+            on_due_count: None,
+        },
+        (
+            "Nothing due",
+            ReviewQueueProps {
+                challenge_history: ChallengeHistory::new(),
+                limit: 20,
+                on_due_count: None,
+            }
+        ),
+    );
+}
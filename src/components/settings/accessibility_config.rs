@@ -0,0 +1,143 @@
+use crate::i18n::use_i18n;
+use crate::model::{Accessibility, MotionPreference, Settings, TEXT_SCALES};
+use yew::prelude::*;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct AccessibilityConfigProps {
+    pub settings: Settings,
+    pub on_change: Callback<Settings>,
+}
+
+/// Text size, dyslexia-friendly font, high contrast and reduced motion.
+#[function_component(AccessibilityConfig)]
+pub fn accessibility_config(props: &AccessibilityConfigProps) -> Html {
+    let i18n = use_i18n();
+    let accessibility = props.settings.accessibility.clone();
+
+    let update = {
+        let settings = props.settings.clone();
+        let on_change = props.on_change.clone();
+        move |change: fn(&mut Accessibility, String)| {
+            let settings = settings.clone();
+            let on_change = on_change.clone();
+            Callback::from(move |e: Event| {
+                #[cfg(feature = "csr")]
+                {
+                    use wasm_bindgen::JsCast;
+                    let value = e.target().and_then(|target| {
+                        if let Some(select) = target.dyn_ref::<web_sys::HtmlSelectElement>() {
+                            Some(select.value())
+                        } else {
+                            target
+                                .dyn_ref::<web_sys::HtmlInputElement>()
+                                .map(|input| input.checked().to_string())
+                        }
+                    });
+                    if let Some(value) = value {
+                        let mut settings = settings.clone();
+                        change(&mut settings.accessibility, value);
+                        on_change.emit(settings);
+                    }
+                }
+                #[cfg(not(feature = "csr"))]
+                let _ = (e, &settings, &on_change, change);
+            })
+        }
+    };
+
+    let on_change_text_scale = update(|accessibility, value| {
+        accessibility.text_scale = value.parse().unwrap_or(100);
+    });
+    let on_toggle_dyslexia_font = update(|accessibility, value| {
+        accessibility.dyslexia_font = value == "true";
+    });
+    let on_toggle_high_contrast = update(|accessibility, value| {
+        accessibility.high_contrast = value == "true";
+    });
+    let on_change_motion = update(|accessibility, value| {
+        accessibility.motion = MotionPreference::from_value(&value);
+    });
+
+    let text_scale = accessibility.text_scale_step();
+
+    html! {
+        <div class="accessibility-config">
+            <div class="accessibility-config__row">
+                <label for="accessibility-text-size">{ i18n.t("Text size") }</label>
+                <select
+                    id="accessibility-text-size"
+                    class="accessibility-config__select"
+                    onchange={on_change_text_scale}
+                >
+                    { for TEXT_SCALES.iter().map(|scale| html! {
+                        <option value={scale.to_string()} selected={*scale == text_scale}>
+                            { format!("{}%", scale) }
+                        </option>
+                    })}
+                </select>
+            </div>
+            <div class="accessibility-config__row">
+                <label for="accessibility-motion">{ i18n.t("Motion") }</label>
+                <select
+                    id="accessibility-motion"
+                    class="accessibility-config__select"
+                    onchange={on_change_motion}
+                >
+                    { for MotionPreference::ALL.iter().map(|motion| html! {
+                        <option
+                            value={motion.value()}
+                            selected={*motion == accessibility.motion}
+                        >
+                            { i18n.t(motion.label()) }
+                        </option>
+                    })}
+                </select>
+            </div>
+            <label class="accessibility-config__option">
+                <input
+                    type="checkbox"
+                    checked={accessibility.dyslexia_font}
+                    onchange={on_toggle_dyslexia_font}
+                />
+                { i18n.t("Dyslexia-friendly font") }
+            </label>
+            <label class="accessibility-config__option">
+                <input
+                    type="checkbox"
+                    checked={accessibility.high_contrast}
+                    onchange={on_toggle_high_contrast}
+                />
+                { i18n.t("High contrast") }
+            </label>
+        </div>
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use yew_preview::prelude::*;
+
+    yew_preview::create_preview!(
+        AccessibilityConfig,
+        AccessibilityConfigProps {
+            settings: Settings::default(),
+            on_change: Callback::noop(),
+        },
+        (
+            "Large text, high contrast",
+            AccessibilityConfigProps {
+                settings: Settings {
+                    accessibility: Accessibility {
+                        text_scale: 130,
+                        dyslexia_font: true,
+                        high_contrast: true,
+                        motion: MotionPreference::Reduce,
+                    },
+                    ..Settings::default()
+                },
+                on_change: Callback::noop(),
+            }
+        ),
+    );
+}
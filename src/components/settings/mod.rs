@@ -1,4 +1,5 @@
 //! Settings components
+mod accessibility_config;
 mod music_config;
 mod select_design;
 mod select_language;
@@ -6,7 +7,9 @@ mod select_level;
 mod select_theme;
 mod settings_component;
 mod sound_config;
+mod speech_config;
 
+pub use accessibility_config::AccessibilityConfig;
 pub use music_config::MusicConfig;
 pub use select_design::SelectDesign;
 pub use select_language::SelectLanguage;
@@ -14,3 +17,4 @@ pub use select_level::SelectLevelComp;
 pub use select_theme::SelectTheme;
 pub use settings_component::SettingsComponent;
 pub use sound_config::SoundConfig;
+pub use speech_config::SpeechConfig;
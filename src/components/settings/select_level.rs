@@ -1,4 +1,5 @@
 use crate::i18n::use_i18n;
+use crate::t;
 use konnektoren_core::prelude::GamePath;
 use yew::prelude::*;
 
@@ -30,10 +31,10 @@ pub fn select_level(props: &SelectLevelCompProps) -> Html {
 
     html! {
         <div class="select-level">
-            <label for="level-select">{ i18n.t("Select Level") }</label>
+            <label for="level-select">{ t!(i18n, SELECT_LEVEL) }</label>
             <select id="level-select" onchange={on_change} value={props.current.to_string()}>
                 <option value="" disabled={true} selected={props.levels.is_empty()}>
-                    { i18n.t("Select Level") }
+                    { t!(i18n, SELECT_LEVEL) }
                 </option>
                 { for props.levels.iter().enumerate().map(|(index, level)| html! {
                     <option value={index.to_string()} selected={index == props.current}>
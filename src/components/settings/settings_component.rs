@@ -1,5 +1,7 @@
 use crate::components::MusicConfig;
+use crate::components::settings::accessibility_config::AccessibilityConfig;
 use crate::components::settings::sound_config::SoundConfig;
+use crate::components::settings::speech_config::SpeechConfig;
 use crate::i18n::use_i18n;
 use crate::model::Settings;
 use crate::prelude::use_settings;
@@ -36,6 +38,8 @@ pub fn settings_component() -> Html {
         <div class="settings">
             <MusicConfig settings={(*settings).clone()} on_change={on_change.clone()} />
             <SoundConfig settings={(*settings).clone()} on_change={on_change.clone()} />
+            <SpeechConfig settings={(*settings).clone()} on_change={on_change.clone()} />
+            <AccessibilityConfig settings={(*settings).clone()} on_change={on_change.clone()} />
             <div class="settings__save-row">
                 <button class="settings__button" onclick={on_save} disabled={!has_changes()}>
                     { i18n.t("Save") }
@@ -1,5 +1,5 @@
 use crate::i18n::use_i18n;
-use crate::model::Settings;
+use crate::model::{QuietHours, Settings};
 use yew::prelude::*;
 
 #[derive(Properties, Clone, PartialEq)]
@@ -29,7 +29,68 @@ pub fn sound_config(props: &SoundConfigProps) -> Html {
         })
     };
 
+    let on_toggle_mute = {
+        let settings = props.settings.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: Event| {
+            on_change.emit(Settings {
+                sound_muted: !settings.sound_muted,
+                ..settings.clone()
+            });
+        })
+    };
+
+    let on_toggle_quiet_hours = {
+        let settings = props.settings.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: Event| {
+            let quiet_hours = match settings.quiet_hours {
+                Some(_) => None,
+                None => Some(QuietHours::default()),
+            };
+            on_change.emit(Settings {
+                quiet_hours,
+                ..settings.clone()
+            });
+        })
+    };
+
+    let on_change_quiet_hour = |is_start: bool| {
+        let settings = props.settings.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            #[cfg(feature = "csr")]
+            {
+                use web_sys::HtmlInputElement;
+                let input: HtmlInputElement = e.target_unchecked_into();
+                let (Some(quiet_hours), Ok(hour)) = (settings.quiet_hours, input.value().parse())
+                else {
+                    return;
+                };
+                let hour = u8::min(hour, 23);
+                let quiet_hours = if is_start {
+                    QuietHours {
+                        start: hour,
+                        ..quiet_hours
+                    }
+                } else {
+                    QuietHours {
+                        end: hour,
+                        ..quiet_hours
+                    }
+                };
+                on_change.emit(Settings {
+                    quiet_hours: Some(quiet_hours),
+                    ..settings.clone()
+                });
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (e, is_start, &settings, &on_change);
+        })
+    };
+
     html! {
+        <>
         <div class="sound-config">
             <label for="sound-volume">{ i18n.t("Sound Volume") }</label>
             <input
@@ -43,6 +104,47 @@ pub fn sound_config(props: &SoundConfigProps) -> Html {
             />
             <output>{sound_volume}</output>
         </div>
+        <div class="sound-config__options">
+            <label class="sound-config__option">
+                <input
+                    type="checkbox"
+                    checked={props.settings.sound_muted}
+                    onchange={on_toggle_mute}
+                />
+                { i18n.t("Mute sounds") }
+            </label>
+            <label class="sound-config__option">
+                <input
+                    type="checkbox"
+                    checked={props.settings.quiet_hours.is_some()}
+                    onchange={on_toggle_quiet_hours}
+                />
+                { i18n.t("Quiet hours") }
+            </label>
+            if let Some(quiet_hours) = props.settings.quiet_hours {
+                <div class="sound-config__quiet-hours">
+                    <label for="quiet-hours-start">{ i18n.t("From") }</label>
+                    <input
+                        id="quiet-hours-start"
+                        type="number"
+                        min="0"
+                        max="23"
+                        value={quiet_hours.start.to_string()}
+                        onchange={on_change_quiet_hour(true)}
+                    />
+                    <label for="quiet-hours-end">{ i18n.t("To") }</label>
+                    <input
+                        id="quiet-hours-end"
+                        type="number"
+                        min="0"
+                        max="23"
+                        value={quiet_hours.end.to_string()}
+                        onchange={on_change_quiet_hour(false)}
+                    />
+                </div>
+            }
+        </div>
+        </>
     }
 }
 
@@ -60,5 +162,16 @@ mod preview {
             },
             on_change: Callback::noop()
         },
+        (
+            "Quiet hours",
+            SoundConfigProps {
+                settings: Settings {
+                    sound_volume: 0.5,
+                    quiet_hours: Some(QuietHours::default()),
+                    ..Settings::default()
+                },
+                on_change: Callback::noop()
+            }
+        ),
     );
 }
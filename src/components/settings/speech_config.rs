@@ -0,0 +1,221 @@
+#[cfg(feature = "effects")]
+use crate::effects::{ReadText, speech_voices};
+use crate::i18n::{flag, language_name, use_i18n};
+use crate::model::Settings;
+use yew::prelude::*;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct SpeechConfigProps {
+    pub settings: Settings,
+    pub on_change: Callback<Settings>,
+    /// Languages to pick a voice for.
+    #[prop_or_else(|| vec!["de".to_string(), "en".to_string()])]
+    pub languages: Vec<String>,
+}
+
+fn sample_text(lang: &str) -> &'static str {
+    match lang {
+        "de" => "Guten Tag! Ich lese dir die Aufgaben vor.",
+        _ => "Hello! I will read the tasks to you.",
+    }
+}
+
+/// Voice, speaking rate and slow speech for text-to-speech.
+#[function_component(SpeechConfig)]
+pub fn speech_config(props: &SpeechConfigProps) -> Html {
+    let i18n = use_i18n();
+    let language = use_state(|| props.languages.first().cloned().unwrap_or_default());
+    let replay = use_state(|| 0u32);
+    let update = use_force_update();
+
+    // Browsers load their voices lazily and announce them with `voiceschanged`.
+    #[cfg(all(feature = "csr", feature = "effects"))]
+    use_effect_with((), move |_| {
+        let listener = gloo::utils::window().speech_synthesis().ok().map(|ss| {
+            gloo::events::EventListener::new(&ss, "voiceschanged", move |_| update.force_update())
+        });
+        move || drop(listener)
+    });
+    #[cfg(not(all(feature = "csr", feature = "effects")))]
+    let _ = update;
+
+    #[cfg(feature = "effects")]
+    let voices = speech_voices(&language);
+    #[cfg(not(feature = "effects"))]
+    let voices: Vec<String> = Vec::new();
+    let voice = props.settings.speech_voice(&language).map(str::to_string);
+    let speech_rate = props.settings.speech_rate;
+
+    let on_change_language = {
+        let language = language.clone();
+        Callback::from(move |e: Event| {
+            #[cfg(feature = "csr")]
+            if let Some(select) = e.target_dyn_into::<web_sys::HtmlSelectElement>() {
+                language.set(select.value());
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (e, &language);
+        })
+    };
+
+    let on_change_voice = {
+        let settings = props.settings.clone();
+        let on_change = props.on_change.clone();
+        let language = (*language).clone();
+        Callback::from(move |e: Event| {
+            #[cfg(feature = "csr")]
+            if let Some(select) = e.target_dyn_into::<web_sys::HtmlSelectElement>() {
+                let voice = Some(select.value()).filter(|voice| !voice.is_empty());
+                let mut settings = settings.clone();
+                settings.set_speech_voice(&language, voice);
+                on_change.emit(settings);
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (e, &settings, &on_change, &language);
+        })
+    };
+
+    let on_change_rate = {
+        let settings = props.settings.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            #[cfg(feature = "csr")]
+            {
+                use web_sys::HtmlInputElement;
+                let input: HtmlInputElement = e.target_unchecked_into();
+                let speech_rate = input.value().parse().unwrap_or(1.0);
+                on_change.emit(Settings {
+                    speech_rate,
+                    ..settings.clone()
+                });
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (e, &settings, &on_change);
+        })
+    };
+
+    let on_toggle_slow_speech = {
+        let settings = props.settings.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: Event| {
+            on_change.emit(Settings {
+                slow_speech: !settings.slow_speech,
+                ..settings.clone()
+            });
+        })
+    };
+
+    let on_test = {
+        let replay = replay.clone();
+        Callback::from(move |_: MouseEvent| replay.set(*replay + 1))
+    };
+
+    // Reads with the settings chosen here, which may not be saved yet
+    #[cfg(feature = "effects")]
+    let test_speech = if *replay > 0 {
+        html! {
+            <ReadText
+                text={sample_text(&language)}
+                lang={(*language).clone()}
+                voice={voice.clone().or_else(|| voices.first().cloned())}
+                rate_factor={Some(props.settings.speech_rate_factor())}
+                replay={*replay}
+            />
+        }
+    } else {
+        html! {}
+    };
+    #[cfg(not(feature = "effects"))]
+    let test_speech = {
+        let _ = sample_text;
+        html! {}
+    };
+
+    html! {
+        <div class="speech-config">
+            <div class="speech-config__row">
+                <label for="speech-language">{ i18n.t("Language") }</label>
+                <select
+                    id="speech-language"
+                    class="speech-config__select"
+                    onchange={on_change_language}
+                >
+                    { for props.languages.iter().map(|lang| html! {
+                        <option value={lang.clone()} selected={*lang == *language}>
+                            { format!("{} {}", flag(lang), language_name(lang)) }
+                        </option>
+                    })}
+                </select>
+            </div>
+            <div class="speech-config__row">
+                <label for="speech-voice">{ i18n.t("Voice") }</label>
+                <select id="speech-voice" class="speech-config__select" onchange={on_change_voice}>
+                    <option value="" selected={voice.is_none()}>{ i18n.t("Default voice") }</option>
+                    { for voices.iter().map(|name| html! {
+                        <option value={name.clone()} selected={voice.as_ref() == Some(name)}>
+                            { name }
+                        </option>
+                    })}
+                </select>
+            </div>
+            if voices.is_empty() {
+                <p class="speech-config__hint">
+                    { i18n.t("No voices are installed for this language.") }
+                </p>
+            }
+            <div class="speech-config__row">
+                <label for="speech-rate">{ i18n.t("Speech Rate") }</label>
+                <input
+                    id="speech-rate"
+                    type="range"
+                    min="0.5"
+                    max="2"
+                    step="0.1"
+                    value={speech_rate.to_string()}
+                    oninput={on_change_rate}
+                />
+                <output>{ format!("{:.1}×", speech_rate) }</output>
+            </div>
+            <label class="speech-config__option">
+                <input
+                    type="checkbox"
+                    checked={props.settings.slow_speech}
+                    onchange={on_toggle_slow_speech}
+                />
+                { i18n.t("Slow speech") }
+            </label>
+            <button class="speech-config__test" onclick={on_test}>
+                <i class="fa-solid fa-volume-high" aria-hidden="true"></i>
+                { i18n.t("Test voice") }
+            </button>
+            { test_speech }
+        </div>
+    }
+}
+
+#[cfg(feature = "yew-preview")]
+mod preview {
+    use super::*;
+    use yew_preview::prelude::*;
+
+    yew_preview::create_preview!(
+        SpeechConfig,
+        SpeechConfigProps {
+            settings: Settings::default(),
+            on_change: Callback::noop(),
+            languages: vec!["de".to_string(), "en".to_string()],
+        },
+        (
+            "Slow speech",
+            SpeechConfigProps {
+                settings: Settings {
+                    speech_rate: 0.8,
+                    slow_speech: true,
+                    ..Settings::default()
+                },
+                on_change: Callback::noop(),
+                languages: vec!["de".to_string()],
+            }
+        ),
+    );
+}
@@ -25,7 +25,7 @@ pub fn timer_component(props: &TimerProps) -> Html {
     }
 }
 
-fn format_duration(duration: Duration, show_milliseconds: bool) -> String {
+pub(crate) fn format_duration(duration: Duration, show_milliseconds: bool) -> String {
     let milliseconds = duration.num_milliseconds() % 1000;
     let seconds = duration.num_seconds() % 60;
     let minutes = duration.num_minutes() % 60;
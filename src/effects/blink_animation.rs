@@ -38,16 +38,19 @@ pub fn blink_animation(props: &BlinkAnimationProps) -> Html {
 
     #[cfg(feature = "csr")]
     {
+        use crate::model::Accessibility;
         use gloo::timers::future::TimeoutFuture;
         use wasm_bindgen::JsCast;
         use web_sys::{HtmlElement, window};
 
         use_effect_with(target_id.clone(), move |_| {
+            // No blinking while motion is reduced.
             if let Some(document) = window()
                 .unwrap()
                 .document()
                 .unwrap()
                 .get_element_by_id(&target_id)
+                .filter(|_| !Accessibility::motion_reduced_on_body())
             {
                 let element = document.dyn_into::<HtmlElement>().unwrap();
                 element.class_list().add_1(&class_name).unwrap();
@@ -4,4 +4,4 @@ mod blink_animation;
 mod read_text;
 
 pub use blink_animation::BlinkAnimation;
-pub use read_text::ReadText;
+pub use read_text::{ReadText, speech_voices};
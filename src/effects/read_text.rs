@@ -7,22 +7,97 @@ pub struct ReadTextProps {
     pub text: String,
     #[prop_or("en-US".to_string())]
     pub lang: String,
+    /// Voice pitch, from `0.0` to `2.0`.
+    #[prop_or(1.0)]
+    pub pitch: f32,
+    /// Speaking rate, from `0.1` to `10.0`. The speech rate and slow speech
+    /// of the settings apply on top.
+    #[prop_or(1.0)]
+    pub rate: f32,
+    /// Factor on `rate` to use instead of the speech rate and slow speech of
+    /// the settings, such as [`Settings::speech_rate_factor`] of settings
+    /// that aren't saved yet.
+    ///
+    /// [`Settings::speech_rate_factor`]: crate::model::Settings::speech_rate_factor
+    #[prop_or_default]
+    pub rate_factor: Option<f32>,
+    /// Name of the voice to speak with, see [`speech_voices`]. Falls back to
+    /// the preferred voice for `lang` in the settings, and then to the first
+    /// installed voice for `lang`.
+    #[prop_or_default]
+    pub voice: Option<String>,
+    /// Holds the speech while `true` and resumes it afterwards.
+    #[prop_or(false)]
+    pub paused: bool,
+    /// Reads the text again whenever this value changes.
+    #[prop_or_default]
+    pub replay: u32,
+    #[prop_or_default]
+    pub on_start: Option<Callback<()>>,
+    /// Called when the text has been read to the end, not when the speech is
+    /// cancelled.
+    #[prop_or_default]
+    pub on_end: Option<Callback<()>>,
+}
+
+/// Names of the installed speech voices for `lang`, matched on the primary
+/// language subtag, so `"de"` finds `"de-DE"` and `"de-AT"` voices.
+///
+/// Some browsers load their voices lazily, so this can be empty on the first
+/// call.
+pub fn speech_voices(lang: &str) -> Vec<String> {
+    #[cfg(feature = "csr")]
+    {
+        use crate::model::primary_language;
+        use gloo::utils::window;
+        use wasm_bindgen::JsCast;
+        use web_sys::SpeechSynthesisVoice;
+
+        let wanted = primary_language(lang);
+        let Ok(ss) = window().speech_synthesis() else {
+            return vec![];
+        };
+        ss.get_voices()
+            .iter()
+            .filter_map(|voice| voice.dyn_into::<SpeechSynthesisVoice>().ok())
+            .filter(|voice| primary_language(&voice.lang()) == wanted)
+            .map(|voice| voice.name())
+            .collect()
+    }
+    #[cfg(not(feature = "csr"))]
+    {
+        let _ = lang;
+        vec![]
+    }
 }
 
 #[function_component(ReadText)]
 pub fn read_text(props: &ReadTextProps) -> Html {
     #[cfg(feature = "csr")]
     {
+        use crate::model::primary_language;
         use crate::providers::use_settings;
         use gloo::timers::callback::Timeout;
         use gloo::utils::window;
-        use web_sys::SpeechSynthesisUtterance;
+        use std::cell::{Cell, RefCell};
+        use std::rc::Rc;
+        use wasm_bindgen::JsCast;
+        use wasm_bindgen::prelude::Closure;
+        use web_sys::{SpeechSynthesisUtterance, SpeechSynthesisVoice};
+
+        type Speech = (SpeechSynthesisUtterance, Vec<Closure<dyn FnMut()>>);
 
         let settings = use_settings();
 
         let text_clone = props.text.clone();
         let lang_clone = props.lang.clone();
-        use_effect_with(props.text.clone(), move |_| {
+        let pitch = props.pitch;
+        let rate = props.rate;
+        let rate_factor = props.rate_factor;
+        let voice = props.voice.clone();
+        let on_start = props.on_start.clone();
+        let on_end = props.on_end.clone();
+        use_effect_with((props.text.clone(), props.replay), move |_| {
             let settings = settings.clone();
 
             // Resolve speech synthesis once so both the speak and cleanup share it
@@ -31,20 +106,72 @@ pub fn read_text(props: &ReadTextProps) -> Html {
                 warn!("Speech synthesis not available in this browser");
             }
 
+            // The utterance and its event handlers live until the cleanup,
+            // which detaches them so a cancelled speech doesn't report its end.
+            let cancelled = Rc::new(Cell::new(false));
+            let speaking: Rc<RefCell<Option<Speech>>> = Rc::default();
+
             if let Some(ss) = speech_synthesis.clone() {
                 // Cancel any queued or ongoing speech before starting the new one
                 ss.cancel();
 
-                Timeout::new(0, move || match SpeechSynthesisUtterance::new() {
-                    Ok(utterance) => {
-                        utterance.set_text(&text_clone);
-                        utterance.set_lang(&lang_clone);
-                        utterance.set_volume(settings.sound_volume);
-                        ss.speak(&utterance);
-                        debug!("Requested text-to-speech for: {}", text_clone);
+                let cancelled = cancelled.clone();
+                let speaking = speaking.clone();
+                Timeout::new(0, move || {
+                    if cancelled.get() {
+                        return;
                     }
-                    Err(err) => {
-                        debug!("Failed to create speech utterance: {:?}", err);
+                    match SpeechSynthesisUtterance::new() {
+                        Ok(utterance) => {
+                            utterance.set_text(&text_clone);
+                            utterance.set_lang(&lang_clone);
+                            utterance.set_volume(settings.sound_volume);
+                            utterance.set_pitch(pitch);
+                            utterance.set_rate(
+                                rate * rate_factor.unwrap_or_else(|| settings.speech_rate_factor()),
+                            );
+                            let name = voice
+                                .clone()
+                                .or_else(|| settings.speech_voice(&lang_clone).map(str::to_string));
+                            let installed: Vec<SpeechSynthesisVoice> = ss
+                                .get_voices()
+                                .iter()
+                                .filter_map(|v| v.dyn_into::<SpeechSynthesisVoice>().ok())
+                                .collect();
+                            // Without a voice, browsers may read with their
+                            // default voice, even of another language.
+                            let language = primary_language(&lang_clone);
+                            let chosen = name
+                                .and_then(|name| installed.iter().find(|v| v.name() == name))
+                                .or_else(|| {
+                                    installed
+                                        .iter()
+                                        .find(|v| primary_language(&v.lang()) == language)
+                                });
+                            if chosen.is_some() {
+                                utterance.set_voice(chosen);
+                            }
+
+                            let mut handlers = Vec::new();
+                            if let Some(on_start) = on_start {
+                                let handler =
+                                    Closure::<dyn FnMut()>::new(move || on_start.emit(()));
+                                utterance.set_onstart(Some(handler.as_ref().unchecked_ref()));
+                                handlers.push(handler);
+                            }
+                            if let Some(on_end) = on_end {
+                                let handler = Closure::<dyn FnMut()>::new(move || on_end.emit(()));
+                                utterance.set_onend(Some(handler.as_ref().unchecked_ref()));
+                                handlers.push(handler);
+                            }
+
+                            ss.speak(&utterance);
+                            debug!("Requested text-to-speech for: {}", text_clone);
+                            *speaking.borrow_mut() = Some((utterance, handlers));
+                        }
+                        Err(err) => {
+                            debug!("Failed to create speech utterance: {:?}", err);
+                        }
                     }
                 })
                 .forget();
@@ -52,11 +179,26 @@ pub fn read_text(props: &ReadTextProps) -> Html {
 
             // Cancel speech when text changes or component unmounts
             move || {
+                cancelled.set(true);
+                if let Some((utterance, _handlers)) = speaking.borrow_mut().take() {
+                    utterance.set_onstart(None);
+                    utterance.set_onend(None);
+                }
                 if let Some(ss) = speech_synthesis {
                     ss.cancel();
                 }
             }
         });
+
+        use_effect_with(props.paused, |paused| {
+            if let Ok(ss) = window().speech_synthesis() {
+                if *paused {
+                    ss.pause();
+                } else {
+                    ss.resume();
+                }
+            }
+        });
     }
 
     html! {
@@ -74,20 +216,55 @@ mod preview {
         ReadText,
         ReadTextProps {
             text: "Hello, World!".to_string(),
-            lang: "en-US".to_string()
+            lang: "en-US".to_string(),
+            pitch: 1.0,
+            rate: 1.0,
+            voice: None,
+            paused: false,
+            replay: 0,
+            on_start: None,
+            on_end: None,
         },
         (
             "german",
             ReadTextProps {
                 text: "Hallo, Welt!".to_string(),
-                lang: "de-DE".to_string()
+                lang: "de-DE".to_string(),
+                pitch: 1.0,
+                rate: 1.0,
+                voice: None,
+                paused: false,
+                replay: 0,
+                on_start: None,
+                on_end: None,
             }
         ),
         (
             "french",
             ReadTextProps {
                 text: "Bonjour le monde!".to_string(),
-                lang: "fr-FR".to_string()
+                lang: "fr-FR".to_string(),
+                pitch: 1.0,
+                rate: 1.0,
+                voice: None,
+                paused: false,
+                replay: 0,
+                on_start: None,
+                on_end: None,
+            }
+        ),
+        (
+            "low and slow",
+            ReadTextProps {
+                text: "Guten Morgen!".to_string(),
+                lang: "de-DE".to_string(),
+                pitch: 0.7,
+                rate: 0.8,
+                voice: None,
+                paused: false,
+                replay: 0,
+                on_start: None,
+                on_end: None,
             }
         )
     );
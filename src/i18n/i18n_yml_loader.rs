@@ -14,8 +14,14 @@ pub struct I18nYmlLoader {
 
 impl I18nYmlLoader {
     pub fn new(yml_data: &str) -> Self {
-        let i18n = serde_yaml::from_str::<YmlFile>(yml_data).unwrap().i18n;
-        I18nYmlLoader { i18n }
+        Self::try_new(yml_data).unwrap()
+    }
+
+    /// Like [`Self::new`], but returns an error for invalid YAML instead of
+    /// panicking, e.g. for translations of community content.
+    pub fn try_new(yml_data: &str) -> Result<Self, serde_yaml::Error> {
+        let i18n = serde_yaml::from_str::<YmlFile>(yml_data)?.i18n;
+        Ok(I18nYmlLoader { i18n })
     }
 }
 
@@ -43,4 +49,9 @@ mod tests {
         let data = loader.get_all();
         assert!(data.is_some());
     }
+
+    #[test]
+    fn test_try_new_rejects_invalid_yaml() {
+        assert!(I18nYmlLoader::try_new("i18n: [not, a, map]").is_err());
+    }
 }
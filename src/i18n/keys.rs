@@ -0,0 +1,52 @@
+//! Translation keys checked at compile time.
+//!
+//! `build.rs` generates one constant per key found in `assets/i18n/en.json`
+//! and `assets/i18n/i18n.yml`, so a misspelled key is a compile error instead
+//! of an untranslated string at runtime. Use them through the [`t!`](crate::t)
+//! macro; keys that are only known at runtime, such as `level.name`, keep
+//! going through `I18nConfig::t`.
+
+include!(concat!(env!("OUT_DIR"), "/i18n_keys.rs"));
+
+/// Looks up a compile-time checked translation key.
+///
+/// `t!(KEY)` expands to the key string, `t!(i18n, KEY)` translates it with
+/// the given `I18nConfig`. Two keys may not share a constant name; the build
+/// fails until they are merged in `assets/i18n`.
+///
+/// # Examples
+///
+/// ```
+/// use konnektoren_yew::i18n::create_i18n_config;
+/// use konnektoren_yew::t;
+///
+/// let i18n = create_i18n_config();
+/// assert_eq!(t!(SELECT_LEVEL), "Select Level");
+/// assert_eq!(t!(i18n, SELECT_LEVEL), "Select Level");
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($key:ident) => {
+        $crate::i18n::keys::$key
+    };
+    ($i18n:expr, $key:ident) => {
+        $i18n.t($crate::i18n::keys::$key)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::create_i18n_config;
+
+    #[test]
+    fn test_keys_are_translated() {
+        let i18n = create_i18n_config();
+        assert_eq!(crate::t!(SELECT_LEVEL), "Select Level");
+        assert_eq!(crate::konnektoren_t!(i18n, DESCRIPTION), "Description");
+        assert_eq!(TASKS_399E986D, "Tasks");
+        assert_eq!(TASKS_0BA9D0F5, "Tasks:");
+        assert!(ALL.contains(&TASKS_399E986D));
+        assert!(ALL.contains(&DESCRIPTION));
+    }
+}
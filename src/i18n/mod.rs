@@ -9,6 +9,10 @@ mod provider;
 mod i18n_json_loader;
 mod i18n_loader;
 mod i18n_yml_loader;
+/// The `keys` module contains translation key constants generated at build time.
+pub mod keys;
+/// The `pseudo` module builds a pseudo-locale for layout testing.
+mod pseudo;
 /// The `selected_language` module manages the state of the currently selected language.
 mod selected_language;
 
@@ -17,6 +21,10 @@ pub use config::{I18nConfig, create_i18n_config};
 pub use i18n_json_loader::I18nJsonLoader;
 pub use i18n_loader::I18nLoader;
 pub use i18n_yml_loader::I18nYmlLoader;
+pub use pseudo::{
+    PSEUDO_BASE_LANGUAGE_CODE, PSEUDO_LANGUAGE_CODE, is_pseudo_language, pseudo_language,
+    pseudo_localize, pseudo_missing, with_pseudo_locale,
+};
 
 /// A constant key used to store the selected language in storage.
 pub const LANGUAGE_KEY: &str = "selected_language";
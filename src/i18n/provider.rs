@@ -1,6 +1,9 @@
 #[cfg(feature = "csr")]
 use super::supported_language_from_candidates;
-use super::{SelectedLanguage, supported_language_code};
+use super::{
+    SelectedLanguage, is_pseudo_language, pseudo_language, supported_language_code,
+    with_pseudo_locale,
+};
 use crate::model::Settings;
 use crate::providers::use_settings;
 use konnektoren_rs::platform::i18n::I18nConfig;
@@ -45,6 +48,9 @@ fn get_url_language(supported_languages: &[Language]) -> Option<Language> {
     let search_params = UrlSearchParams::new_with_str(&search).ok()?;
 
     let lang = search_params.get("lang")?;
+    if is_pseudo_language(&lang) {
+        return Some(pseudo_language());
+    }
     language_from_code(&lang, supported_languages)
 }
 
@@ -110,7 +116,11 @@ fn get_browser_language(_supported_languages: &[Language]) -> Option<Language> {
 #[cfg(feature = "ssr")]
 fn get_env_language(supported_languages: &[Language]) -> Option<Language> {
     std::env::var("LANG").ok().and_then(|lang| {
-        let language = language_from_code(&lang, supported_languages)?;
+        let language = if is_pseudo_language(&lang) {
+            pseudo_language()
+        } else {
+            language_from_code(&lang, supported_languages)?
+        };
         tracing::debug!(
             "🌐 Using language from environment: LANG={} resolved to {}",
             lang,
@@ -120,6 +130,39 @@ fn get_env_language(supported_languages: &[Language]) -> Option<Language> {
     })
 }
 
+/// Whether the pseudo-locale was requested via `?lang=xx` or, in SSR, `LANG=xx`.
+#[cfg(feature = "csr")]
+fn pseudo_locale_requested() -> bool {
+    use gloo::utils::window;
+    use web_sys::UrlSearchParams;
+
+    window()
+        .location()
+        .search()
+        .ok()
+        .and_then(|search| UrlSearchParams::new_with_str(&search).ok())
+        .and_then(|params| params.get("lang"))
+        .is_some_and(|lang| is_pseudo_language(&lang))
+}
+
+#[cfg(all(not(feature = "csr"), feature = "ssr"))]
+fn pseudo_locale_requested() -> bool {
+    std::env::var("LANG").is_ok_and(|lang| is_pseudo_language(&lang))
+}
+
+#[cfg(all(not(feature = "csr"), not(feature = "ssr")))]
+fn pseudo_locale_requested() -> bool {
+    false
+}
+
+fn provided_config(config: &I18nConfig) -> I18nConfig {
+    if pseudo_locale_requested() {
+        with_pseudo_locale(config.clone())
+    } else {
+        config.clone()
+    }
+}
+
 fn determine_language(config: &I18nConfig, settings: &UseStateHandle<Settings>) -> Language {
     let supported_languages = config.supported_languages();
 
@@ -197,10 +240,12 @@ pub fn i18n_provider(props: &I18nProviderProps) -> Html {
     }
 
     let settings = use_settings();
+    // Building the pseudo-locale walks every key, so only redo it for a new config.
+    let props_config = (*use_memo(props.config.clone(), provided_config)).clone();
 
     let config_ctx = use_state(|| {
-        let language = determine_language(&props.config, &settings);
-        let mut config = props.config.clone();
+        let language = determine_language(&props_config, &settings);
+        let mut config = props_config.clone();
         config.default_language = language;
         config
     });
@@ -208,7 +253,6 @@ pub fn i18n_provider(props: &I18nProviderProps) -> Html {
     {
         let config_ctx = config_ctx.clone();
         let settings = settings.clone();
-        let props_config = props.config.clone();
 
         use_effect_with(settings.clone(), move |settings| {
             let language = determine_language(&props_config, settings);
@@ -240,7 +284,7 @@ pub fn i18n_provider(props: &I18nProviderProps) -> Html {
 pub fn browser_i18n_provider(props: &I18nProviderProps) -> Html {
     use crate::providers::SettingsContext;
 
-    let initial_config = props.config.clone();
+    let initial_config = (*use_memo(props.config.clone(), provided_config)).clone();
     let supported_languages = initial_config.supported_languages();
 
     // Optionally read settings — returns None when SettingsProvider is not in the tree
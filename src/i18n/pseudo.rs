@@ -0,0 +1,201 @@
+use konnektoren_rs::platform::i18n::{I18nConfig, Language};
+use serde_json::{Map, Value};
+
+/// Language code that selects the pseudo-locale, e.g. `?lang=xx`.
+pub const PSEUDO_LANGUAGE_CODE: &str = "xx";
+
+/// Language whose strings are transformed to build the pseudo-locale.
+pub const PSEUDO_BASE_LANGUAGE_CODE: &str = "en";
+
+/// Pseudo-localized text is expanded by this percentage of the source length.
+const EXPANSION_PERCENT: usize = 40;
+
+pub fn is_pseudo_language(code: &str) -> bool {
+    code.trim().eq_ignore_ascii_case(PSEUDO_LANGUAGE_CODE)
+}
+
+fn accent(c: char) -> char {
+    match c {
+        'a' => 'á',
+        'c' => 'ç',
+        'e' => 'é',
+        'g' => 'ĝ',
+        'i' => 'í',
+        'l' => 'ļ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        's' => 'š',
+        'u' => 'ü',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Å',
+        'C' => 'Ç',
+        'E' => 'É',
+        'G' => 'Ĝ',
+        'I' => 'Î',
+        'L' => 'Ļ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'S' => 'Š',
+        'U' => 'Ü',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        c => c,
+    }
+}
+
+/// Transforms a string into its pseudo-localized form.
+///
+/// Letters are accented, the text is padded by about 40% and wrapped in
+/// brackets so truncated or concatenated strings are easy to spot.
+/// `{placeholder}` segments are kept as they are.
+///
+/// # Examples
+///
+/// ```
+/// use konnektoren_yew::i18n::pseudo_localize;
+/// assert_eq!(pseudo_localize("Save"), "[Šávé ~~]");
+/// ```
+pub fn pseudo_localize(text: &str) -> String {
+    let mut accented = String::with_capacity(text.len() * 2);
+    let mut in_placeholder = false;
+    for c in text.chars() {
+        match c {
+            '{' => in_placeholder = true,
+            '}' => in_placeholder = false,
+            _ => {}
+        }
+        accented.push(if in_placeholder { c } else { accent(c) });
+    }
+
+    let length = text.chars().count();
+    let padding = (length * EXPANSION_PERCENT).div_ceil(100).max(1);
+    format!("[{} {}]", accented, "~".repeat(padding))
+}
+
+/// Marks a key that has no source text in the pseudo-locale.
+pub fn pseudo_missing(key: &str) -> String {
+    format!("⟦MISSING: {}⟧", key)
+}
+
+/// Pseudo-localized translations of every known key.
+///
+/// Keys are collected from all languages and from `used`. Keys with base
+/// language text are pseudo-localized, all others become [`pseudo_missing`]
+/// markers.
+fn pseudo_translations(config: &I18nConfig, used: &[&str]) -> Map<String, Value> {
+    let base = config
+        .translations
+        .get(PSEUDO_BASE_LANGUAGE_CODE)
+        .and_then(Value::as_object);
+
+    let keys = config
+        .translations
+        .values()
+        .filter_map(Value::as_object)
+        .flat_map(|translations| translations.keys().map(String::as_str))
+        .chain(used.iter().copied());
+
+    let mut pseudo = Map::new();
+    for key in keys {
+        if pseudo.contains_key(key) {
+            continue;
+        }
+        let text = match base.and_then(|base| base.get(key)).and_then(Value::as_str) {
+            Some(text) => pseudo_localize(text),
+            None => pseudo_missing(key),
+        };
+        pseudo.insert(key.to_string(), Value::String(text));
+    }
+    pseudo
+}
+
+/// Adds the pseudo-locale as language [`PSEUDO_LANGUAGE_CODE`] and selects
+/// it, leaving the other languages untouched.
+///
+/// Keys that are missing from the base language, including keys of
+/// `i18n.yml` without English text, are rendered as [`pseudo_missing`]
+/// markers. Only keys built at runtime still pass through unmarked.
+pub fn with_pseudo_locale(mut config: I18nConfig) -> I18nConfig {
+    let pseudo = pseudo_translations(&config, super::keys::ALL);
+    config
+        .translations
+        .insert(PSEUDO_LANGUAGE_CODE.to_string(), Value::Object(pseudo));
+    config.default_language = pseudo_language();
+    config
+}
+
+/// The pseudo-locale language, which is not one of the built-in languages.
+pub fn pseudo_language() -> Language {
+    Language::from_code(PSEUDO_LANGUAGE_CODE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::create_i18n_config;
+    use serde_json::json;
+
+    #[test]
+    fn test_is_pseudo_language() {
+        assert!(is_pseudo_language("xx"));
+        assert!(is_pseudo_language(" XX "));
+        assert!(!is_pseudo_language("en"));
+    }
+
+    #[test]
+    fn test_pseudo_localize() {
+        let text = "Select Level";
+        let pseudo = pseudo_localize(text);
+
+        assert!(pseudo.starts_with('['));
+        assert!(pseudo.ends_with(']'));
+        assert!(pseudo.contains("Šéļéçt Ļévéļ"));
+        assert!(pseudo.chars().count() as f32 >= text.chars().count() as f32 * 1.4);
+    }
+
+    #[test]
+    fn test_pseudo_localize_keeps_placeholders() {
+        assert_eq!(pseudo_localize("{name}"), "[{name} ~~~]");
+    }
+
+    #[test]
+    fn test_with_pseudo_locale() {
+        let mut config = create_i18n_config();
+        config.merge_translation(
+            &Language::from("de"),
+            json!({ "OnlyGerman": "Nur Deutsch" }),
+        );
+
+        let config = with_pseudo_locale(config);
+        let xx = pseudo_language();
+        let en = Language::from(PSEUDO_BASE_LANGUAGE_CODE);
+
+        assert_eq!(config.default_language.code(), PSEUDO_LANGUAGE_CODE);
+        assert_eq!(config.t("Language"), pseudo_localize("Language"));
+        assert_eq!(
+            config.t_with_lang("OnlyGerman", &xx),
+            pseudo_missing("OnlyGerman")
+        );
+        assert_eq!(config.t_with_lang("Language", &en), "Language");
+        assert_eq!(
+            config.t_with_lang("Language", &Language::from("de")),
+            "Sprache"
+        );
+    }
+
+    #[test]
+    fn test_untranslated_keys_are_marked() {
+        let config = create_i18n_config();
+        let pseudo = pseudo_translations(&config, &["Language", "Never translated"]);
+
+        assert_eq!(
+            pseudo.get("Never translated").and_then(Value::as_str),
+            Some(pseudo_missing("Never translated").as_str())
+        );
+        assert_eq!(
+            pseudo.get("Language").and_then(Value::as_str),
+            Some(pseudo_localize("Language").as_str())
+        );
+    }
+}
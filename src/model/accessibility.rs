@@ -0,0 +1,214 @@
+use serde::{Deserialize, Serialize};
+
+/// Text sizes in percent that have a body class.
+pub const TEXT_SCALES: [u16; 5] = [90, 100, 115, 130, 150];
+
+const CLASS_PREFIX: &str = "a11y-";
+
+fn default_text_scale() -> u16 {
+    100
+}
+
+/// Whether animations play.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MotionPreference {
+    /// Follow `prefers-reduced-motion` of the system.
+    #[default]
+    System,
+    Reduce,
+    Allow,
+}
+
+impl MotionPreference {
+    pub const ALL: [MotionPreference; 3] = [Self::System, Self::Reduce, Self::Allow];
+
+    /// Untranslated label for the motion select.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::System => "Follow system",
+            Self::Reduce => "Reduce motion",
+            Self::Allow => "Allow motion",
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::System => "system",
+            Self::Reduce => "reduce",
+            Self::Allow => "allow",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|motion| motion.value() == value)
+            .unwrap_or_default()
+    }
+}
+
+/// Accessibility preferences, applied as `a11y-*` classes on `<body>`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Accessibility {
+    /// Text size in percent, one of [`TEXT_SCALES`].
+    #[serde(default = "default_text_scale")]
+    pub text_scale: u16,
+    /// Use the OpenDyslexic font.
+    #[serde(default)]
+    pub dyslexia_font: bool,
+    /// Use the high-contrast variant of the theme.
+    #[serde(default)]
+    pub high_contrast: bool,
+    #[serde(default)]
+    pub motion: MotionPreference,
+}
+
+impl Default for Accessibility {
+    fn default() -> Self {
+        Self {
+            text_scale: default_text_scale(),
+            dyslexia_font: false,
+            high_contrast: false,
+            motion: MotionPreference::default(),
+        }
+    }
+}
+
+impl Accessibility {
+    pub fn reduces_motion(&self, system_prefers_reduced_motion: bool) -> bool {
+        match self.motion {
+            MotionPreference::System => system_prefers_reduced_motion,
+            MotionPreference::Reduce => true,
+            MotionPreference::Allow => false,
+        }
+    }
+
+    /// The text scale with a body class closest to `text_scale`.
+    pub fn text_scale_step(&self) -> u16 {
+        TEXT_SCALES
+            .into_iter()
+            .min_by_key(|scale| scale.abs_diff(self.text_scale))
+            .unwrap_or(100)
+    }
+
+    pub fn body_classes(&self, system_prefers_reduced_motion: bool) -> Vec<String> {
+        let mut classes = vec![format!("{}text-{}", CLASS_PREFIX, self.text_scale_step())];
+        if self.dyslexia_font {
+            classes.push(format!("{}dyslexia-font", CLASS_PREFIX));
+        }
+        if self.high_contrast {
+            classes.push(format!("{}high-contrast", CLASS_PREFIX));
+        }
+        if self.reduces_motion(system_prefers_reduced_motion) {
+            classes.push(format!("{}reduced-motion", CLASS_PREFIX));
+        }
+        classes
+    }
+
+    /// Writes these preferences' classes to `<body>`, replacing any other
+    /// `a11y-*` class.
+    #[cfg(feature = "csr")]
+    pub fn apply_to_body(&self, system_prefers_reduced_motion: bool) {
+        if let Some(body) = gloo::utils::document().body() {
+            let mut classes: Vec<String> = body
+                .class_name()
+                .split_whitespace()
+                .filter(|class| !class.starts_with(CLASS_PREFIX))
+                .map(String::from)
+                .collect();
+            classes.extend(self.body_classes(system_prefers_reduced_motion));
+            body.set_class_name(&classes.join(" "));
+        }
+    }
+
+    /// Whether `<body>` asks for reduced motion, for effects that animate
+    /// from code.
+    pub fn motion_reduced_on_body() -> bool {
+        #[cfg(feature = "csr")]
+        {
+            gloo::utils::document().body().is_some_and(|body| {
+                body.class_list()
+                    .contains(&format!("{}reduced-motion", CLASS_PREFIX))
+            })
+        }
+
+        #[cfg(not(feature = "csr"))]
+        {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_preferences_only_set_the_text_size() {
+        assert_eq!(
+            Accessibility::default().body_classes(false),
+            vec!["a11y-text-100"]
+        );
+        assert_eq!(
+            Accessibility::default().body_classes(true),
+            vec!["a11y-text-100", "a11y-reduced-motion"]
+        );
+    }
+
+    #[test]
+    fn preferences_become_classes() {
+        let accessibility = Accessibility {
+            text_scale: 130,
+            dyslexia_font: true,
+            high_contrast: true,
+            motion: MotionPreference::Allow,
+        };
+        assert_eq!(
+            accessibility.body_classes(true),
+            vec!["a11y-text-130", "a11y-dyslexia-font", "a11y-high-contrast"]
+        );
+    }
+
+    #[test]
+    fn text_scale_snaps_to_a_step() {
+        let scale = |text_scale| {
+            Accessibility {
+                text_scale,
+                ..Accessibility::default()
+            }
+            .text_scale_step()
+        };
+        assert_eq!(scale(100), 100);
+        assert_eq!(scale(120), 115);
+        assert_eq!(scale(400), 150);
+        assert_eq!(scale(0), 90);
+    }
+
+    #[test]
+    fn motion_values_round_trip() {
+        for motion in MotionPreference::ALL {
+            assert_eq!(MotionPreference::from_value(motion.value()), motion);
+            assert_eq!(
+                serde_json::to_string(&motion).unwrap(),
+                format!("\"{}\"", motion.value())
+            );
+        }
+        assert_eq!(
+            MotionPreference::from_value("unknown"),
+            MotionPreference::System
+        );
+    }
+
+    #[test]
+    fn motion_follows_the_system_unless_chosen() {
+        let motion = |motion| Accessibility {
+            motion,
+            ..Accessibility::default()
+        };
+        assert!(motion(MotionPreference::System).reduces_motion(true));
+        assert!(!motion(MotionPreference::System).reduces_motion(false));
+        assert!(motion(MotionPreference::Reduce).reduces_motion(false));
+        assert!(!motion(MotionPreference::Allow).reduces_motion(true));
+    }
+}
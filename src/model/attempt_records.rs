@@ -0,0 +1,119 @@
+use super::{ChallengeHints, DialogOutcome, challenge_performance, question_count};
+use konnektoren_core::challenges::Challenge;
+
+/// What is known about finished attempts beyond their history entries:
+/// the hints taken and the outcome of branching dialogs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AttemptRecords {
+    pub hints: Vec<ChallengeHints>,
+    pub dialog_outcomes: Vec<DialogOutcome>,
+}
+
+impl AttemptRecords {
+    /// Performance of a challenge from the history: the score of the dialog
+    /// outcome recorded with it, or else [`challenge_performance`], less the
+    /// penalty for the hints recorded with it.
+    pub fn performance(&self, challenge: &Challenge) -> u32 {
+        let performance = match self
+            .dialog_outcomes
+            .iter()
+            .rev()
+            .find(|outcome| outcome.matches(challenge))
+        {
+            Some(outcome) => outcome.score.min(100),
+            None => challenge_performance(challenge, &challenge.challenge_result),
+        };
+        match self
+            .hints
+            .iter()
+            .rev()
+            .find(|hints| hints.matches(challenge))
+        {
+            Some(recorded) => recorded
+                .hints
+                .apply_penalty(performance, question_count(challenge)),
+            None => performance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::HintUsage;
+    use chrono::Utc;
+    use konnektoren_core::challenges::{
+        ChallengeResult, ChallengeType, Dialog, MultipleChoice, MultipleChoiceOption, Question,
+    };
+
+    fn articles() -> Challenge {
+        let options: Vec<_> = ["der", "die"]
+            .iter()
+            .enumerate()
+            .map(|(id, name)| MultipleChoiceOption {
+                id,
+                name: name.to_string(),
+            })
+            .collect();
+        let questions = ["Tisch", "Lampe"]
+            .iter()
+            .zip([0, 1])
+            .map(|(question, option)| Question {
+                question: question.to_string(),
+                help: String::new(),
+                image: None,
+                option,
+            })
+            .collect();
+        let mut challenge = Challenge::default();
+        challenge.challenge_config.id = "articles".to_string();
+        challenge.challenge_type = ChallengeType::MultipleChoice(MultipleChoice {
+            id: "articles".to_string(),
+            name: "Articles".to_string(),
+            lang: "de".to_string(),
+            options: options.clone(),
+            questions,
+        });
+        challenge.challenge_result = ChallengeResult::MultipleChoice(options);
+        challenge.start_time = Some(Utc::now());
+        challenge
+    }
+
+    #[test]
+    fn recorded_hints_lower_history_performance() {
+        let challenge = articles();
+        assert_eq!(AttemptRecords::default().performance(&challenge), 100);
+
+        let mut hints = HintUsage::default();
+        hints.take(0);
+        hints.take(0);
+        let records = AttemptRecords {
+            hints: vec![ChallengeHints::new(&challenge, hints)],
+            ..Default::default()
+        };
+        assert_eq!(records.performance(&challenge), 75);
+
+        let mut other_attempt = challenge.clone();
+        other_attempt.start_time = Some(Utc::now() + chrono::Duration::seconds(5));
+        assert!(!records.hints[0].matches(&other_attempt));
+        assert_eq!(records.performance(&other_attempt), 100);
+    }
+
+    #[test]
+    fn dialog_outcomes_replace_the_core_score() {
+        let mut challenge = Challenge::default();
+        challenge.challenge_config.id = "bakery".to_string();
+        challenge.challenge_type = ChallengeType::Dialog(Dialog::default());
+        challenge.start_time = Some(Utc::now());
+        let records = AttemptRecords {
+            dialog_outcomes: vec![DialogOutcome {
+                challenge_id: "bakery".to_string(),
+                started_at: challenge.start_time,
+                ending: Some("Rude".to_string()),
+                score: 25,
+            }],
+            ..Default::default()
+        };
+        assert_eq!(records.performance(&challenge), 25);
+    }
+}
@@ -0,0 +1,116 @@
+use super::HintUsage;
+use konnektoren_core::challenges::ChallengeResult;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Progress of an unfinished challenge, saved after every answer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ChallengeCheckpoint {
+    /// The task to continue with.
+    pub task_index: usize,
+    /// Answers given so far.
+    pub challenge_result: ChallengeResult,
+    /// Hints taken so far.
+    #[serde(default)]
+    pub hints: HintUsage,
+    /// Answers to parts of `task_index` given before the task was done, such
+    /// as the filled gaps of a gap fill question.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub partial_answers: Vec<String>,
+    /// Typed answers of checked questions by question index, as entered.
+    /// The result holds the expected spelling for answers that only matched
+    /// after normalization.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub typed_answers: BTreeMap<usize, Vec<String>>,
+}
+
+impl ChallengeCheckpoint {
+    pub fn new(task_index: usize, challenge_result: ChallengeResult) -> Self {
+        Self {
+            task_index,
+            challenge_result,
+            hints: HintUsage::default(),
+            partial_answers: Vec::new(),
+            typed_answers: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_partial_answers(mut self, partial_answers: Vec<String>) -> Self {
+        self.partial_answers = partial_answers;
+        self
+    }
+
+    pub fn with_typed_answers(mut self, typed_answers: BTreeMap<usize, Vec<String>>) -> Self {
+        self.typed_answers = typed_answers;
+        self
+    }
+
+    /// Task index to resume at, clamped to the number of tasks.
+    pub fn resume_index(&self, total_tasks: usize) -> usize {
+        self.task_index.min(total_tasks.saturating_sub(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konnektoren_core::challenges::MultipleChoiceOption;
+
+    #[test]
+    fn resume_index_is_clamped() {
+        let checkpoint = ChallengeCheckpoint::new(5, ChallengeResult::default());
+        assert_eq!(checkpoint.resume_index(10), 5);
+        assert_eq!(checkpoint.resume_index(3), 2);
+        assert_eq!(checkpoint.resume_index(0), 0);
+    }
+
+    #[test]
+    fn ser_de_checkpoint() {
+        let checkpoint = ChallengeCheckpoint::new(
+            1,
+            ChallengeResult::MultipleChoice(vec![MultipleChoiceOption {
+                id: 0,
+                name: "der".to_string(),
+            }]),
+        );
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        let checkpoint2: ChallengeCheckpoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(checkpoint, checkpoint2);
+    }
+
+    #[test]
+    fn partial_answers_round_trip() {
+        let checkpoint = ChallengeCheckpoint::new(1, ChallengeResult::GapFill(vec![]))
+            .with_partial_answers(vec!["habe".to_string(), String::new()]);
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ChallengeCheckpoint>(&json).unwrap(),
+            checkpoint
+        );
+        let json = serde_json::to_string(&ChallengeCheckpoint::default()).unwrap();
+        assert!(!json.contains("partial_answers"));
+    }
+
+    #[test]
+    fn typed_answers_round_trip() {
+        let checkpoint = ChallengeCheckpoint::new(1, ChallengeResult::GapFill(vec![]))
+            .with_typed_answers(BTreeMap::from([(0, vec!["Strasse".to_string()])]));
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ChallengeCheckpoint>(&json).unwrap(),
+            checkpoint
+        );
+        let json = serde_json::to_string(&ChallengeCheckpoint::default()).unwrap();
+        assert!(!json.contains("typed_answers"));
+    }
+
+    #[test]
+    fn checkpoint_without_hints() {
+        let json = serde_json::to_string(&ChallengeCheckpoint::new(2, ChallengeResult::default()))
+            .unwrap()
+            .replace(",\"hints\":{\"questions\":{}}", "");
+        assert!(!json.contains("hints"));
+        let checkpoint: ChallengeCheckpoint = serde_json::from_str(&json).unwrap();
+        assert!(checkpoint.hints.is_empty());
+    }
+}
@@ -0,0 +1,352 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use konnektoren_core::challenges::{Challenge, ChallengeType};
+use konnektoren_core::game::Game;
+use serde::{Deserialize, Serialize};
+
+/// A downloaded challenge asset, such as a custom package or a markdown
+/// file, kept so the challenge can be played offline.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CachedContent {
+    pub url: String,
+    /// `ETag` the server sent with the content.
+    #[serde(default)]
+    pub etag: Option<String>,
+    /// `Last-Modified` the server sent with the content.
+    #[serde(default)]
+    pub last_modified: Option<String>,
+    /// The content, base64 encoded.
+    pub data: String,
+}
+
+impl CachedContent {
+    pub fn new(url: &str, data: &[u8]) -> Self {
+        Self {
+            url: url.to_string(),
+            data: STANDARD.encode(data),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_validators(mut self, etag: Option<String>, last_modified: Option<String>) -> Self {
+        self.etag = etag;
+        self.last_modified = last_modified;
+        self
+    }
+
+    /// Bytes the content takes up in storage, counted against the cache
+    /// budget.
+    pub fn stored_bytes(&self) -> usize {
+        self.url.len()
+            + self.data.len()
+            + self.etag.as_ref().map_or(0, String::len)
+            + self.last_modified.as_ref().map_or(0, String::len)
+    }
+
+    /// The content, `None` when the stored data is corrupt.
+    pub fn bytes(&self) -> Option<Vec<u8>> {
+        STANDARD.decode(&self.data).ok()
+    }
+
+    /// Headers that ask the server to answer `304 Not Modified` while the
+    /// cached copy is current.
+    pub fn conditional_headers(&self) -> Vec<(&'static str, &str)> {
+        let mut headers = Vec::new();
+        if let Some(etag) = &self.etag {
+            headers.push(("If-None-Match", etag.as_str()));
+        }
+        if let Some(last_modified) = &self.last_modified {
+            headers.push(("If-Modified-Since", last_modified.as_str()));
+        }
+        headers
+    }
+}
+
+/// Urls and sizes of the cached content, least recently used first, so the
+/// cache can stay within a byte budget.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContentCacheIndex {
+    #[serde(default)]
+    pub entries: Vec<ContentCacheEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContentCacheEntry {
+    pub url: String,
+    /// Size of the stored content, see [`CachedContent::stored_bytes`].
+    pub bytes: usize,
+}
+
+impl ContentCacheIndex {
+    pub fn total_bytes(&self) -> usize {
+        self.entries.iter().map(|entry| entry.bytes).sum()
+    }
+
+    /// Marks `url` as used most recently. `false` if it isn't cached.
+    pub fn touch(&mut self, url: &str) -> bool {
+        match self.entries.iter().position(|entry| entry.url == url) {
+            Some(index) => {
+                let entry = self.entries.remove(index);
+                self.entries.push(entry);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Adds `url` as used most recently, replacing an older copy, and
+    /// returns the urls evicted to stay within `budget` bytes, least recently
+    /// used first.
+    pub fn insert(&mut self, url: &str, bytes: usize, budget: usize) -> Vec<String> {
+        self.remove(url);
+        self.entries.push(ContentCacheEntry {
+            url: url.to_string(),
+            bytes,
+        });
+        let mut evicted = Vec::new();
+        while self.total_bytes() > budget && self.entries.len() > 1 {
+            evicted.push(self.entries.remove(0).url);
+        }
+        evicted
+    }
+
+    pub fn remove(&mut self, url: &str) {
+        self.entries.retain(|entry| entry.url != url);
+    }
+}
+
+/// Where the content of a challenge came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// Downloaded or confirmed current, and kept for offline use.
+    Fresh,
+    /// The network failed, so the cached copy is shown.
+    Offline,
+    /// Downloaded, but not kept for offline use.
+    Uncached,
+}
+
+impl CacheStatus {
+    /// Untranslated label for the cache status badge.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Fresh => "Available offline",
+            Self::Offline => "Offline copy",
+            Self::Uncached => "Not available offline",
+        }
+    }
+
+    pub fn modifier(&self) -> &'static str {
+        match self {
+            Self::Fresh => "fresh",
+            Self::Offline => "offline",
+            Self::Uncached => "uncached",
+        }
+    }
+}
+
+/// Kind of a challenge asset, which decides how a download is checked
+/// before it is cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    /// Package of a custom challenge.
+    Package,
+    /// Markdown text of an informative challenge.
+    Markdown,
+}
+
+impl ContentKind {
+    /// Rejects downloads that aren't content of this kind, such as the HTML
+    /// page a static host serves for a missing file.
+    pub fn validate(&self, data: &[u8]) -> Result<(), String> {
+        if data.is_empty() {
+            return Err("Empty content".to_string());
+        }
+        if *self == Self::Markdown {
+            let content = std::str::from_utf8(data)
+                .map_err(|e| format!("Failed to convert markdown content to string: {}", e))?
+                .to_lowercase();
+            if content.contains("<!doctype html")
+                || content.contains("<html")
+                || (content.contains("<script") && content.contains("wasm"))
+            {
+                return Err("Received an HTML page instead of markdown".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An asset a challenge downloads when it starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeContent {
+    pub url: String,
+    pub kind: ContentKind,
+}
+
+/// Assets `challenge` downloads in `language`, including the English
+/// fallback of informative texts.
+pub fn challenge_content(challenge: &Challenge, language: &str) -> Vec<ChallengeContent> {
+    match &challenge.challenge_type {
+        ChallengeType::Custom(custom) => custom
+            .package_url
+            .iter()
+            .map(|url| ChallengeContent {
+                url: url.clone(),
+                kind: ContentKind::Package,
+            })
+            .collect(),
+        ChallengeType::Informative(informative) => {
+            let mut content: Vec<ChallengeContent> = Vec::new();
+            for text in informative
+                .text
+                .iter()
+                .filter(|text| text.language == language || text.language == "en")
+                .filter(|text| is_markdown_path(&text.text))
+            {
+                if !content.iter().any(|c| c.url == text.text) {
+                    content.push(ChallengeContent {
+                        url: text.text.clone(),
+                        kind: ContentKind::Markdown,
+                    });
+                }
+            }
+            content
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Assets of the `count` challenges that follow `challenge_index` in game
+/// path `game_path`, to download ahead of time.
+pub fn upcoming_content(
+    game: &Game,
+    game_path: usize,
+    challenge_index: usize,
+    count: usize,
+    language: &str,
+) -> Vec<ChallengeContent> {
+    let Some(path) = game.game_paths.get(game_path) else {
+        return Vec::new();
+    };
+    path.challenges
+        .iter()
+        .skip(challenge_index + 1)
+        .take(count)
+        .filter_map(|config| game.create_challenge(&config.id).ok())
+        .flat_map(|challenge| challenge_content(&challenge, language))
+        .collect()
+}
+
+/// Whether an informative text refers to a markdown file rather than holding
+/// the text itself.
+pub fn is_markdown_path(text: &str) -> bool {
+    let text = text.trim();
+    !text.contains('\n') && text.to_ascii_lowercase().ends_with(".md")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_round_trips() {
+        let content = CachedContent::new("articles.md", b"# Artikel\n\nder, die, das");
+        assert_eq!(
+            content.bytes().as_deref(),
+            Some(&b"# Artikel\n\nder, die, das"[..])
+        );
+        let corrupt = CachedContent {
+            data: "not base64!".to_string(),
+            ..content
+        };
+        assert_eq!(corrupt.bytes(), None);
+    }
+
+    #[test]
+    fn validators_become_conditional_headers() {
+        let content = CachedContent::new("package.zip", &[1, 2, 3]);
+        assert!(content.conditional_headers().is_empty());
+        let content = content.with_validators(
+            Some("\"v2\"".to_string()),
+            Some("Mon, 19 Oct 2026 08:00:00 GMT".to_string()),
+        );
+        assert_eq!(
+            content.conditional_headers(),
+            vec![
+                ("If-None-Match", "\"v2\""),
+                ("If-Modified-Since", "Mon, 19 Oct 2026 08:00:00 GMT"),
+            ]
+        );
+    }
+
+    #[test]
+    fn least_recently_used_content_is_evicted() {
+        let mut index = ContentCacheIndex::default();
+        assert!(index.insert("a.zip", 40, 100).is_empty());
+        assert!(index.insert("b.zip", 40, 100).is_empty());
+        assert!(index.touch("a.zip"));
+        assert!(!index.touch("c.zip"));
+
+        assert_eq!(index.insert("c.zip", 40, 100), vec!["b.zip".to_string()]);
+        assert_eq!(index.total_bytes(), 80);
+
+        // A new copy replaces the old one instead of counting twice
+        assert!(index.insert("c.zip", 50, 100).is_empty());
+        assert_eq!(index.total_bytes(), 90);
+
+        index.remove("a.zip");
+        let urls: Vec<_> = index.entries.iter().map(|e| e.url.as_str()).collect();
+        assert_eq!(urls, vec!["c.zip"]);
+    }
+
+    #[test]
+    fn html_pages_are_not_markdown() {
+        assert!(ContentKind::Markdown.validate(b"# Artikel").is_ok());
+        assert!(
+            ContentKind::Markdown
+                .validate(b"<!DOCTYPE html><html><body>Not found</body></html>")
+                .is_err()
+        );
+        assert!(ContentKind::Markdown.validate(&[0xff, 0xfe]).is_err());
+        assert!(ContentKind::Package.validate(&[]).is_err());
+        assert!(ContentKind::Package.validate(b"<html>").is_ok());
+    }
+
+    #[test]
+    fn informative_markdown_is_collected() {
+        use konnektoren_core::challenges::{Informative, InformativeText};
+
+        let text = |language: &str, text: &str| InformativeText {
+            language: language.to_string(),
+            text: text.to_string(),
+        };
+        let challenge = Challenge {
+            challenge_type: ChallengeType::Informative(Informative {
+                id: "articles-info".to_string(),
+                name: "Artikel".to_string(),
+                description: String::new(),
+                text: vec![
+                    text("en", "info/articles.en.md"),
+                    text("de", "info/articles.de.md"),
+                    text("es", "info/articles.es.md"),
+                ],
+            }),
+            ..Default::default()
+        };
+        let urls: Vec<_> = challenge_content(&challenge, "de")
+            .into_iter()
+            .map(|content| content.url)
+            .collect();
+        assert_eq!(urls, vec!["info/articles.en.md", "info/articles.de.md"]);
+        assert_eq!(challenge_content(&challenge, "en").len(), 1);
+    }
+
+    #[test]
+    fn markdown_paths_are_recognized() {
+        assert!(is_markdown_path("articles.md"));
+        assert!(is_markdown_path(" /assets/info/Grammar.MD "));
+        assert!(!is_markdown_path("Read the text.md\nand answer."));
+        assert!(!is_markdown_path("Die Artikel im Deutschen"));
+    }
+}
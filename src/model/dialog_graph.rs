@@ -0,0 +1,377 @@
+use chrono::{DateTime, Utc};
+use konnektoren_core::challenges::{Challenge, ChallengeType, Dialog, DialogAnswer, DialogTurn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Whether the learner picks the line of `turn` from options. Unlike quiz
+/// turns, choices don't need a correct option.
+pub fn is_choice_turn(turn: &DialogTurn) -> bool {
+    turn.options
+        .as_ref()
+        .is_some_and(|options| !options.is_empty())
+}
+
+/// Option the learner picked as their line in turn `index`, if they did.
+pub fn picked_line(dialog: &Dialog, answers: &[DialogAnswer], index: usize) -> Option<String> {
+    let answer = answers.iter().find(|answer| answer.turn_index == index)?;
+    dialog
+        .turns
+        .get(index)?
+        .options
+        .as_ref()?
+        .get(answer.selected_option)
+        .cloned()
+}
+
+/// An ending of a branching dialog.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DialogEnding {
+    /// Turn the dialog ends with.
+    pub turn: usize,
+    pub title: String,
+    /// Score in percent for reaching this ending. Without one, the ending is
+    /// scored by the correct options picked on the way.
+    #[serde(default)]
+    pub score: Option<u32>,
+}
+
+/// Branches of a role-play dialog, where the line the learner picks decides
+/// the next turn.
+///
+/// Dialogs in `konnektoren-core` are a linear script, so the graph is kept
+/// beside the [`Dialog`] and refers to its turns by index. Challenge files
+/// give it as a `next` key on the turns and an `endings` key on the dialog,
+/// see [`Self::from_yaml`]. The graph must not loop; a turn is never
+/// visited twice.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DialogGraph {
+    /// Turn each option leads to, keyed by turn index and listed in option
+    /// order. Turns without options have a single entry, and turns without
+    /// an entry continue with the following turn.
+    #[serde(default)]
+    pub next_turns: BTreeMap<usize, Vec<usize>>,
+    /// Turns the dialog ends with, besides the last one.
+    #[serde(default)]
+    pub endings: Vec<DialogEnding>,
+}
+
+#[derive(Deserialize)]
+struct GraphTurn {
+    #[serde(default)]
+    next: Option<Vec<usize>>,
+}
+
+#[derive(Deserialize)]
+struct GraphDialog {
+    #[serde(default)]
+    turns: Vec<GraphTurn>,
+    #[serde(default)]
+    endings: Vec<DialogEnding>,
+}
+
+impl DialogGraph {
+    /// Reads the `next` keys of the turns and the `endings` of a dialog
+    /// challenge file, with or without the challenge type tag around the
+    /// dialog. `None` for linear dialogs.
+    pub fn from_yaml(source: &str) -> Result<Option<Self>, serde_yaml::Error> {
+        let value: serde_yaml::Value = serde_yaml::from_str(source)?;
+        let dialog = match value.get("turns") {
+            Some(_) => value,
+            None => value
+                .as_mapping()
+                .and_then(|mapping| mapping.values().find(|v| v.get("turns").is_some()))
+                .cloned()
+                .unwrap_or(value),
+        };
+        let dialog: GraphDialog = serde_yaml::from_value(dialog)?;
+        let graph = Self {
+            next_turns: dialog
+                .turns
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, turn)| Some((index, turn.next?)))
+                .collect(),
+            endings: dialog.endings,
+        };
+        Ok((!graph.next_turns.is_empty() || !graph.endings.is_empty()).then_some(graph))
+    }
+
+    pub fn ending(&self, turn: usize) -> Option<&DialogEnding> {
+        self.endings.iter().find(|ending| ending.turn == turn)
+    }
+
+    /// Turn that follows `turn` once `option` was picked, `None` when the
+    /// dialog ends with it. Without an option, the correct one or else the
+    /// first one is followed.
+    pub fn next_turn(&self, dialog: &Dialog, turn: usize, option: Option<usize>) -> Option<usize> {
+        if self.ending(turn).is_some() {
+            return None;
+        }
+        let next = match self.next_turns.get(&turn) {
+            Some(next) => {
+                let option = option
+                    .or_else(|| dialog.turns.get(turn).and_then(|t| t.correct_option))
+                    .unwrap_or(0);
+                next.get(option).or(next.first()).copied()
+            }
+            None => Some(turn + 1),
+        };
+        next.filter(|next| *next < dialog.turns.len())
+    }
+
+    /// Turns visited with `answers`, from the first one up to the ending or
+    /// the first choice that wasn't answered.
+    pub fn path(&self, dialog: &Dialog, answers: &[DialogAnswer]) -> Vec<usize> {
+        let mut path = Vec::new();
+        let mut turn = (!dialog.turns.is_empty()).then_some(0);
+        while let Some(current) = turn {
+            if path.contains(&current) {
+                break;
+            }
+            path.push(current);
+            let answer = answers.iter().find(|answer| answer.turn_index == current);
+            if is_choice_turn(&dialog.turns[current]) && answer.is_none() {
+                break;
+            }
+            turn = self.next_turn(dialog, current, answer.map(|a| a.selected_option));
+        }
+        path
+    }
+
+    /// The ending reached with `answers`, if it is one of [`Self::endings`].
+    pub fn reached_ending(
+        &self,
+        dialog: &Dialog,
+        answers: &[DialogAnswer],
+    ) -> Option<&DialogEnding> {
+        self.path(dialog, answers)
+            .last()
+            .and_then(|turn| self.ending(*turn))
+    }
+
+    /// Performance of a played dialog in percent: the score of the ending
+    /// reached, or else the share of choices on the path answered with their
+    /// correct option. Choices without a correct option accept any line.
+    pub fn performance(&self, dialog: &Dialog, answers: &[DialogAnswer]) -> u32 {
+        if let Some(score) = self
+            .reached_ending(dialog, answers)
+            .and_then(|ending| ending.score)
+        {
+            return score.min(100);
+        }
+        let choices: Vec<_> = self
+            .path(dialog, answers)
+            .into_iter()
+            .filter(|turn| is_choice_turn(&dialog.turns[*turn]))
+            .collect();
+        if choices.is_empty() {
+            return 100;
+        }
+        let correct = choices
+            .iter()
+            .filter(|turn| {
+                let answer = answers.iter().find(|answer| answer.turn_index == **turn);
+                match (dialog.turns[**turn].correct_option, answer) {
+                    (_, None) => false,
+                    (None, Some(_)) => true,
+                    (Some(correct), Some(answer)) => answer.selected_option == correct,
+                }
+            })
+            .count();
+        (correct * 100 / choices.len()) as u32
+    }
+}
+
+/// Score of a branching dialog attempt. Core scores the recorded answers
+/// without the graph, so the score is stored apart from the challenge
+/// history and matched to its entry by challenge id and start time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DialogOutcome {
+    pub challenge_id: String,
+    pub started_at: Option<DateTime<Utc>>,
+    /// Title of the ending reached, if it is one of the graph's endings.
+    #[serde(default)]
+    pub ending: Option<String>,
+    /// [`DialogGraph::performance`] of the answers.
+    pub score: u32,
+}
+
+impl DialogOutcome {
+    /// The outcome of `answers` to `challenge`, `None` if it isn't a dialog.
+    pub fn new(
+        challenge: &Challenge,
+        graph: &DialogGraph,
+        answers: &[DialogAnswer],
+    ) -> Option<Self> {
+        let ChallengeType::Dialog(dialog) = &challenge.challenge_type else {
+            return None;
+        };
+        Some(Self {
+            challenge_id: challenge.challenge_config.id.clone(),
+            started_at: challenge.start_time,
+            ending: graph
+                .reached_ending(dialog, answers)
+                .map(|ending| ending.title.clone()),
+            score: graph.performance(dialog, answers),
+        })
+    }
+
+    pub fn matches(&self, challenge: &Challenge) -> bool {
+        self.challenge_id == challenge.challenge_config.id
+            && self.started_at == challenge.start_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn(text: &str, options: Option<Vec<&str>>, correct_option: Option<usize>) -> DialogTurn {
+        DialogTurn {
+            speaker: "anna".to_string(),
+            text: text.to_string(),
+            audio: None,
+            options: options.map(|options| options.into_iter().map(str::to_string).collect()),
+            correct_option,
+        }
+    }
+
+    fn answer(turn_index: usize, selected_option: usize) -> DialogAnswer {
+        DialogAnswer {
+            turn_index,
+            selected_option,
+        }
+    }
+
+    /// 0 → 1 (choice) → 2 → 3 (polite ending) or → 4 (rude ending).
+    fn bakery() -> (Dialog, DialogGraph) {
+        let dialog = Dialog {
+            turns: vec![
+                turn("Guten Tag!", None, None),
+                turn(
+                    "Ein Brot, bitte.",
+                    Some(vec!["Ein Brot, bitte.", "Brot!"]),
+                    None,
+                ),
+                turn("Gerne, das macht 3 Euro.", None, None),
+                turn("Danke, schönen Tag!", None, None),
+                turn("Wie bitte?", None, None),
+            ],
+            ..Default::default()
+        };
+        let graph = DialogGraph {
+            next_turns: BTreeMap::from([(1, vec![2, 4])]),
+            endings: vec![
+                DialogEnding {
+                    turn: 3,
+                    title: "Polite".to_string(),
+                    score: None,
+                },
+                DialogEnding {
+                    turn: 4,
+                    title: "Rude".to_string(),
+                    score: Some(25),
+                },
+            ],
+        };
+        (dialog, graph)
+    }
+
+    #[test]
+    fn options_lead_to_their_turns() {
+        let (dialog, graph) = bakery();
+        assert_eq!(graph.next_turn(&dialog, 0, None), Some(1));
+        assert_eq!(graph.next_turn(&dialog, 1, Some(1)), Some(4));
+        assert_eq!(graph.next_turn(&dialog, 1, None), Some(2));
+        assert_eq!(graph.next_turn(&dialog, 3, None), None);
+        assert_eq!(graph.next_turn(&dialog, 4, None), None);
+    }
+
+    #[test]
+    fn path_follows_the_answers() {
+        let (dialog, graph) = bakery();
+        assert_eq!(graph.path(&dialog, &[answer(1, 0)]), vec![0, 1, 2, 3]);
+        assert_eq!(graph.path(&dialog, &[answer(1, 1)]), vec![0, 1, 4]);
+        assert_eq!(graph.path(&dialog, &[]), vec![0, 1]);
+        assert_eq!(
+            graph
+                .reached_ending(&dialog, &[answer(1, 1)])
+                .map(|e| e.title.as_str()),
+            Some("Rude")
+        );
+    }
+
+    #[test]
+    fn picked_lines_come_from_the_options() {
+        let (dialog, _) = bakery();
+        assert_eq!(
+            picked_line(&dialog, &[answer(1, 1)], 1).as_deref(),
+            Some("Brot!")
+        );
+        assert_eq!(picked_line(&dialog, &[answer(1, 1)], 0), None);
+        assert_eq!(picked_line(&dialog, &[], 1), None);
+    }
+
+    #[test]
+    fn endings_set_the_score() {
+        let (dialog, graph) = bakery();
+        assert_eq!(graph.performance(&dialog, &[answer(1, 0)]), 100);
+        assert_eq!(graph.performance(&dialog, &[answer(1, 1)]), 25);
+        assert_eq!(graph.performance(&dialog, &[]), 0);
+    }
+
+    #[test]
+    fn loops_stop_the_path() {
+        let (dialog, mut graph) = bakery();
+        graph.next_turns.insert(2, vec![0]);
+        assert_eq!(graph.path(&dialog, &[answer(1, 0)]), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn graph_is_read_from_the_challenge_file() {
+        let source = r#"
+dialog:
+  id: bakery
+  turns:
+    - speaker: anna
+      text: Guten Tag!
+    - speaker: ben
+      text: Ein Brot, bitte.
+      options: ["Ein Brot, bitte.", "Brot!"]
+      next: [2, 4]
+    - speaker: anna
+      text: Gerne, das macht 3 Euro.
+    - speaker: ben
+      text: Danke, schönen Tag!
+    - speaker: anna
+      text: Wie bitte?
+  endings:
+    - turn: 3
+      title: Polite
+    - turn: 4
+      title: Rude
+      score: 25
+"#;
+        let (_, expected) = bakery();
+        assert_eq!(DialogGraph::from_yaml(source).unwrap(), Some(expected));
+        assert_eq!(
+            DialogGraph::from_yaml("turns:\n  - text: Hallo").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn outcomes_keep_the_ending_score() {
+        let (dialog, graph) = bakery();
+        let mut challenge = Challenge::default();
+        challenge.challenge_config.id = "bakery".to_string();
+        challenge.challenge_type = ChallengeType::Dialog(dialog);
+        challenge.start_time = Some(Utc::now());
+
+        let outcome = DialogOutcome::new(&challenge, &graph, &[answer(1, 1)]).unwrap();
+        assert_eq!(outcome.ending.as_deref(), Some("Rude"));
+        assert_eq!(outcome.score, 25);
+        assert!(outcome.matches(&challenge));
+        assert!(DialogOutcome::new(&Challenge::default(), &graph, &[]).is_none());
+    }
+}
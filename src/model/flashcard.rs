@@ -0,0 +1,158 @@
+use super::ReviewGrade;
+use konnektoren_core::challenges::{
+    Challenge, ChallengeResult, ChallengeType, MultipleChoiceOption, Performance, Vocabulary,
+};
+
+impl ReviewGrade {
+    /// Share of a flashcard's score earned with this grade, in percent.
+    pub fn score(&self) -> u32 {
+        match self {
+            Self::Again => 0,
+            Self::Hard => 50,
+            Self::Good | Self::Easy => 100,
+        }
+    }
+
+    /// Whether the card counts as known.
+    pub fn is_known(&self) -> bool {
+        *self != Self::Again
+    }
+}
+
+/// Result of a flashcard session over a vocabulary list.
+///
+/// There is no result type for vocabulary, so the grades are stored as a
+/// multiple choice result with one entry per word, in list order, whose id
+/// is the position of the grade in [`ReviewGrade::ALL`]. Words that were
+/// never graded count as `Again`.
+pub fn flashcard_result(grades: &[Option<ReviewGrade>]) -> ChallengeResult {
+    ChallengeResult::MultipleChoice(
+        grades
+            .iter()
+            .map(|grade| {
+                let grade = grade.unwrap_or(ReviewGrade::Again);
+                MultipleChoiceOption {
+                    id: ReviewGrade::ALL
+                        .iter()
+                        .position(|g| *g == grade)
+                        .unwrap_or(0),
+                    name: grade.name().to_string(),
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Grades of a flashcard result over `vocabulary`, empty for any other
+/// result.
+///
+/// Only a result in the shape of [`flashcard_result`] counts: one entry per
+/// word, each with the id and name of a grade.
+pub fn flashcard_grades(vocabulary: &Vocabulary, result: &ChallengeResult) -> Vec<ReviewGrade> {
+    let ChallengeResult::MultipleChoice(options) = result else {
+        return vec![];
+    };
+    if options.len() != vocabulary.items.len() {
+        return vec![];
+    }
+    options
+        .iter()
+        .map(|option| {
+            ReviewGrade::ALL
+                .get(option.id)
+                .filter(|grade| grade.name() == option.name)
+                .copied()
+        })
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default()
+}
+
+/// Performance of a challenge result in percent.
+///
+/// Same as [`Performance::performance`], except that vocabulary lists
+/// finished with flashcards are scored by their grades.
+pub fn challenge_performance(challenge: &Challenge, result: &ChallengeResult) -> u32 {
+    match (&challenge.challenge_type, result) {
+        (ChallengeType::Vocabulary(vocabulary), ChallengeResult::MultipleChoice(_)) => {
+            let grades = flashcard_grades(vocabulary, result);
+            if grades.is_empty() {
+                return 0;
+            }
+            grades.iter().map(ReviewGrade::score).sum::<u32>() / grades.len() as u32
+        }
+        _ => challenge.performance(result),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konnektoren_core::challenges::VocabularyItem;
+
+    fn words(count: usize) -> Vocabulary {
+        Vocabulary {
+            items: (0..count)
+                .map(|id| VocabularyItem {
+                    id,
+                    text: format!("word {}", id),
+                    translation: None,
+                    icon: None,
+                    phonetic: None,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn grades_round_trip_through_result() {
+        let grades = [
+            Some(ReviewGrade::Good),
+            None,
+            Some(ReviewGrade::Easy),
+            Some(ReviewGrade::Hard),
+        ];
+        let result = flashcard_result(&grades);
+        assert_eq!(
+            flashcard_grades(&words(4), &result),
+            vec![
+                ReviewGrade::Good,
+                ReviewGrade::Again,
+                ReviewGrade::Easy,
+                ReviewGrade::Hard
+            ]
+        );
+        assert!(flashcard_grades(&words(4), &ChallengeResult::Informative).is_empty());
+    }
+
+    #[test]
+    fn other_multiple_choice_results_are_not_grades() {
+        let result = flashcard_result(&[Some(ReviewGrade::Good), Some(ReviewGrade::Hard)]);
+        assert!(flashcard_grades(&words(3), &result).is_empty());
+
+        let stray = ChallengeResult::MultipleChoice(vec![MultipleChoiceOption {
+            id: 1,
+            name: "die".to_string(),
+        }]);
+        assert!(flashcard_grades(&words(1), &stray).is_empty());
+    }
+
+    #[test]
+    fn vocabulary_is_scored_by_grades() {
+        let challenge = Challenge {
+            challenge_type: ChallengeType::Vocabulary(words(4)),
+            ..Default::default()
+        };
+        let result = flashcard_result(&[
+            Some(ReviewGrade::Good),
+            Some(ReviewGrade::Again),
+            Some(ReviewGrade::Hard),
+            Some(ReviewGrade::Easy),
+        ]);
+        assert_eq!(challenge_performance(&challenge, &result), 62);
+        assert_eq!(
+            challenge_performance(&challenge, &ChallengeResult::MultipleChoice(vec![])),
+            0
+        );
+    }
+}
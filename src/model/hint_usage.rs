@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use konnektoren_core::challenges::{Challenge, ChallengeType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Number of questions hints are counted against.
+pub fn question_count(challenge: &Challenge) -> usize {
+    match &challenge.challenge_type {
+        ChallengeType::MultipleChoice(mc) => mc.questions.len(),
+        ChallengeType::GapFill(gap_fill) => gap_fill.questions.len(),
+        ChallengeType::Ordering(ordering) => ordering.items.len(),
+        ChallengeType::ContextualChoice(cc) => cc.items.len(),
+        _ => 0,
+    }
+}
+
+/// Hints taken per question of a challenge attempt.
+///
+/// Every hint costs a quarter of the question's share of the score, up to
+/// the whole share.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct HintUsage {
+    /// Number of hints taken, by question index.
+    pub questions: BTreeMap<usize, usize>,
+}
+
+impl HintUsage {
+    /// Share of a question's score lost per hint, in percent.
+    pub const PENALTY_PER_HINT: usize = 25;
+
+    /// Number of hints taken for question `index`.
+    pub fn level(&self, index: usize) -> usize {
+        self.questions.get(&index).copied().unwrap_or(0)
+    }
+
+    /// Takes the next hint for question `index` and returns the new level.
+    pub fn take(&mut self, index: usize) -> usize {
+        let level = self.questions.entry(index).or_insert(0);
+        *level += 1;
+        *level
+    }
+
+    /// Number of hints taken over all questions.
+    pub fn total(&self) -> usize {
+        self.questions.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+
+    /// Percentage points lost to hints in a challenge of `total_questions`.
+    pub fn penalty(&self, total_questions: usize) -> u32 {
+        if total_questions == 0 {
+            return 0;
+        }
+        let lost: usize = self
+            .questions
+            .values()
+            .map(|&hints| (hints * Self::PENALTY_PER_HINT).min(100))
+            .sum();
+        (lost / total_questions) as u32
+    }
+
+    /// `performance` reduced by the hint penalty.
+    pub fn apply_penalty(&self, performance: u32, total_questions: usize) -> u32 {
+        performance.saturating_sub(self.penalty(total_questions))
+    }
+}
+
+/// Hints taken in a finished challenge. Stored apart from the challenge
+/// history, which has no room for them, and matched to its entry by
+/// challenge id and start time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChallengeHints {
+    pub challenge_id: String,
+    pub started_at: Option<DateTime<Utc>>,
+    pub hints: HintUsage,
+}
+
+impl ChallengeHints {
+    pub fn new(challenge: &Challenge, hints: HintUsage) -> Self {
+        Self {
+            challenge_id: challenge.challenge_config.id.clone(),
+            started_at: challenge.start_time,
+            hints,
+        }
+    }
+
+    pub fn matches(&self, challenge: &Challenge) -> bool {
+        self.challenge_id == challenge.challenge_config.id
+            && self.started_at == challenge.start_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_counts_per_question() {
+        let mut hints = HintUsage::default();
+        assert_eq!(hints.take(2), 1);
+        assert_eq!(hints.take(2), 2);
+        hints.take(0);
+        assert_eq!(hints.level(2), 2);
+        assert_eq!(hints.level(1), 0);
+        assert_eq!(hints.total(), 3);
+        assert!(!hints.is_empty());
+    }
+
+    #[test]
+    fn penalty_is_capped_per_question() {
+        let mut hints = HintUsage::default();
+        hints.take(0);
+        assert_eq!(hints.penalty(5), 5);
+        for _ in 0..10 {
+            hints.take(1);
+        }
+        assert_eq!(hints.penalty(5), 25);
+        assert_eq!(hints.apply_penalty(100, 5), 75);
+        assert_eq!(hints.apply_penalty(10, 5), 0);
+        assert_eq!(HintUsage::default().penalty(0), 0);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut hints = HintUsage::default();
+        hints.take(3);
+        let json = serde_json::to_string(&hints).unwrap();
+        assert_eq!(serde_json::from_str::<HintUsage>(&json).unwrap(), hints);
+    }
+}
@@ -5,22 +5,66 @@
 //! designed to be serialized and deserialized for persistence and communication.
 //!
 //! Key models include:
+//! - [`Accessibility`]: Text size, font, contrast and motion preferences.
+//! - [`AttemptRecords`]: Hints and dialog outcomes of finished attempts, to score the history with.
+//! - [`CachedContent`]: Downloaded challenge assets kept for offline play.
+//! - [`ChallengeCheckpoint`]: Saved progress of an unfinished challenge.
 //! - [`Design`]: Defines the visual design modes (e.g., desktop, mobile).
+//! - [`DialogGraph`]: Branches and endings of a role-play dialog, and the [`DialogOutcome`] reached.
+//! - [`flashcard_result`]: Self-graded flashcards of a vocabulary list as a challenge result.
+//! - [`HintUsage`]: Hints taken during a challenge attempt, and [`ChallengeHints`] recorded when it is finished.
 //! - [`Inbox`]: Represents the user's message inbox.
+//! - [`Playlist`]: Background music by route and game path.
+//! - [`ReviewSchedule`]: Spaced-repetition schedule of questions to review.
+//! - [`SandboxMessage`]: Messages from custom challenges running in a sandboxed frame.
 //! - [`SessionInitializer`]: A trait for initializing user sessions.
 //! - [`Settings`]: Stores user-configurable application settings.
+//! - [`SoundSet`]: Audio files of the feedback sounds, and [`QuietHours`] without them.
 //! - [`Theme`]: Defines the UI themes (e.g., light, dark).
+//! - [`TimeLimit`]: Countdown for timed challenges, and [`ChallengeTimeout`] for challenges that ran out of time.
+//! - [`VocabularyAudio`]: Recorded pronunciations of vocabulary items.
 //!
 //! These models are central to the application's data flow and state management.
 
+mod accessibility;
+mod attempt_records;
+mod challenge_checkpoint;
+mod content_cache;
 mod design;
+mod dialog_graph;
+mod flashcard;
+mod hint_usage;
 mod inbox;
+mod playlist;
+mod review;
+mod sandbox_message;
 mod session_initializer;
 mod settings;
+mod sound;
 mod theme;
+mod time_limit;
+mod vocabulary_audio;
 
+pub use accessibility::{Accessibility, MotionPreference, TEXT_SCALES};
+pub use attempt_records::AttemptRecords;
+pub use challenge_checkpoint::ChallengeCheckpoint;
+pub use content_cache::{
+    CacheStatus, CachedContent, ChallengeContent, ContentCacheEntry, ContentCacheIndex,
+    ContentKind, challenge_content, is_markdown_path, upcoming_content,
+};
 pub use design::{Design, DesignMode};
+pub use dialog_graph::{DialogEnding, DialogGraph, DialogOutcome, is_choice_turn, picked_line};
+pub use flashcard::{challenge_performance, flashcard_grades, flashcard_result};
+pub use hint_usage::{ChallengeHints, HintUsage, question_count};
 pub use inbox::Inbox;
+pub use playlist::{DEFAULT_MUSIC_URL, Playlist, next_track};
+pub use review::{ReviewGrade, ReviewItem, ReviewSchedule, current_day, recall_items};
+pub use sandbox_message::{
+    MAX_SANDBOX_MESSAGE_BYTES, SandboxInit, SandboxMessage, SandboxMessageError,
+};
 pub use session_initializer::{DefaultSessionInitializer, SessionInitializer};
-pub use settings::Settings;
+pub use settings::{SLOW_SPEECH_FACTOR, Settings, primary_language};
+pub use sound::{QuietHours, SoundDebouncer, SoundEffect, SoundSet};
 pub use theme::Theme;
+pub use time_limit::{ChallengeTimeout, TimeLimit, TimeLimitScope, without_timeouts};
+pub use vocabulary_audio::{VocabularyAudio, local_asset_path};
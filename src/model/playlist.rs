@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Track shipped in `assets/music`, so there is music offline and without
+/// extra downloads at build time.
+pub const DEFAULT_MUSIC_URL: &str = "/assets/music/fanfare.wav";
+
+/// Background music, chosen by game path, then by route, then the default
+/// `tracks`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Playlist {
+    #[serde(default = "default_tracks")]
+    pub tracks: Vec<String>,
+    /// Tracks by route path. The longest route that is the path or one of
+    /// its parents wins, so `/map` also plays on `/map/grammar`. An empty
+    /// list plays the default tracks.
+    #[serde(default)]
+    pub routes: BTreeMap<String, Vec<String>>,
+    /// Tracks by game path id.
+    #[serde(default)]
+    pub game_paths: BTreeMap<String, Vec<String>>,
+}
+
+fn default_tracks() -> Vec<String> {
+    vec![DEFAULT_MUSIC_URL.to_string()]
+}
+
+impl Default for Playlist {
+    fn default() -> Self {
+        Self {
+            tracks: default_tracks(),
+            routes: BTreeMap::new(),
+            game_paths: BTreeMap::new(),
+        }
+    }
+}
+
+impl Playlist {
+    pub fn tracks_for(&self, route: Option<&str>, game_path: Option<&str>) -> &[String] {
+        let game_path_tracks = game_path.and_then(|id| self.game_paths.get(id));
+        let route_tracks = route.and_then(|route| {
+            self.routes
+                .iter()
+                .filter(|(prefix, _)| route_matches(prefix, route))
+                .max_by_key(|(prefix, _)| prefix.len())
+                .map(|(_, tracks)| tracks)
+        });
+        game_path_tracks
+            .or(route_tracks)
+            .filter(|tracks| !tracks.is_empty())
+            .unwrap_or(&self.tracks)
+    }
+}
+
+fn route_matches(prefix: &str, route: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    route == prefix
+        || route
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Track to play after track `index` of `len`, `None` at the end of a
+/// playlist that doesn't repeat.
+pub fn next_track(index: usize, len: usize, repeat: bool) -> Option<usize> {
+    match index + 1 {
+        next if next < len => Some(next),
+        _ if repeat && len > 0 => Some(0),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn playlist() -> Playlist {
+        let tracks = |url: &str| vec![url.to_string()];
+        Playlist {
+            tracks: tracks("default.ogg"),
+            routes: [
+                ("/".to_string(), tracks("home.ogg")),
+                ("/map".to_string(), tracks("map.ogg")),
+                ("/settings".to_string(), vec![]),
+            ]
+            .into(),
+            game_paths: [("grammar".to_string(), tracks("grammar.ogg"))].into(),
+        }
+    }
+
+    #[test]
+    fn game_path_tracks_come_first() {
+        let playlist = playlist();
+        assert_eq!(
+            playlist.tracks_for(Some("/map"), Some("grammar")),
+            ["grammar.ogg"]
+        );
+        assert_eq!(
+            playlist.tracks_for(Some("/map"), Some("verbs")),
+            ["map.ogg"]
+        );
+    }
+
+    #[test]
+    fn longest_route_wins() {
+        let playlist = playlist();
+        assert_eq!(playlist.tracks_for(Some("/map/grammar"), None), ["map.ogg"]);
+        assert_eq!(playlist.tracks_for(Some("/mapping"), None), ["home.ogg"]);
+        assert_eq!(playlist.tracks_for(Some("/"), None), ["home.ogg"]);
+        assert_eq!(
+            playlist.tracks_for(Some("/settings"), None),
+            ["default.ogg"]
+        );
+        assert_eq!(playlist.tracks_for(None, None), ["default.ogg"]);
+    }
+
+    #[test]
+    fn default_playlist_is_bundled() {
+        assert_eq!(Playlist::default().tracks, vec![DEFAULT_MUSIC_URL]);
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(DEFAULT_MUSIC_URL.trim_start_matches('/'));
+        assert!(path.is_file(), "{} is missing", path.display());
+        let playlist: Playlist = serde_json::from_str("{}").unwrap();
+        assert_eq!(playlist, Playlist::default());
+    }
+
+    #[test]
+    fn tracks_follow_each_other() {
+        assert_eq!(next_track(0, 3, false), Some(1));
+        assert_eq!(next_track(2, 3, false), None);
+        assert_eq!(next_track(2, 3, true), Some(0));
+        assert_eq!(next_track(0, 0, true), None);
+    }
+}
@@ -0,0 +1,539 @@
+use super::flashcard_grades;
+use konnektoren_core::challenges::{Challenge, ChallengeHistory, ChallengeResult, ChallengeType};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::LazyLock;
+
+const MIN_EASE: f64 = 1.3;
+const DEFAULT_EASE: f64 = 2.5;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// `{}` and `{0}` placeholders of contextual choice templates.
+static PLACEHOLDER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\{\d*\}").unwrap());
+
+/// Days since the Unix epoch, the unit review items are scheduled in.
+pub fn current_day() -> i64 {
+    chrono::Utc::now().timestamp().div_euclid(SECONDS_PER_DAY)
+}
+
+/// How well a review item was remembered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewGrade {
+    Again,
+    Hard,
+    Good,
+    Easy,
+}
+
+impl ReviewGrade {
+    pub const ALL: [ReviewGrade; 4] = [Self::Again, Self::Hard, Self::Good, Self::Easy];
+
+    /// Response quality on the SM-2 scale from 0 to 5.
+    pub fn quality(&self) -> u8 {
+        match self {
+            Self::Again => 1,
+            Self::Hard => 3,
+            Self::Good => 4,
+            Self::Easy => 5,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Again => "Again",
+            Self::Hard => "Hard",
+            Self::Good => "Good",
+            Self::Easy => "Easy",
+        }
+    }
+}
+
+/// A single question to recall, scheduled with the SM-2 algorithm.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewItem {
+    /// `<challenge id>:<question index>`
+    pub id: String,
+    pub challenge_id: String,
+    pub question_index: usize,
+    pub prompt: String,
+    pub answer: String,
+    pub ease: f64,
+    /// Days until the next review.
+    pub interval: u32,
+    /// Successful reviews in a row.
+    pub repetitions: u32,
+    /// Day the item is due, see [`current_day`].
+    pub due: i64,
+}
+
+impl ReviewItem {
+    pub fn new(
+        challenge_id: &str,
+        question_index: usize,
+        prompt: String,
+        answer: String,
+        today: i64,
+    ) -> Self {
+        Self {
+            id: format!("{}:{}", challenge_id, question_index),
+            challenge_id: challenge_id.to_string(),
+            question_index,
+            prompt,
+            answer,
+            ease: DEFAULT_EASE,
+            interval: 0,
+            repetitions: 0,
+            due: today,
+        }
+    }
+
+    pub fn is_due(&self, today: i64) -> bool {
+        self.due <= today
+    }
+
+    /// Updates ease, interval and due day after a review.
+    pub fn review(&mut self, grade: ReviewGrade, today: i64) {
+        let quality = f64::from(grade.quality());
+
+        if grade == ReviewGrade::Again {
+            self.repetitions = 0;
+            self.interval = 1;
+        } else {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (f64::from(self.interval) * self.ease).round() as u32,
+            };
+            self.repetitions += 1;
+        }
+
+        self.ease =
+            (self.ease + 0.1 - (5.0 - quality) * (0.08 + (5.0 - quality) * 0.02)).max(MIN_EASE);
+        self.due = today + i64::from(self.interval);
+    }
+
+    /// Makes the item due again after it was answered wrong in a challenge.
+    fn lapse(&mut self, today: i64) {
+        self.repetitions = 0;
+        self.interval = 0;
+        self.ease = (self.ease - 0.2).max(MIN_EASE);
+        self.due = today;
+    }
+}
+
+/// Key of a finished challenge in the history: its id and when it ended
+/// (or started), so the same challenge played twice counts twice.
+pub fn history_key(challenge: &Challenge) -> String {
+    let time = challenge.end_time.or(challenge.start_time);
+    match time {
+        Some(time) => format!(
+            "{}@{}",
+            challenge.challenge_config.id,
+            time.timestamp_millis()
+        ),
+        None => challenge.challenge_config.id.clone(),
+    }
+}
+
+/// All review items of a learner together with which challenges of the
+/// history have been turned into items.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ReviewSchedule {
+    pub items: Vec<ReviewItem>,
+    /// [`history_key`]s of the challenges already scanned for mistakes.
+    #[serde(default)]
+    pub synced: BTreeSet<String>,
+}
+
+impl ReviewSchedule {
+    /// Adds recall items for the challenges of `history` that weren't synced
+    /// yet, wherever they are in the history. Keys of challenges that left
+    /// a capped history are dropped. Returns whether the schedule changed.
+    pub fn sync_history(&mut self, history: &ChallengeHistory, today: i64) -> bool {
+        let mut changed = false;
+        let keys: BTreeSet<String> = history.challenges.iter().map(history_key).collect();
+        for challenge in &history.challenges {
+            if self.synced.insert(history_key(challenge)) {
+                self.add_challenge(challenge, today);
+                changed = true;
+            }
+        }
+
+        // An empty history is more likely not loaded yet than cleared
+        if history.challenges.is_empty() {
+            return changed;
+        }
+        let synced = self.synced.len();
+        self.synced.retain(|key| keys.contains(key));
+        changed || self.synced.len() != synced
+    }
+
+    /// Adds the recall items of a finished challenge. Questions that are
+    /// already scheduled become due again.
+    pub fn add_challenge(&mut self, challenge: &Challenge, today: i64) {
+        for item in recall_items(challenge, today) {
+            match self.items.iter_mut().find(|i| i.id == item.id) {
+                Some(existing) => existing.lapse(today),
+                None => self.items.push(item),
+            }
+        }
+    }
+
+    pub fn due_count(&self, today: i64) -> usize {
+        self.items.iter().filter(|item| item.is_due(today)).count()
+    }
+
+    /// Due items, most overdue first, interleaved so that consecutive items
+    /// come from different challenges where possible.
+    pub fn due_items(&self, today: i64) -> Vec<ReviewItem> {
+        let mut by_challenge: BTreeMap<&str, VecDeque<&ReviewItem>> = BTreeMap::new();
+        let mut due: Vec<&ReviewItem> = self.items.iter().filter(|i| i.is_due(today)).collect();
+        due.sort_by_key(|item| item.due);
+        for item in due {
+            by_challenge
+                .entry(item.challenge_id.as_str())
+                .or_default()
+                .push_back(item);
+        }
+
+        let mut queues: Vec<VecDeque<&ReviewItem>> = by_challenge.into_values().collect();
+        queues.sort_by_key(|queue| queue.front().map(|item| item.due));
+
+        let mut mixed = vec![];
+        while queues.iter().any(|queue| !queue.is_empty()) {
+            for queue in queues.iter_mut() {
+                if let Some(item) = queue.pop_front() {
+                    mixed.push(item.clone());
+                }
+            }
+        }
+        mixed
+    }
+
+    pub fn review(&mut self, id: &str, grade: ReviewGrade, today: i64) {
+        if let Some(item) = self.items.iter_mut().find(|item| item.id == id) {
+            item.review(grade, today);
+        }
+    }
+}
+
+/// Derives recall items from a finished challenge.
+///
+/// Multiple choice, gap fill and contextual choice challenges contribute the
+/// questions answered incorrectly. Vocabulary lists studied with flashcards
+/// contribute the words graded `Again` or `Hard`; lists that were only read
+/// have no grades and contribute nothing.
+pub fn recall_items(challenge: &Challenge, today: i64) -> Vec<ReviewItem> {
+    let id = challenge.challenge_config.id.as_str();
+    let result = &challenge.challenge_result;
+
+    match (&challenge.challenge_type, result) {
+        (ChallengeType::MultipleChoice(mc), ChallengeResult::MultipleChoice(answers)) => mc
+            .questions
+            .iter()
+            .zip(answers)
+            .enumerate()
+            .filter(|(_, (question, answer))| question.option != answer.id)
+            .map(|(index, (question, _))| {
+                let answer = mc
+                    .options
+                    .iter()
+                    .find(|option| option.id == question.option)
+                    .map(|option| option.name.clone())
+                    .unwrap_or_default();
+                ReviewItem::new(id, index, question.question.clone(), answer, today)
+            })
+            .collect(),
+        (ChallengeType::GapFill(gap_fill), ChallengeResult::GapFill(answers)) => answers
+            .iter()
+            .filter(|answer| !gap_fill.check_answer(answer))
+            .filter_map(|answer| {
+                let question = gap_fill.questions.get(answer.question_index)?;
+                let solution = question
+                    .gaps
+                    .iter()
+                    .map(|gap| gap.correct.clone())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Some(ReviewItem::new(
+                    id,
+                    answer.question_index,
+                    question.sentence.replace("__", "___"),
+                    solution,
+                    today,
+                ))
+            })
+            .collect(),
+        (ChallengeType::ContextualChoice(cc), ChallengeResult::ContextualChoice(answers)) => cc
+            .items
+            .iter()
+            .zip(answers)
+            .enumerate()
+            .filter(|(_, (item, answer))| {
+                !item
+                    .choices
+                    .iter()
+                    .zip(&answer.ids)
+                    .all(|(choice, &id)| choice.options.get(id) == Some(&choice.correct_answer))
+            })
+            .map(|(index, (item, _))| {
+                let solution = item
+                    .choices
+                    .iter()
+                    .map(|choice| choice.correct_answer.clone())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let prompt = PLACEHOLDER.replace_all(&item.template, "___").to_string();
+                ReviewItem::new(id, index, prompt, solution, today)
+            })
+            .collect(),
+        (ChallengeType::Vocabulary(vocabulary), _) => {
+            let grades = flashcard_grades(vocabulary, result);
+            vocabulary
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| {
+                    grades.get(*index).is_some_and(|grade| {
+                        matches!(grade, ReviewGrade::Again | ReviewGrade::Hard)
+                    })
+                })
+                .map(|(index, item)| {
+                    ReviewItem::new(
+                        id,
+                        index,
+                        item.text.clone(),
+                        item.translation.clone().unwrap_or_default(),
+                        today,
+                    )
+                })
+                .collect()
+        }
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konnektoren_core::challenges::{
+        ChallengeConfig, MultipleChoice, MultipleChoiceOption, Question,
+    };
+
+    fn item() -> ReviewItem {
+        ReviewItem::new("articles", 0, "Haus".to_string(), "das".to_string(), 100)
+    }
+
+    fn articles_challenge(answers: &[usize]) -> Challenge {
+        let options = ["der", "die", "das"]
+            .iter()
+            .enumerate()
+            .map(|(id, name)| MultipleChoiceOption {
+                id,
+                name: name.to_string(),
+            })
+            .collect::<Vec<_>>();
+        let questions = ["Haus", "Tisch", "Lampe"]
+            .iter()
+            .zip([2, 0, 1])
+            .map(|(question, option)| Question {
+                question: question.to_string(),
+                help: String::new(),
+                image: None,
+                option,
+            })
+            .collect();
+        let result = answers.iter().map(|&id| options[id].clone()).collect();
+
+        Challenge {
+            challenge_type: ChallengeType::MultipleChoice(MultipleChoice {
+                id: "articles".to_string(),
+                name: "Articles".to_string(),
+                lang: "de".to_string(),
+                options,
+                questions,
+            }),
+            challenge_config: ChallengeConfig {
+                id: "articles".to_string(),
+                ..Default::default()
+            },
+            challenge_result: ChallengeResult::MultipleChoice(result),
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    #[test]
+    fn intervals_grow_with_good_reviews() {
+        let mut item = item();
+        item.review(ReviewGrade::Good, 100);
+        assert_eq!((item.interval, item.due), (1, 101));
+        item.review(ReviewGrade::Good, 101);
+        assert_eq!((item.interval, item.due), (6, 107));
+        item.review(ReviewGrade::Good, 107);
+        assert_eq!((item.interval, item.due), (15, 122));
+        assert_eq!(item.repetitions, 3);
+    }
+
+    #[test]
+    fn again_resets_the_item() {
+        let mut item = item();
+        item.review(ReviewGrade::Easy, 100);
+        item.review(ReviewGrade::Easy, 101);
+        item.review(ReviewGrade::Again, 107);
+        assert_eq!((item.repetitions, item.interval, item.due), (0, 1, 108));
+        assert!(item.ease >= MIN_EASE);
+    }
+
+    #[test]
+    fn ease_never_drops_below_minimum() {
+        let mut item = item();
+        for day in 0..10 {
+            item.review(ReviewGrade::Again, day);
+        }
+        assert_eq!(item.ease, MIN_EASE);
+    }
+
+    #[test]
+    fn recall_items_from_incorrect_answers() {
+        let items = recall_items(&articles_challenge(&[2, 1, 0]), 5);
+        let ids: Vec<_> = items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, ["articles:1", "articles:2"]);
+        assert_eq!(items[0].prompt, "Tisch");
+        assert_eq!(items[0].answer, "der");
+        assert!(items.iter().all(|item| item.is_due(5)));
+    }
+
+    fn played_at(mut challenge: Challenge, minute: u32) -> Challenge {
+        use chrono::TimeZone;
+        challenge.end_time = chrono::Utc
+            .with_ymd_and_hms(2026, 10, 19, 8, minute, 0)
+            .single();
+        challenge
+    }
+
+    #[test]
+    fn sync_history_only_scans_new_challenges() {
+        let mut history = ChallengeHistory::new();
+        history
+            .challenges
+            .push(played_at(articles_challenge(&[2, 1, 1]), 0));
+
+        let mut schedule = ReviewSchedule::default();
+        assert!(schedule.sync_history(&history, 5));
+        assert_eq!(schedule.items.len(), 1);
+
+        schedule.review("articles:1", ReviewGrade::Good, 5);
+        assert!(!schedule.sync_history(&history, 6));
+        assert_eq!(schedule.due_count(5), 0);
+
+        history
+            .challenges
+            .push(played_at(articles_challenge(&[2, 1, 0]), 1));
+        assert!(schedule.sync_history(&history, 6));
+        assert_eq!(schedule.items.len(), 2);
+        assert_eq!(schedule.due_count(6), 2);
+    }
+
+    #[test]
+    fn sync_history_survives_capping_and_reordering() {
+        let first = played_at(articles_challenge(&[2, 1, 1]), 0);
+        let second = played_at(articles_challenge(&[2, 0, 0]), 1);
+        let mut history = ChallengeHistory::new();
+        history.challenges = vec![first.clone(), second.clone()];
+
+        let mut schedule = ReviewSchedule::default();
+        schedule.sync_history(&history, 5);
+        schedule.review("articles:1", ReviewGrade::Good, 5);
+        schedule.review("articles:2", ReviewGrade::Good, 5);
+
+        // Newest first, then capped to one entry: nothing new to add
+        history.challenges = vec![second.clone(), first];
+        assert!(!schedule.sync_history(&history, 5));
+        history.challenges = vec![second];
+        assert!(schedule.sync_history(&history, 5));
+        assert_eq!(schedule.synced.len(), 1);
+        assert_eq!(schedule.due_count(5), 0);
+
+        // A new challenge is found even though the history length didn't change
+        history.challenges = vec![played_at(articles_challenge(&[0, 0, 1]), 2)];
+        assert!(schedule.sync_history(&history, 5));
+        assert_eq!(schedule.due_count(5), 1);
+    }
+
+    #[test]
+    fn due_items_are_mixed_across_challenges() {
+        let mut schedule = ReviewSchedule::default();
+        for (challenge, index) in [("a", 0), ("a", 1), ("b", 0), ("b", 1)] {
+            let item = ReviewItem::new(challenge, index, String::new(), String::new(), 1);
+            schedule.items.push(item);
+        }
+        schedule
+            .items
+            .push(ReviewItem::new("c", 0, String::new(), String::new(), 9));
+
+        let ids: Vec<_> = schedule
+            .due_items(1)
+            .into_iter()
+            .map(|item| item.id)
+            .collect();
+        assert_eq!(ids, ["a:0", "b:0", "a:1", "b:1"]);
+    }
+
+    #[test]
+    fn recall_items_from_flashcard_grades() {
+        use crate::model::flashcard_result;
+        use konnektoren_core::challenges::{Vocabulary, VocabularyItem};
+
+        let words = ["Haus", "Baum", "Hund"]
+            .iter()
+            .enumerate()
+            .map(|(id, word)| VocabularyItem {
+                id,
+                text: word.to_string(),
+                translation: None,
+                icon: None,
+                phonetic: None,
+            });
+        let mut challenge = Challenge {
+            challenge_type: ChallengeType::Vocabulary(Vocabulary {
+                items: words.collect(),
+                ..Default::default()
+            }),
+            challenge_result: ChallengeResult::Informative,
+            ..Default::default()
+        };
+        assert!(recall_items(&challenge, 1).is_empty());
+
+        // Reading the list again doesn't lapse words already scheduled
+        let mut schedule = ReviewSchedule::default();
+        schedule
+            .items
+            .push(ReviewItem::new("", 0, "Haus".to_string(), String::new(), 1));
+        schedule.items[0].review(ReviewGrade::Good, 1);
+        let due = schedule.items[0].due;
+        schedule.add_challenge(&challenge, 2);
+        assert_eq!(schedule.items[0].due, due);
+
+        challenge.challenge_result = flashcard_result(&[
+            Some(ReviewGrade::Good),
+            Some(ReviewGrade::Hard),
+            Some(ReviewGrade::Again),
+        ]);
+        let prompts: Vec<_> = recall_items(&challenge, 1)
+            .into_iter()
+            .map(|item| item.prompt)
+            .collect();
+        assert_eq!(prompts, ["Baum", "Hund"]);
+    }
+
+    #[test]
+    fn ser_de_schedule() {
+        let mut schedule = ReviewSchedule::default();
+        schedule.add_challenge(&articles_challenge(&[0, 0, 0]), 3);
+        let json = serde_json::to_string(&schedule).unwrap();
+        let schedule2: ReviewSchedule = serde_json::from_str(&json).unwrap();
+        assert_eq!(schedule, schedule2);
+    }
+}
@@ -0,0 +1,168 @@
+use konnektoren_core::challenges::{ChallengeResult, Custom, CustomChallengeResult};
+use konnektoren_core::commands::{ChallengeCommand, Command};
+use konnektoren_core::events::{ChallengeEvent, Event};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Largest message accepted from a sandboxed challenge.
+pub const MAX_SANDBOX_MESSAGE_BYTES: usize = 64 * 1024;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SandboxMessageError {
+    #[error("Message of {0} bytes exceeds the limit of {MAX_SANDBOX_MESSAGE_BYTES} bytes")]
+    TooLarge(usize),
+
+    #[error("Invalid message: {0}")]
+    Invalid(String),
+}
+
+/// Message a custom challenge running in a sandboxed frame sends to the app.
+///
+/// Anything not matching this schema, including unknown fields, is
+/// rejected, so a challenge can only report answers, move between its tasks
+/// and finish.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SandboxMessage {
+    /// The question at `index` was answered correctly.
+    SolvedCorrect {
+        index: usize,
+    },
+    /// The question at `index` was answered incorrectly.
+    SolvedIncorrect {
+        index: usize,
+    },
+    NextTask,
+    PreviousTask,
+    Finish {
+        #[serde(default)]
+        result: Option<CustomChallengeResult>,
+    },
+}
+
+impl SandboxMessage {
+    pub fn parse(json: &str) -> Result<Self, SandboxMessageError> {
+        if json.len() > MAX_SANDBOX_MESSAGE_BYTES {
+            return Err(SandboxMessageError::TooLarge(json.len()));
+        }
+        let invalid = |err: serde_json::Error| SandboxMessageError::Invalid(err.to_string());
+        let value: serde_json::Value = serde_json::from_str(json).map_err(invalid)?;
+        let allowed: &[&str] = match value.get("type").and_then(|t| t.as_str()) {
+            Some("finish") => &["type", "result"],
+            Some("solved_correct" | "solved_incorrect") => &["type", "index"],
+            _ => &["type"],
+        };
+        if let Some(field) = value
+            .as_object()
+            .and_then(|fields| fields.keys().find(|key| !allowed.contains(&key.as_str())))
+        {
+            return Err(SandboxMessageError::Invalid(format!(
+                "unknown field `{field}`"
+            )));
+        }
+        serde_json::from_value(value).map_err(invalid)
+    }
+
+    /// The challenge command this message asks for, `None` for events.
+    pub fn into_command(self) -> Option<Command> {
+        let command = match self {
+            Self::SolvedCorrect { .. } | Self::SolvedIncorrect { .. } => return None,
+            Self::NextTask => ChallengeCommand::NextTask,
+            Self::PreviousTask => ChallengeCommand::PreviousTask,
+            Self::Finish { result } => {
+                ChallengeCommand::Finish(result.map(ChallengeResult::Custom))
+            }
+        };
+        Some(Command::Challenge(command))
+    }
+
+    /// The challenge event this message reports, `None` for commands.
+    pub fn to_event(&self) -> Option<Event> {
+        let event = match self {
+            Self::SolvedCorrect { index } => ChallengeEvent::SolvedCorrect(*index),
+            Self::SolvedIncorrect { index } => ChallengeEvent::SolvedIncorrect(*index),
+            _ => return None,
+        };
+        Some(Event::Challenge(event))
+    }
+}
+
+/// The only data the app passes into a sandboxed challenge. It is written
+/// into the frame's document, so nothing has to be posted to the frame.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "init")]
+pub struct SandboxInit {
+    pub challenge: Custom,
+    /// Translations of the challenge for the selected language.
+    pub i18n: serde_json::Value,
+    /// Result to show, for the results page of a challenge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<CustomChallengeResult>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands_are_mapped() {
+        assert_eq!(
+            SandboxMessage::parse(r#"{"type":"solved_correct","index":2}"#),
+            Ok(SandboxMessage::SolvedCorrect { index: 2 })
+        );
+        assert!(matches!(
+            SandboxMessage::parse(r#"{"type":"next_task"}"#)
+                .unwrap()
+                .into_command(),
+            Some(Command::Challenge(ChallengeCommand::NextTask))
+        ));
+        assert!(matches!(
+            SandboxMessage::parse(r#"{"type":"finish"}"#)
+                .unwrap()
+                .into_command(),
+            Some(Command::Challenge(ChallengeCommand::Finish(None)))
+        ));
+        assert!(
+            SandboxMessage::SolvedCorrect { index: 2 }
+                .into_command()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn events_are_mapped() {
+        assert!(matches!(
+            SandboxMessage::parse(r#"{"type":"solved_incorrect","index":1}"#)
+                .unwrap()
+                .to_event(),
+            Some(Event::Challenge(ChallengeEvent::SolvedIncorrect(1)))
+        ));
+        assert!(SandboxMessage::NextTask.to_event().is_none());
+    }
+
+    #[test]
+    fn rejects_anything_else() {
+        for json in [
+            r#"{"type":"save_game"}"#,
+            r#"{"type":"next_task","session":"x"}"#,
+            r#"{"action":"NextTask"}"#,
+            r#"{"type":"solved_correct"}"#,
+            r#"{"type":"solved_correct","index":-1}"#,
+            r#"{"type":"ready"}"#,
+            "not json",
+        ] {
+            assert!(matches!(
+                SandboxMessage::parse(json),
+                Err(SandboxMessageError::Invalid(_))
+            ));
+        }
+        let padded = format!(
+            r#"{{"type":"next_task"{}}}"#,
+            " ".repeat(MAX_SANDBOX_MESSAGE_BYTES)
+        );
+        assert!(matches!(
+            SandboxMessage::parse(&padded),
+            Err(SandboxMessageError::TooLarge(_))
+        ));
+    }
+}
@@ -1,4 +1,21 @@
+use super::{Accessibility, QuietHours};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Factor on the speaking rate while slow speech is on.
+pub const SLOW_SPEECH_FACTOR: f32 = 0.7;
+
+/// Primary subtag of a language tag, so `"de-AT"` and `"de_DE"` are `"de"`.
+pub fn primary_language(lang: &str) -> String {
+    lang.split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+fn default_speech_rate() -> f32 {
+    1.0
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Settings {
@@ -8,6 +25,22 @@ pub struct Settings {
     pub sound_volume: f32,
     pub theme: String,
     pub show_helpers: bool,
+    /// Turns off feedback sounds, independent of `sound_volume`.
+    #[serde(default)]
+    pub sound_muted: bool,
+    #[serde(default)]
+    pub quiet_hours: Option<QuietHours>,
+    /// Name of the preferred speech voice, keyed by primary language.
+    #[serde(default)]
+    pub speech_voices: BTreeMap<String, String>,
+    /// Speaking rate of text-to-speech, from `0.5` to `2.0`.
+    #[serde(default = "default_speech_rate")]
+    pub speech_rate: f32,
+    /// Read more slowly, for beginners.
+    #[serde(default)]
+    pub slow_speech: bool,
+    #[serde(default)]
+    pub accessibility: Accessibility,
 }
 
 impl Default for Settings {
@@ -19,10 +52,53 @@ impl Default for Settings {
             sound_volume: 0.3,
             theme: "light".to_string(),
             show_helpers: true,
+            sound_muted: false,
+            quiet_hours: None,
+            speech_voices: BTreeMap::new(),
+            speech_rate: default_speech_rate(),
+            slow_speech: false,
+            accessibility: Accessibility::default(),
         }
     }
 }
 
+impl Settings {
+    /// Whether feedback sounds may play at `hour` of the local day.
+    pub fn sounds_allowed(&self, hour: u8) -> bool {
+        !self.sound_muted
+            && self.sound_volume > 0.0
+            && !self
+                .quiet_hours
+                .is_some_and(|quiet_hours| quiet_hours.contains(hour))
+    }
+
+    /// Preferred voice for `lang`, in any of its regional variants.
+    pub fn speech_voice(&self, lang: &str) -> Option<&str> {
+        self.speech_voices
+            .get(&primary_language(lang))
+            .map(String::as_str)
+    }
+
+    /// Sets or, with `None`, clears the preferred voice for `lang`.
+    pub fn set_speech_voice(&mut self, lang: &str, voice: Option<String>) {
+        let lang = primary_language(lang);
+        match voice {
+            Some(voice) => self.speech_voices.insert(lang, voice),
+            None => self.speech_voices.remove(&lang),
+        };
+    }
+
+    /// Factor on the rate of all speech, including slow speech.
+    pub fn speech_rate_factor(&self) -> f32 {
+        let factor = if self.slow_speech {
+            SLOW_SPEECH_FACTOR
+        } else {
+            1.0
+        };
+        self.speech_rate.clamp(0.5, 2.0) * factor
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,4 +120,63 @@ mod tests {
         let settings2: Settings = serde_json::from_str(&json).unwrap();
         assert_eq!(settings, settings2);
     }
+
+    #[test]
+    fn older_settings_have_sounds() {
+        let json = r#"{"id":"","language":"de","music_volume":0.0,"sound_volume":0.5,"theme":"dark","show_helpers":true}"#;
+        let settings: Settings = serde_json::from_str(json).unwrap();
+        assert!(!settings.sound_muted);
+        assert_eq!(settings.quiet_hours, None);
+        assert!(settings.speech_voices.is_empty());
+        assert_eq!(settings.speech_rate, 1.0);
+        assert!(!settings.slow_speech);
+        assert_eq!(settings.accessibility, Accessibility::default());
+    }
+
+    #[test]
+    fn sounds_respect_mute_and_quiet_hours() {
+        let settings = Settings::default();
+        assert!(settings.sounds_allowed(23));
+        let muted = Settings {
+            sound_muted: true,
+            ..settings.clone()
+        };
+        assert!(!muted.sounds_allowed(12));
+        let quiet = Settings {
+            quiet_hours: Some(QuietHours::default()),
+            ..settings
+        };
+        assert!(!quiet.sounds_allowed(23));
+        assert!(quiet.sounds_allowed(12));
+    }
+
+    #[test]
+    fn speech_voices_are_kept_per_language() {
+        let mut settings = Settings::default();
+        settings.set_speech_voice("de-DE", Some("Anna".to_string()));
+        assert_eq!(settings.speech_voice("de"), Some("Anna"));
+        assert_eq!(settings.speech_voice("de_AT"), Some("Anna"));
+        assert_eq!(settings.speech_voice("en-US"), None);
+        settings.set_speech_voice("de", None);
+        assert_eq!(settings.speech_voice("de-DE"), None);
+    }
+
+    #[test]
+    fn slow_speech_slows_the_rate() {
+        let settings = Settings {
+            speech_rate: 1.2,
+            ..Settings::default()
+        };
+        assert_eq!(settings.speech_rate_factor(), 1.2);
+        let slow = Settings {
+            slow_speech: true,
+            ..settings
+        };
+        assert!((slow.speech_rate_factor() - 1.2 * SLOW_SPEECH_FACTOR).abs() < f32::EPSILON);
+        let too_fast = Settings {
+            speech_rate: 5.0,
+            ..Settings::default()
+        };
+        assert_eq!(too_fast.speech_rate_factor(), 2.0);
+    }
 }
@@ -0,0 +1,160 @@
+use konnektoren_core::events::{ChallengeEvent, Event};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A feedback sound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEffect {
+    Correct,
+    Incorrect,
+    Achievement,
+}
+
+impl SoundEffect {
+    /// Sound to give as feedback on `event`, if any.
+    pub fn for_event(event: &Event) -> Option<Self> {
+        match event {
+            Event::Challenge(ChallengeEvent::SolvedCorrect(_)) => Some(Self::Correct),
+            Event::Challenge(ChallengeEvent::SolvedIncorrect(_)) => Some(Self::Incorrect),
+            _ => None,
+        }
+    }
+}
+
+/// Audio files of the feedback sounds. The default set is shipped in
+/// `assets/sounds`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoundSet {
+    pub correct: String,
+    pub incorrect: String,
+    pub achievement: String,
+}
+
+impl Default for SoundSet {
+    fn default() -> Self {
+        Self {
+            correct: "/assets/sounds/correct.wav".to_string(),
+            incorrect: "/assets/sounds/incorrect.wav".to_string(),
+            achievement: "/assets/sounds/achievement.wav".to_string(),
+        }
+    }
+}
+
+impl SoundSet {
+    pub fn url(&self, effect: SoundEffect) -> &str {
+        match effect {
+            SoundEffect::Correct => &self.correct,
+            SoundEffect::Incorrect => &self.incorrect,
+            SoundEffect::Achievement => &self.achievement,
+        }
+    }
+
+    pub fn urls(&self) -> [&str; 3] {
+        [&self.correct, &self.incorrect, &self.achievement]
+    }
+}
+
+/// Hours of the day without feedback sounds, from `start` up to `end`,
+/// wrapping past midnight when `end` is before `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub start: u8,
+    pub end: u8,
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        Self { start: 22, end: 7 }
+    }
+}
+
+impl QuietHours {
+    pub fn contains(&self, hour: u8) -> bool {
+        if self.start <= self.end {
+            (self.start..self.end).contains(&hour)
+        } else {
+            hour >= self.start || hour < self.end
+        }
+    }
+}
+
+/// Drops repeats of a sound within `interval_ms`, e.g. when several answers
+/// are checked at once.
+#[derive(Debug, Clone, Default)]
+pub struct SoundDebouncer {
+    interval_ms: f64,
+    last_played: HashMap<SoundEffect, f64>,
+}
+
+impl SoundDebouncer {
+    pub fn new(interval_ms: f64) -> Self {
+        Self {
+            interval_ms,
+            last_played: HashMap::new(),
+        }
+    }
+
+    /// Whether `effect` may play at `now_ms`, which then counts as its last
+    /// play.
+    pub fn should_play(&mut self, effect: SoundEffect, now_ms: f64) -> bool {
+        if self
+            .last_played
+            .get(&effect)
+            .is_some_and(|last| now_ms - last < self.interval_ms)
+        {
+            return false;
+        }
+        self.last_played.insert(effect, now_ms);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solved_events_have_sounds() {
+        assert_eq!(
+            SoundEffect::for_event(&Event::Challenge(ChallengeEvent::SolvedCorrect(0))),
+            Some(SoundEffect::Correct)
+        );
+        assert_eq!(
+            SoundEffect::for_event(&Event::Challenge(ChallengeEvent::SolvedIncorrect(2))),
+            Some(SoundEffect::Incorrect)
+        );
+    }
+
+    #[test]
+    fn default_sounds_are_shipped() {
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        for url in SoundSet::default().urls() {
+            let path = root.join(url.trim_start_matches('/'));
+            assert!(path.is_file(), "{} is missing", path.display());
+        }
+    }
+
+    #[test]
+    fn quiet_hours_wrap_past_midnight() {
+        let night = QuietHours::default();
+        assert!(night.contains(22));
+        assert!(night.contains(0));
+        assert!(night.contains(6));
+        assert!(!night.contains(7));
+        assert!(!night.contains(12));
+
+        let lunch = QuietHours { start: 12, end: 14 };
+        assert!(lunch.contains(13));
+        assert!(!lunch.contains(14));
+        assert!(!QuietHours { start: 9, end: 9 }.contains(9));
+    }
+
+    #[test]
+    fn repeated_sounds_are_debounced() {
+        let mut debouncer = SoundDebouncer::new(300.0);
+        assert!(debouncer.should_play(SoundEffect::Correct, 1000.0));
+        assert!(!debouncer.should_play(SoundEffect::Correct, 1200.0));
+        assert!(debouncer.should_play(SoundEffect::Incorrect, 1200.0));
+        assert!(debouncer.should_play(SoundEffect::Correct, 1300.0));
+    }
+}
@@ -0,0 +1,182 @@
+use chrono::{DateTime, Duration, Utc};
+use konnektoren_core::challenges::{Challenge, ChallengeHistory};
+use serde::{Deserialize, Serialize};
+
+/// What a time limit applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TimeLimitScope {
+    /// One countdown for the whole challenge.
+    #[default]
+    Challenge,
+    /// The countdown restarts with every question.
+    Question,
+}
+
+/// Countdown after which a challenge is finished with the answers given so
+/// far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeLimit {
+    pub seconds: u32,
+    pub scope: TimeLimitScope,
+    /// Remaining seconds from which the countdown shows a warning.
+    pub warning_seconds: u32,
+}
+
+impl TimeLimit {
+    pub const DEFAULT_WARNING_SECONDS: u32 = 10;
+
+    pub fn per_challenge(seconds: u32) -> Self {
+        Self {
+            seconds,
+            scope: TimeLimitScope::Challenge,
+            warning_seconds: Self::DEFAULT_WARNING_SECONDS.min(seconds / 2),
+        }
+    }
+
+    pub fn per_question(seconds: u32) -> Self {
+        Self {
+            scope: TimeLimitScope::Question,
+            ..Self::per_challenge(seconds)
+        }
+    }
+
+    pub fn with_warning(mut self, warning_seconds: u32) -> Self {
+        self.warning_seconds = warning_seconds;
+        self
+    }
+
+    /// Time left after `elapsed`, never negative.
+    pub fn remaining(&self, elapsed: Duration) -> Duration {
+        (Duration::seconds(self.seconds.into()) - elapsed).max(Duration::zero())
+    }
+
+    pub fn is_warning(&self, remaining: Duration) -> bool {
+        remaining <= Duration::seconds(self.warning_seconds.into())
+    }
+}
+
+/// A challenge that was finished because its time limit ran out. Stored
+/// apart from the challenge history, which can't tell time-outs from
+/// incorrect answers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChallengeTimeout {
+    pub challenge_id: String,
+    /// Start time of the challenge, to find it in the history.
+    pub started_at: Option<DateTime<Utc>>,
+    pub timed_out_at: DateTime<Utc>,
+    /// The task that was open when time ran out.
+    pub task_index: usize,
+    pub time_limit: TimeLimit,
+}
+
+impl ChallengeTimeout {
+    /// How far the end time of a history entry may be from the time-out
+    /// for a challenge without a start time to match it.
+    pub const END_TIME_TOLERANCE_SECONDS: i64 = 5;
+
+    /// Whether `challenge` from the history is the attempt that timed out:
+    /// same start time, or, for challenges played without one, an end time
+    /// right at the time-out.
+    pub fn matches(&self, challenge: &Challenge) -> bool {
+        if self.challenge_id != challenge.challenge_config.id {
+            return false;
+        }
+        match self.started_at {
+            Some(started_at) => challenge.start_time == Some(started_at),
+            None => challenge.end_time.is_some_and(|end_time| {
+                (end_time - self.timed_out_at).num_seconds().abs()
+                    <= Self::END_TIME_TOLERANCE_SECONDS
+            }),
+        }
+    }
+}
+
+/// `history` without the challenges that timed out, e.g. to build an
+/// `AverageTimeTakenMetric` from completed attempts only.
+pub fn without_timeouts(
+    history: &ChallengeHistory,
+    timeouts: &[ChallengeTimeout],
+) -> ChallengeHistory {
+    let mut completed = ChallengeHistory::new();
+    for challenge in &history.challenges {
+        if !timeouts.iter().any(|timeout| timeout.matches(challenge)) {
+            completed.add_challenge(challenge.clone());
+        }
+    }
+    completed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remaining_and_warning() {
+        let limit = TimeLimit::per_challenge(60);
+        assert_eq!(limit.warning_seconds, 10);
+        assert_eq!(
+            limit.remaining(Duration::seconds(15)),
+            Duration::seconds(45)
+        );
+        assert_eq!(limit.remaining(Duration::seconds(90)), Duration::zero());
+        assert!(!limit.is_warning(Duration::seconds(11)));
+        assert!(limit.is_warning(Duration::seconds(10)));
+
+        let short = TimeLimit::per_question(8);
+        assert_eq!(short.scope, TimeLimitScope::Question);
+        assert_eq!(short.warning_seconds, 4);
+        assert_eq!(short.with_warning(2).warning_seconds, 2);
+    }
+
+    #[test]
+    fn timeouts_are_left_out_of_history() {
+        let started = Utc::now();
+        let challenge = |id: &str| {
+            let mut challenge = Challenge::default();
+            challenge.challenge_config.id = id.to_string();
+            challenge.start_time = Some(started);
+            challenge
+        };
+        let mut history = ChallengeHistory::new();
+        history.add_challenge(challenge("articles"));
+        history.add_challenge(challenge("verbs"));
+
+        let timeout = ChallengeTimeout {
+            challenge_id: "verbs".to_string(),
+            started_at: Some(started),
+            timed_out_at: started + Duration::seconds(60),
+            task_index: 3,
+            time_limit: TimeLimit::per_challenge(60),
+        };
+        let completed = without_timeouts(&history, &[timeout]);
+        assert_eq!(completed.challenges.len(), 1);
+        assert_eq!(completed.challenges[0].challenge_config.id, "articles");
+    }
+
+    #[test]
+    fn timeouts_without_start_time_match_by_end_time() {
+        let timed_out_at = Utc::now();
+        let timeout = ChallengeTimeout {
+            challenge_id: "verbs".to_string(),
+            started_at: None,
+            timed_out_at,
+            task_index: 0,
+            time_limit: TimeLimit::per_question(10),
+        };
+        let finished = |end_time: Option<DateTime<Utc>>| {
+            let mut challenge = Challenge::default();
+            challenge.challenge_config.id = "verbs".to_string();
+            challenge.start_time = Some(timed_out_at - Duration::seconds(40));
+            challenge.end_time = end_time;
+            challenge
+        };
+        assert!(timeout.matches(&finished(Some(timed_out_at + Duration::seconds(1)))));
+        assert!(!timeout.matches(&finished(Some(timed_out_at + Duration::minutes(3)))));
+        assert!(!timeout.matches(&finished(None)));
+
+        let mut history = ChallengeHistory::new();
+        history.add_challenge(finished(Some(timed_out_at)));
+        history.add_challenge(finished(Some(timed_out_at - Duration::hours(1))));
+        assert_eq!(without_timeouts(&history, &[timeout]).challenges.len(), 1);
+    }
+}
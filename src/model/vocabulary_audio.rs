@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Recorded pronunciations of the items of a vocabulary.
+///
+/// Vocabulary items in `konnektoren-core` have no audio, so the recordings
+/// are kept beside the `Vocabulary` and refer to its items by id. Challenge
+/// files give them as an `audio` key on the items, see [`Self::from_yaml`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VocabularyAudio {
+    /// Audio file of each item, keyed by item id.
+    #[serde(default)]
+    pub files: BTreeMap<usize, String>,
+}
+
+#[derive(Deserialize)]
+struct AudioItem {
+    id: usize,
+    #[serde(default)]
+    audio: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AudioItems {
+    #[serde(default)]
+    items: Vec<AudioItem>,
+}
+
+impl VocabularyAudio {
+    /// Reads the `audio` keys of the items of a vocabulary challenge file,
+    /// with or without the challenge type tag around the vocabulary.
+    pub fn from_yaml(source: &str) -> Result<Self, serde_yaml::Error> {
+        let value: serde_yaml::Value = serde_yaml::from_str(source)?;
+        let vocabulary = match value.get("items") {
+            Some(_) => value,
+            None => value
+                .as_mapping()
+                .and_then(|mapping| mapping.values().find(|v| v.get("items").is_some()))
+                .cloned()
+                .unwrap_or(value),
+        };
+        let items: AudioItems = serde_yaml::from_value(vocabulary)?;
+        let files = items
+            .items
+            .into_iter()
+            .filter_map(|item| Some((item.id, item.audio?)))
+            .filter(|(_, file)| !file.trim().is_empty())
+            .collect();
+        Ok(Self { files })
+    }
+
+    pub fn file(&self, item_id: usize) -> Option<&str> {
+        self.files.get(&item_id).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+/// Path of an asset below the site root, `None` for files on other hosts.
+pub fn local_asset_path(url: &str) -> Option<&str> {
+    let url = url.trim();
+    let remote = url.starts_with("//")
+        || url.split_once(':').is_some_and(|(scheme, _)| {
+            !scheme.is_empty()
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+        });
+    if remote {
+        None
+    } else {
+        Some(url.trim_start_matches("./").trim_start_matches('/'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn audio_is_read_from_items() {
+        let source = r#"
+id: animals
+name: Tiere
+items:
+  - id: 0
+    text: der Hund
+    audio: /assets/audio/hund.mp3
+  - id: 1
+    text: die Katze
+  - id: 2
+    text: das Pferd
+    audio: ""
+"#;
+        let audio = VocabularyAudio::from_yaml(source).unwrap();
+        assert_eq!(audio.file(0), Some("/assets/audio/hund.mp3"));
+        assert_eq!(audio.file(1), None);
+        assert_eq!(audio.file(2), None);
+    }
+
+    #[test]
+    fn audio_is_read_from_tagged_challenges() {
+        let source = r#"
+vocabulary:
+  id: animals
+  items:
+    - id: 3
+      text: der Vogel
+      audio: audio/vogel.ogg
+"#;
+        let audio = VocabularyAudio::from_yaml(source).unwrap();
+        assert_eq!(audio.file(3), Some("audio/vogel.ogg"));
+        assert!(VocabularyAudio::from_yaml("id: empty").unwrap().is_empty());
+    }
+
+    #[test]
+    fn remote_assets_are_not_local() {
+        assert_eq!(
+            local_asset_path("/assets/audio/hund.mp3"),
+            Some("assets/audio/hund.mp3")
+        );
+        assert_eq!(local_asset_path("./audio/hund.mp3"), Some("audio/hund.mp3"));
+        assert_eq!(local_asset_path("https://konnektoren.help/hund.mp3"), None);
+        assert_eq!(local_asset_path("//cdn.example.com/hund.mp3"), None);
+        assert_eq!(local_asset_path("data:audio/ogg;base64,AAAA"), None);
+    }
+}
@@ -1,5 +1,5 @@
 use super::repository_hooks::{use_session, use_session_repository};
-use crate::repository::GameStatePersistenceImpl;
+use crate::repository::{GameStatePersistenceImpl, PracticeGameStatePersistence};
 use konnektoren_core::commands::CommandBus;
 use konnektoren_core::controller::{
     ChallengeFinishPlugin, GameController, GameControllerTrait, GameXpPlugin,
@@ -12,11 +12,20 @@ use yew::prelude::*;
 #[derive(Clone, PartialEq)]
 pub struct GameControllerContext {
     pub controller: Arc<GameController>,
+    /// Challenges are played for practice: no XP, leaderboard or history.
+    pub practice: bool,
 }
 
 impl GameControllerContext {
     pub fn new(controller: Arc<GameController>) -> Self {
-        Self { controller }
+        Self {
+            controller,
+            practice: false,
+        }
+    }
+
+    pub fn with_practice(self, practice: bool) -> Self {
+        Self { practice, ..self }
     }
 }
 
@@ -69,6 +78,10 @@ pub struct GameControllerProviderProps {
     pub children: Children,
     #[prop_or_default]
     pub game_controller: Option<Arc<GameController>>,
+    /// Practice mode: the default controller runs without the XP and
+    /// challenge finish plugins and doesn't save the game state.
+    #[prop_or(false)]
+    pub practice: bool,
 }
 
 #[function_component(GameControllerProvider)]
@@ -82,12 +95,18 @@ pub fn game_controller_provider(props: &GameControllerProviderProps) -> Html {
             let game = Game::default();
             let session = Arc::new(RwLock::new((*session).clone()));
 
-            let persistence = Arc::new(GameStatePersistenceImpl::new(session_repository, session));
-
-            let mut controller = GameController::new(game, persistence);
-            controller.register_plugin(Arc::new(ChallengeFinishPlugin));
-            controller.register_plugin(Arc::new(GameXpPlugin));
-            controller.init()
+            if props.practice {
+                let persistence = Arc::new(PracticeGameStatePersistence::new(session));
+                GameController::new(game, persistence).init()
+            } else {
+                let persistence =
+                    Arc::new(GameStatePersistenceImpl::new(session_repository, session));
+
+                let mut controller = GameController::new(game, persistence);
+                controller.register_plugin(Arc::new(ChallengeFinishPlugin));
+                controller.register_plugin(Arc::new(GameXpPlugin));
+                controller.init()
+            }
         }
     };
 
@@ -96,7 +115,7 @@ pub fn game_controller_provider(props: &GameControllerProviderProps) -> Html {
         tracing::error!("Failed to load game state: {:?}", e);
     }
 
-    let context = GameControllerContext::new(controller.clone());
+    let context = GameControllerContext::new(controller.clone()).with_practice(props.practice);
 
     {
         let session = session.clone();
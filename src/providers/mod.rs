@@ -19,8 +19,10 @@ pub mod profile_provider;
 mod repository_context;
 pub mod repository_hooks;
 pub mod repository_provider;
+pub mod review_provider;
 pub mod session_provider;
 pub mod settings_provider;
+pub mod sound_provider;
 #[cfg(feature = "ssr")]
 pub mod ssr_impl;
 pub mod theme_provider;
@@ -39,13 +41,16 @@ pub use inbox_provider::{InboxContext, InboxProvider};
 use profile_provider::{ProfileContext, ProfileProvider};
 pub use repository_context::RepositoryContext;
 pub use repository_hooks::{
-    use_certificate_repository, use_certificates, use_inbox, use_inbox_repository, use_profile,
-    use_profile_repository, use_session, use_session_repository, use_settings,
+    use_attempt_records, use_attempt_repository, use_certificate_repository, use_certificates,
+    use_challenge_timeouts, use_inbox, use_inbox_repository, use_profile, use_profile_repository,
+    use_review_repository, use_session, use_session_repository, use_settings,
     use_settings_repository,
 };
 pub use repository_provider::{
     RepositoryConfig, RepositoryProvider, RepositoryProviderProps, create_repositories,
 };
+pub use review_provider::{ReviewContext, ReviewProvider, ReviewProviderProps};
 pub use session_provider::{SessionContext, SessionProvider};
 pub use settings_provider::{SettingsContext, SettingsProvider};
+pub use sound_provider::{SoundContext, SoundProvider, SoundProviderProps, use_sound_effect};
 pub use theme_provider::{ThemeContext, ThemeProvider, use_theme};
@@ -1,7 +1,8 @@
 use super::RepositoryConfig;
 use crate::repository::{
-    CertificateRepositoryTrait, InboxRepositoryTrait, ProfileRepositoryTrait,
-    SessionRepositoryTrait, SettingsRepositoryTrait,
+    AttemptRepositoryTrait, CertificateRepositoryTrait, ContentCacheRepositoryTrait,
+    InboxRepositoryTrait, ProfileRepositoryTrait, ReviewRepositoryTrait, SessionRepositoryTrait,
+    SettingsRepositoryTrait,
 };
 use konnektoren_core::certificates::CertificateData;
 use std::sync::{Arc, RwLock};
@@ -13,6 +14,9 @@ pub struct RepositoryContext {
     pub profile_repository: Arc<dyn ProfileRepositoryTrait>,
     pub inbox_repository: Arc<dyn InboxRepositoryTrait>,
     pub session_repository: Arc<dyn SessionRepositoryTrait>,
+    pub review_repository: Arc<dyn ReviewRepositoryTrait>,
+    pub attempt_repository: Arc<dyn AttemptRepositoryTrait>,
+    pub content_cache_repository: Arc<dyn ContentCacheRepositoryTrait>,
     pub certificates: Arc<RwLock<Vec<CertificateData>>>,
 }
 
@@ -23,6 +27,12 @@ impl PartialEq for RepositoryContext {
             && Arc::ptr_eq(&self.profile_repository, &other.profile_repository)
             && Arc::ptr_eq(&self.inbox_repository, &other.inbox_repository)
             && Arc::ptr_eq(&self.session_repository, &other.session_repository)
+            && Arc::ptr_eq(&self.review_repository, &other.review_repository)
+            && Arc::ptr_eq(&self.attempt_repository, &other.attempt_repository)
+            && Arc::ptr_eq(
+                &self.content_cache_repository,
+                &other.content_cache_repository,
+            )
             && Arc::ptr_eq(&self.certificates, &other.certificates)
     }
 }
@@ -35,6 +45,9 @@ impl RepositoryContext {
             profile_repository: config.profile_repository,
             inbox_repository: config.inbox_repository,
             session_repository: config.session_repository,
+            review_repository: config.review_repository,
+            attempt_repository: config.attempt_repository,
+            content_cache_repository: config.content_cache_repository,
             certificates: Arc::new(RwLock::new(Vec::new())),
         }
     }
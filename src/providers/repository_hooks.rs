@@ -1,11 +1,11 @@
-use crate::model::{Inbox, Settings};
+use crate::model::{AttemptRecords, ChallengeTimeout, Inbox, Settings};
 use crate::providers::{
     CertificatesContext, InboxContext, ProfileContext, RepositoryContext, SessionContext,
     SettingsContext,
 };
 use crate::repository::{
-    CertificateRepositoryTrait, InboxRepositoryTrait, ProfileRepositoryTrait,
-    SessionRepositoryTrait, SettingsRepositoryTrait,
+    AttemptRepositoryTrait, CertificateRepositoryTrait, InboxRepositoryTrait,
+    ProfileRepositoryTrait, ReviewRepositoryTrait, SessionRepositoryTrait, SettingsRepositoryTrait,
 };
 use konnektoren_core::certificates::CertificateData;
 use konnektoren_core::prelude::{PlayerProfile, Session};
@@ -106,6 +106,42 @@ pub fn use_session_repository() -> Arc<dyn SessionRepositoryTrait> {
     }
 }
 
+#[hook]
+pub fn use_review_repository() -> Arc<dyn ReviewRepositoryTrait> {
+    #[cfg(feature = "ssr")]
+    {
+        static INSTANCE: std::sync::OnceLock<Arc<dyn ReviewRepositoryTrait>> =
+            std::sync::OnceLock::new();
+        INSTANCE
+            .get_or_init(|| ssr_impl::review_repository())
+            .clone()
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        use_context::<RepositoryContext>()
+            .expect("RepositoryContext not found")
+            .review_repository
+    }
+}
+
+#[hook]
+pub fn use_attempt_repository() -> Arc<dyn AttemptRepositoryTrait> {
+    #[cfg(feature = "ssr")]
+    {
+        static INSTANCE: std::sync::OnceLock<Arc<dyn AttemptRepositoryTrait>> =
+            std::sync::OnceLock::new();
+        INSTANCE
+            .get_or_init(|| ssr_impl::attempt_repository())
+            .clone()
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        use_context::<RepositoryContext>()
+            .expect("RepositoryContext not found")
+            .attempt_repository
+    }
+}
+
 #[hook]
 pub fn use_session() -> UseStateHandle<Session> {
     use_context::<SessionContext>()
@@ -168,3 +204,65 @@ pub fn use_certificates() -> UseStateHandle<Vec<CertificateData>> {
             .certificates
     }
 }
+
+/// Hints and dialog outcomes recorded with finished challenges, reloaded
+/// whenever the history holds `history_len` challenges. Empty without a
+/// repository.
+#[hook]
+pub fn use_attempt_records(history_len: usize) -> AttemptRecords {
+    let attempt_repository = use_context::<RepositoryContext>().map(|c| c.attempt_repository);
+    let records = use_state(AttemptRecords::default);
+    {
+        let records = records.clone();
+        use_effect_with(history_len, move |_| {
+            #[cfg(feature = "csr")]
+            {
+                if let Some(attempt_repository) = attempt_repository {
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let hints = attempt_repository.get_hints().await;
+                        let dialog_outcomes = attempt_repository.get_dialog_outcomes().await;
+                        match (hints, dialog_outcomes) {
+                            (Ok(hints), Ok(dialog_outcomes)) => records.set(AttemptRecords {
+                                hints,
+                                dialog_outcomes,
+                            }),
+                            (Err(e), _) | (_, Err(e)) => {
+                                tracing::error!("Failed to load attempt records: {:?}", e)
+                            }
+                        }
+                    });
+                }
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (attempt_repository, records);
+        });
+    }
+    (*records).clone()
+}
+
+/// Challenges that ran out of time, reloaded whenever the history holds
+/// `history_len` challenges. Empty without a repository.
+#[hook]
+pub fn use_challenge_timeouts(history_len: usize) -> Vec<ChallengeTimeout> {
+    let attempt_repository = use_context::<RepositoryContext>().map(|c| c.attempt_repository);
+    let timeouts = use_state(Vec::<ChallengeTimeout>::new);
+    {
+        let timeouts = timeouts.clone();
+        use_effect_with(history_len, move |_| {
+            #[cfg(feature = "csr")]
+            {
+                if let Some(attempt_repository) = attempt_repository {
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match attempt_repository.get_timeouts().await {
+                            Ok(loaded) => timeouts.set(loaded),
+                            Err(e) => tracing::error!("Failed to load time-outs: {:?}", e),
+                        }
+                    });
+                }
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (attempt_repository, timeouts);
+        });
+    }
+    (*timeouts).clone()
+}
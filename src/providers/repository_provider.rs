@@ -4,9 +4,11 @@ use super::{
 };
 use crate::model::SessionInitializer;
 use crate::repository::{
-    CertificateRepository, CertificateRepositoryTrait, InboxRepository, InboxRepositoryTrait,
-    ProfileRepository, ProfileRepositoryTrait, SessionRepository, SessionRepositoryTrait,
-    SettingsRepository, SettingsRepositoryTrait, Storage,
+    AttemptRepository, AttemptRepositoryTrait, CertificateRepository, CertificateRepositoryTrait,
+    ContentCacheRepository, ContentCacheRepositoryTrait, InboxRepository, InboxRepositoryTrait,
+    ProfileRepository, ProfileRepositoryTrait, ReviewRepository, ReviewRepositoryTrait,
+    SessionRepository, SessionRepositoryTrait, SettingsRepository, SettingsRepositoryTrait,
+    Storage,
 };
 use std::sync::Arc;
 use yew::prelude::*;
@@ -18,6 +20,9 @@ pub struct RepositoryConfig {
     pub profile_repository: Arc<dyn ProfileRepositoryTrait>,
     pub inbox_repository: Arc<dyn InboxRepositoryTrait>,
     pub session_repository: Arc<dyn SessionRepositoryTrait>,
+    pub review_repository: Arc<dyn ReviewRepositoryTrait>,
+    pub attempt_repository: Arc<dyn AttemptRepositoryTrait>,
+    pub content_cache_repository: Arc<dyn ContentCacheRepositoryTrait>,
     pub session_initializer: Arc<dyn SessionInitializer>,
 }
 
@@ -28,6 +33,12 @@ impl PartialEq for RepositoryConfig {
             && Arc::ptr_eq(&self.profile_repository, &other.profile_repository)
             && Arc::ptr_eq(&self.inbox_repository, &other.inbox_repository)
             && Arc::ptr_eq(&self.session_repository, &other.session_repository)
+            && Arc::ptr_eq(&self.review_repository, &other.review_repository)
+            && Arc::ptr_eq(&self.attempt_repository, &other.attempt_repository)
+            && Arc::ptr_eq(
+                &self.content_cache_repository,
+                &other.content_cache_repository,
+            )
             && Arc::ptr_eq(&self.session_initializer, &other.session_initializer)
     }
 }
@@ -45,8 +56,14 @@ pub fn create_repositories<S: Storage + Send + Sync + 'static>(
             as Arc<dyn ProfileRepositoryTrait>,
         inbox_repository: Arc::new(InboxRepository::new(storage.clone()))
             as Arc<dyn InboxRepositoryTrait>,
-        session_repository: Arc::new(SessionRepository::new(storage))
+        session_repository: Arc::new(SessionRepository::new(storage.clone()))
             as Arc<dyn SessionRepositoryTrait>,
+        review_repository: Arc::new(ReviewRepository::new(storage.clone()))
+            as Arc<dyn ReviewRepositoryTrait>,
+        attempt_repository: Arc::new(AttemptRepository::new(storage.clone()))
+            as Arc<dyn AttemptRepositoryTrait>,
+        content_cache_repository: Arc::new(ContentCacheRepository::new(storage))
+            as Arc<dyn ContentCacheRepositoryTrait>,
         session_initializer,
     }
 }
@@ -0,0 +1,119 @@
+use crate::model::{ReviewSchedule, current_day};
+use crate::providers::RepositoryContext;
+use crate::repository::{REVIEW_STORAGE_KEY, ReviewRepositoryTrait};
+use konnektoren_core::challenges::ChallengeHistory;
+use std::sync::Arc;
+use yew::prelude::*;
+
+/// The review schedule shared by all review components below a
+/// [`ReviewProvider`]. `None` until loaded.
+#[derive(Clone, PartialEq)]
+pub struct ReviewContext {
+    pub schedule: UseStateHandle<Option<ReviewSchedule>>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReviewProviderProps {
+    pub challenge_history: ChallengeHistory,
+    pub children: Children,
+}
+
+/// Loads the review schedule once, adds the mistakes of newly finished
+/// challenges and shares it, so a review queue and any number of badges
+/// don't each load and save their own copy.
+#[function_component(ReviewProvider)]
+pub fn review_provider(props: &ReviewProviderProps) -> Html {
+    let schedule = use_synced_review_schedule(props.challenge_history.clone(), true);
+    let context = ReviewContext { schedule };
+
+    html! {
+        <ContextProvider<ReviewContext> {context}>
+            { for props.children.iter() }
+        </ContextProvider<ReviewContext>>
+    }
+}
+
+/// Loads the schedule and syncs it with `challenge_history` while `enabled`.
+/// Without a repository the schedule only lives in memory.
+#[hook]
+pub(crate) fn use_synced_review_schedule(
+    challenge_history: ChallengeHistory,
+    enabled: bool,
+) -> UseStateHandle<Option<ReviewSchedule>> {
+    let review_repository = use_context::<RepositoryContext>().map(|c| c.review_repository);
+    let schedule = use_state(|| None::<ReviewSchedule>);
+
+    {
+        let schedule = schedule.clone();
+        use_effect_with(
+            (challenge_history, enabled),
+            move |(challenge_history, enabled)| {
+                if !*enabled {
+                    return;
+                }
+                let challenge_history = challenge_history.clone();
+                match (review_repository, (*schedule).clone()) {
+                    // Loaded already: only sync, and save if that changed it
+                    (review_repository, Some(mut loaded)) => {
+                        if loaded.sync_history(&challenge_history, current_day()) {
+                            if let Some(review_repository) = review_repository {
+                                save_schedule(review_repository, loaded.clone());
+                            }
+                            schedule.set(Some(loaded));
+                        }
+                    }
+                    (Some(review_repository), None) => {
+                        load_schedule(review_repository, challenge_history, schedule)
+                    }
+                    (None, None) => {
+                        let mut loaded = ReviewSchedule::default();
+                        loaded.sync_history(&challenge_history, current_day());
+                        schedule.set(Some(loaded));
+                    }
+                }
+            },
+        );
+    }
+
+    schedule
+}
+
+fn load_schedule(
+    review_repository: Arc<dyn ReviewRepositoryTrait>,
+    challenge_history: ChallengeHistory,
+    schedule: UseStateHandle<Option<ReviewSchedule>>,
+) {
+    #[cfg(feature = "csr")]
+    wasm_bindgen_futures::spawn_local(async move {
+        let mut loaded = match review_repository.get_schedule(REVIEW_STORAGE_KEY).await {
+            Ok(loaded) => loaded.unwrap_or_default(),
+            Err(e) => {
+                tracing::error!("Failed to load review schedule: {:?}", e);
+                ReviewSchedule::default()
+            }
+        };
+        if loaded.sync_history(&challenge_history, current_day()) {
+            save_schedule(review_repository, loaded.clone());
+        }
+        schedule.set(Some(loaded));
+    });
+    #[cfg(not(feature = "csr"))]
+    let _ = (review_repository, challenge_history, schedule);
+}
+
+pub(crate) fn save_schedule(
+    review_repository: Arc<dyn ReviewRepositoryTrait>,
+    schedule: ReviewSchedule,
+) {
+    #[cfg(feature = "csr")]
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Err(e) = review_repository
+            .save_schedule(REVIEW_STORAGE_KEY, &schedule)
+            .await
+        {
+            tracing::error!("Failed to save review schedule: {:?}", e);
+        }
+    });
+    #[cfg(not(feature = "csr"))]
+    let _ = (review_repository, schedule);
+}
@@ -24,6 +24,7 @@ impl PartialEq for SettingsProviderProps {
 pub fn settings_provider(props: &SettingsProviderProps) -> Html {
     // IMPORTANT: use_state must be called OUTSIDE cfg blocks to maintain hook ordering
     let settings = use_state(Settings::default);
+    let system_reduced_motion = use_state(|| false);
 
     // Load settings (CSR only)
     #[cfg(feature = "csr")]
@@ -68,6 +69,39 @@ pub fn settings_provider(props: &SettingsProviderProps) -> Html {
         });
     }
 
+    // Follow `prefers-reduced-motion` of the system (CSR only)
+    #[cfg(feature = "csr")]
+    {
+        let system_reduced_motion = system_reduced_motion.clone();
+        use_effect_with((), move |_| {
+            let query = gloo::utils::window()
+                .match_media("(prefers-reduced-motion: reduce)")
+                .ok()
+                .flatten();
+            let listener = query.map(|query| {
+                system_reduced_motion.set(query.matches());
+                let target = query.clone();
+                gloo::events::EventListener::new(&query, "change", move |_| {
+                    system_reduced_motion.set(target.matches());
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    // Apply the accessibility preferences as body classes (CSR only)
+    #[cfg(feature = "csr")]
+    {
+        use_effect_with(
+            (settings.accessibility.clone(), *system_reduced_motion),
+            |(accessibility, system_reduced_motion)| {
+                accessibility.apply_to_body(*system_reduced_motion);
+            },
+        );
+    }
+    #[cfg(not(feature = "csr"))]
+    let _ = system_reduced_motion;
+
     let context = SettingsContext { settings };
 
     html! {
@@ -0,0 +1,158 @@
+use super::{GameControllerContext, use_settings};
+use crate::model::{SoundDebouncer, SoundEffect, SoundSet};
+use konnektoren_core::events::EventType;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use yew::prelude::*;
+
+static NEXT_PROVIDER_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Play callbacks of the mounted providers. Event bus listeners must be
+    /// `Send + Sync`, so they only keep the provider id and look the callback
+    /// up here. The bus can't unsubscribe, so the listener of an unmounted
+    /// provider finds nothing.
+    static PLAYERS: RefCell<HashMap<usize, Callback<SoundEffect>>> = RefCell::new(HashMap::new());
+}
+
+#[derive(Clone, PartialEq)]
+pub struct SoundContext {
+    /// Plays a feedback sound, unless sounds are muted or quiet right now.
+    pub play: Callback<SoundEffect>,
+}
+
+/// Callback that plays a feedback sound. Does nothing outside a
+/// [`SoundProvider`].
+#[hook]
+pub fn use_sound_effect() -> Callback<SoundEffect> {
+    use_context::<SoundContext>()
+        .map(|context| context.play)
+        .unwrap_or_else(Callback::noop)
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SoundProviderProps {
+    pub children: Children,
+    #[prop_or_default]
+    pub sounds: SoundSet,
+    /// Minimum time between two plays of the same sound.
+    #[prop_or(250)]
+    pub debounce_ms: u32,
+    /// Keep quiet while motion is reduced, by the accessibility settings or
+    /// the system.
+    #[prop_or(true)]
+    pub respect_reduced_motion: bool,
+}
+
+#[cfg(feature = "csr")]
+fn prefers_reduced_motion() -> bool {
+    gloo::utils::window()
+        .match_media("(prefers-reduced-motion: reduce)")
+        .ok()
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
+
+/// Plays the feedback sounds requested with [`use_sound_effect`] at the
+/// sound volume of the settings. `ChallengeComponent` requests them for the
+/// answers its challenges report. Inside a `GameControllerProvider`, answers
+/// on its `EventBus` play them too; an answer reported both ways is played
+/// once, as the debouncer drops the second play.
+#[function_component(SoundProvider)]
+pub fn sound_provider(props: &SoundProviderProps) -> Html {
+    let settings = use_settings();
+    let event_bus = use_context::<GameControllerContext>()
+        .map(|context| context.controller.event_bus().clone());
+    let debouncer = use_mut_ref(|| SoundDebouncer::new(f64::from(props.debounce_ms)));
+    let id = *use_state(|| NEXT_PROVIDER_ID.fetch_add(1, Ordering::Relaxed));
+
+    use_effect_with(props.sounds.clone(), |sounds| {
+        #[cfg(feature = "csr")]
+        for url in sounds.urls() {
+            crate::tools::preload_audio(url);
+        }
+        #[cfg(not(feature = "csr"))]
+        let _ = sounds;
+        || ()
+    });
+
+    let play = {
+        let settings = (*settings).clone();
+        let sounds = props.sounds.clone();
+        let respect_reduced_motion = props.respect_reduced_motion;
+        Callback::from(move |effect: SoundEffect| {
+            #[cfg(feature = "csr")]
+            {
+                let hour = js_sys::Date::new_0().get_hours() as u8;
+                if !settings.sounds_allowed(hour)
+                    || (respect_reduced_motion
+                        && settings
+                            .accessibility
+                            .reduces_motion(prefers_reduced_motion()))
+                    || !debouncer
+                        .borrow_mut()
+                        .should_play(effect, js_sys::Date::now())
+                {
+                    return;
+                }
+                let url = sounds.url(effect).to_string();
+                let volume = settings.sound_volume;
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Err(e) = crate::tools::play_audio(&url, volume).await {
+                        tracing::debug!("{}", e);
+                    }
+                });
+            }
+            #[cfg(not(feature = "csr"))]
+            let _ = (
+                &settings,
+                &sounds,
+                respect_reduced_motion,
+                &debouncer,
+                effect,
+            );
+        })
+    };
+
+    {
+        let play = play.clone();
+        use_effect_with(
+            (
+                (*settings).clone(),
+                props.sounds.clone(),
+                props.respect_reduced_motion,
+            ),
+            move |_| {
+                PLAYERS.with(|players| players.borrow_mut().insert(id, play));
+                || ()
+            },
+        );
+    }
+
+    use_effect_with(id, move |id| {
+        let id = *id;
+        if let Some(event_bus) = event_bus {
+            event_bus.subscribe(EventType::Challenge, move |event| {
+                let Some(effect) = SoundEffect::for_event(&event) else {
+                    return;
+                };
+                let play = PLAYERS.with(|players| players.borrow().get(&id).cloned());
+                if let Some(play) = play {
+                    play.emit(effect);
+                }
+            });
+        }
+        move || {
+            PLAYERS.with(|players| players.borrow_mut().remove(&id));
+        }
+    });
+
+    let context = SoundContext { play };
+
+    html! {
+        <ContextProvider<SoundContext> {context}>
+            {props.children.clone()}
+        </ContextProvider<SoundContext>>
+    }
+}
@@ -1,13 +1,17 @@
-use crate::model::{Inbox, Settings};
+use crate::model::{
+    ChallengeCheckpoint, ChallengeHints, ChallengeTimeout, DialogOutcome, Inbox, ReviewSchedule,
+    Settings,
+};
 use crate::providers::{
     CertificatesContext, InboxContext, ProfileContext, RepositoryContext, SessionContext,
     SettingsContext,
 };
 use crate::repository::{
-    CertificateRepositoryTrait, InboxRepositoryTrait, ProfileRepositoryTrait,
-    SessionRepositoryTrait, SettingsRepositoryTrait,
+    AttemptRepositoryTrait, CertificateRepositoryTrait, InboxRepositoryTrait,
+    ProfileRepositoryTrait, ReviewRepositoryTrait, SessionRepositoryTrait, SettingsRepositoryTrait,
 };
 use konnektoren_core::certificates::CertificateData;
+use konnektoren_core::challenges::{Challenge, ChallengeHistory};
 use konnektoren_core::prelude::{PlayerProfile, Session};
 use std::sync::Arc;
 use yew::prelude::*;
@@ -128,6 +132,63 @@ pub mod ssr_impl {
         }
     }
 
+    #[async_trait]
+    impl AttemptRepositoryTrait for DummyRepository {
+        async fn save_checkpoint(
+            &self,
+            _: &str,
+            _: &ChallengeCheckpoint,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn get_checkpoint(
+            &self,
+            _: &str,
+        ) -> Result<Option<ChallengeCheckpoint>, RepositoryError> {
+            Ok(None)
+        }
+        async fn delete_checkpoint(&self, _: &str) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn add_mistake_review(&self, _: &Challenge) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn get_mistake_reviews(&self) -> Result<ChallengeHistory, RepositoryError> {
+            Ok(ChallengeHistory::new())
+        }
+        async fn add_timeout(&self, _: &ChallengeTimeout) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn get_timeouts(&self) -> Result<Vec<ChallengeTimeout>, RepositoryError> {
+            Ok(vec![])
+        }
+        async fn add_hints(&self, _: &ChallengeHints) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn get_hints(&self) -> Result<Vec<ChallengeHints>, RepositoryError> {
+            Ok(vec![])
+        }
+        async fn add_dialog_outcome(&self, _: &DialogOutcome) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn get_dialog_outcomes(&self) -> Result<Vec<DialogOutcome>, RepositoryError> {
+            Ok(vec![])
+        }
+    }
+
+    #[async_trait]
+    impl ReviewRepositoryTrait for DummyRepository {
+        async fn save_schedule(&self, _: &str, _: &ReviewSchedule) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+        async fn get_schedule(&self, _: &str) -> Result<Option<ReviewSchedule>, RepositoryError> {
+            Ok(None)
+        }
+        async fn delete_schedule(&self, _: &str) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
     pub fn certificate_repository() -> Arc<dyn CertificateRepositoryTrait> {
         Arc::new(DummyRepository) as Arc<dyn CertificateRepositoryTrait>
     }
@@ -147,4 +208,12 @@ pub mod ssr_impl {
     pub fn session_repository() -> Arc<dyn SessionRepositoryTrait> {
         Arc::new(DummyRepository) as Arc<dyn SessionRepositoryTrait>
     }
+
+    pub fn review_repository() -> Arc<dyn ReviewRepositoryTrait> {
+        Arc::new(DummyRepository) as Arc<dyn ReviewRepositoryTrait>
+    }
+
+    pub fn attempt_repository() -> Arc<dyn AttemptRepositoryTrait> {
+        Arc::new(DummyRepository) as Arc<dyn AttemptRepositoryTrait>
+    }
 }
@@ -0,0 +1,278 @@
+use super::repository_error::RepositoryError;
+use super::storage::Storage;
+use crate::model::{ChallengeCheckpoint, ChallengeHints, ChallengeTimeout, DialogOutcome};
+use async_trait::async_trait;
+use konnektoren_core::challenges::{Challenge, ChallengeHistory};
+
+pub const CHECKPOINT_STORAGE_KEY: &str = "konnektoren_checkpoint";
+/// Mistake reviews are kept apart from the game's challenge history so they
+/// don't count towards XP or progress.
+pub const MISTAKE_REVIEW_STORAGE_KEY: &str = "konnektoren_mistake_reviews";
+/// Challenges that ran out of time, so they can be told apart from
+/// incorrect answers in the history.
+pub const TIMEOUT_STORAGE_KEY: &str = "konnektoren_timeouts";
+/// Number of time-outs kept; older ones are dropped first.
+pub const MAX_TIMEOUT_RECORDS: usize = 500;
+/// Hints taken in finished challenges, to score the history with them.
+pub const HINTS_STORAGE_KEY: &str = "konnektoren_hints";
+/// Number of hint records kept; older ones are dropped first.
+pub const MAX_HINT_RECORDS: usize = 500;
+/// Scores of branching dialogs, which core can't score without the graph.
+pub const DIALOG_OUTCOME_STORAGE_KEY: &str = "konnektoren_dialog_outcomes";
+/// Number of dialog outcomes kept; older ones are dropped first.
+pub const MAX_DIALOG_OUTCOME_RECORDS: usize = 500;
+
+/// Storage key for the checkpoint of a single challenge.
+pub fn checkpoint_key(challenge_id: &str) -> String {
+    format!("{}_{}", CHECKPOINT_STORAGE_KEY, challenge_id)
+}
+
+/// Records of single challenge attempts that the game state doesn't keep:
+/// checkpoints of unfinished challenges, mistake reviews, time-outs, hints
+/// and dialog outcomes.
+#[async_trait]
+pub trait AttemptRepositoryTrait: Send + Sync {
+    async fn save_checkpoint(
+        &self,
+        challenge_id: &str,
+        checkpoint: &ChallengeCheckpoint,
+    ) -> Result<(), RepositoryError>;
+    async fn get_checkpoint(
+        &self,
+        challenge_id: &str,
+    ) -> Result<Option<ChallengeCheckpoint>, RepositoryError>;
+    async fn delete_checkpoint(&self, challenge_id: &str) -> Result<(), RepositoryError>;
+    async fn add_mistake_review(&self, challenge: &Challenge) -> Result<(), RepositoryError>;
+    async fn get_mistake_reviews(&self) -> Result<ChallengeHistory, RepositoryError>;
+    async fn add_timeout(&self, timeout: &ChallengeTimeout) -> Result<(), RepositoryError>;
+    async fn get_timeouts(&self) -> Result<Vec<ChallengeTimeout>, RepositoryError>;
+    async fn add_hints(&self, hints: &ChallengeHints) -> Result<(), RepositoryError>;
+    async fn get_hints(&self) -> Result<Vec<ChallengeHints>, RepositoryError>;
+    async fn add_dialog_outcome(&self, outcome: &DialogOutcome) -> Result<(), RepositoryError>;
+    async fn get_dialog_outcomes(&self) -> Result<Vec<DialogOutcome>, RepositoryError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AttemptRepository<S: Storage> {
+    storage: S,
+}
+
+impl<S: Storage> AttemptRepository<S> {
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl<S: Storage + Send + Sync> AttemptRepositoryTrait for AttemptRepository<S> {
+    async fn save_checkpoint(
+        &self,
+        challenge_id: &str,
+        checkpoint: &ChallengeCheckpoint,
+    ) -> Result<(), RepositoryError> {
+        self.storage
+            .set(&checkpoint_key(challenge_id), checkpoint)
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn get_checkpoint(
+        &self,
+        challenge_id: &str,
+    ) -> Result<Option<ChallengeCheckpoint>, RepositoryError> {
+        self.storage
+            .get(&checkpoint_key(challenge_id))
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn delete_checkpoint(&self, challenge_id: &str) -> Result<(), RepositoryError> {
+        self.storage
+            .remove(&checkpoint_key(challenge_id))
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn add_mistake_review(&self, challenge: &Challenge) -> Result<(), RepositoryError> {
+        let mut reviews = self.get_mistake_reviews().await?;
+        reviews.add_challenge(challenge.clone());
+        self.storage
+            .set(MISTAKE_REVIEW_STORAGE_KEY, &reviews)
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn get_mistake_reviews(&self) -> Result<ChallengeHistory, RepositoryError> {
+        self.storage
+            .get(MISTAKE_REVIEW_STORAGE_KEY)
+            .await
+            .map(|reviews| reviews.unwrap_or_else(ChallengeHistory::new))
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn add_timeout(&self, timeout: &ChallengeTimeout) -> Result<(), RepositoryError> {
+        let mut timeouts = self.get_timeouts().await?;
+        timeouts.push(timeout.clone());
+        let excess = timeouts.len().saturating_sub(MAX_TIMEOUT_RECORDS);
+        timeouts.drain(..excess);
+        self.storage
+            .set(TIMEOUT_STORAGE_KEY, &timeouts)
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn get_timeouts(&self) -> Result<Vec<ChallengeTimeout>, RepositoryError> {
+        self.storage
+            .get(TIMEOUT_STORAGE_KEY)
+            .await
+            .map(Option::unwrap_or_default)
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn add_hints(&self, hints: &ChallengeHints) -> Result<(), RepositoryError> {
+        let mut records = self.get_hints().await?;
+        records.push(hints.clone());
+        let excess = records.len().saturating_sub(MAX_HINT_RECORDS);
+        records.drain(..excess);
+        self.storage
+            .set(HINTS_STORAGE_KEY, &records)
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn get_hints(&self) -> Result<Vec<ChallengeHints>, RepositoryError> {
+        self.storage
+            .get(HINTS_STORAGE_KEY)
+            .await
+            .map(Option::unwrap_or_default)
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn add_dialog_outcome(&self, outcome: &DialogOutcome) -> Result<(), RepositoryError> {
+        let mut outcomes = self.get_dialog_outcomes().await?;
+        outcomes.push(outcome.clone());
+        let excess = outcomes.len().saturating_sub(MAX_DIALOG_OUTCOME_RECORDS);
+        outcomes.drain(..excess);
+        self.storage
+            .set(DIALOG_OUTCOME_STORAGE_KEY, &outcomes)
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn get_dialog_outcomes(&self) -> Result<Vec<DialogOutcome>, RepositoryError> {
+        self.storage
+            .get(DIALOG_OUTCOME_STORAGE_KEY)
+            .await
+            .map(Option::unwrap_or_default)
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::memory_storage::MemoryStorage;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_checkpoint() {
+        let repo = AttemptRepository::new(MemoryStorage::default());
+        let checkpoint = ChallengeCheckpoint::new(3, Default::default());
+
+        repo.save_checkpoint("articles", &checkpoint).await.unwrap();
+        assert_eq!(
+            repo.get_checkpoint("articles").await.unwrap(),
+            Some(checkpoint)
+        );
+        assert!(repo.get_checkpoint("other").await.unwrap().is_none());
+
+        repo.delete_checkpoint("articles").await.unwrap();
+        assert!(repo.get_checkpoint("articles").await.unwrap().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_mistake_reviews() {
+        let repo = AttemptRepository::new(MemoryStorage::default());
+        assert!(
+            repo.get_mistake_reviews()
+                .await
+                .unwrap()
+                .challenges
+                .is_empty()
+        );
+
+        repo.add_mistake_review(&Challenge::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            repo.get_mistake_reviews().await.unwrap().challenges.len(),
+            1
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_timeouts() {
+        let repo = AttemptRepository::new(MemoryStorage::default());
+        assert!(repo.get_timeouts().await.unwrap().is_empty());
+
+        let timeout = ChallengeTimeout {
+            challenge_id: "articles".to_string(),
+            started_at: None,
+            timed_out_at: chrono::Utc::now(),
+            task_index: 2,
+            time_limit: crate::model::TimeLimit::per_challenge(60),
+        };
+        repo.add_timeout(&timeout).await.unwrap();
+        assert_eq!(repo.get_timeouts().await.unwrap(), vec![timeout.clone()]);
+
+        for task_index in 0..MAX_TIMEOUT_RECORDS {
+            let timeout = ChallengeTimeout {
+                task_index,
+                ..timeout.clone()
+            };
+            repo.add_timeout(&timeout).await.unwrap();
+        }
+        let timeouts = repo.get_timeouts().await.unwrap();
+        assert_eq!(timeouts.len(), MAX_TIMEOUT_RECORDS);
+        assert_eq!(timeouts[0].task_index, 0);
+        assert_eq!(
+            timeouts[MAX_TIMEOUT_RECORDS - 1].task_index,
+            MAX_TIMEOUT_RECORDS - 1
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_hints_are_capped() {
+        let repo = AttemptRepository::new(MemoryStorage::default());
+        assert!(repo.get_hints().await.unwrap().is_empty());
+
+        let record = |id: usize| ChallengeHints {
+            challenge_id: id.to_string(),
+            started_at: None,
+            hints: Default::default(),
+        };
+        for id in 0..=MAX_HINT_RECORDS {
+            repo.add_hints(&record(id)).await.unwrap();
+        }
+        let records = repo.get_hints().await.unwrap();
+        assert_eq!(records.len(), MAX_HINT_RECORDS);
+        assert_eq!(records[0], record(1));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_dialog_outcomes() {
+        let repo = AttemptRepository::new(MemoryStorage::default());
+        assert!(repo.get_dialog_outcomes().await.unwrap().is_empty());
+
+        let outcome = DialogOutcome {
+            challenge_id: "bakery".to_string(),
+            started_at: None,
+            ending: Some("Rude".to_string()),
+            score: 25,
+        };
+        repo.add_dialog_outcome(&outcome).await.unwrap();
+        assert_eq!(repo.get_dialog_outcomes().await.unwrap(), vec![outcome]);
+    }
+}
@@ -0,0 +1,192 @@
+use super::repository::Repository;
+use super::repository_error::RepositoryError;
+use super::storage::Storage;
+use crate::model::{CachedContent, ContentCacheIndex};
+use async_trait::async_trait;
+
+pub const CONTENT_CACHE_STORAGE_KEY: &str = "konnektoren_content_cache";
+/// Storage key of the [`ContentCacheIndex`].
+pub const CONTENT_CACHE_INDEX_KEY: &str = "konnektoren_content_cache_index";
+/// Bytes the cached content may take up. The storage is shared with the
+/// session and progress, so the least recently used content is evicted
+/// before it could fill the quota.
+pub const MAX_CONTENT_CACHE_BYTES: usize = 2 * 1024 * 1024;
+
+/// Storage key of the cached content of `url`.
+pub fn content_cache_key(url: &str) -> String {
+    format!("{}:{}", CONTENT_CACHE_STORAGE_KEY, url)
+}
+
+#[async_trait]
+pub trait ContentCacheRepositoryTrait: Send + Sync {
+    async fn save_content(&self, content: &CachedContent) -> Result<(), RepositoryError>;
+    async fn get_content(&self, url: &str) -> Result<Option<CachedContent>, RepositoryError>;
+    async fn delete_content(&self, url: &str) -> Result<(), RepositoryError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ContentCacheRepository<S: Storage> {
+    storage: S,
+    budget: usize,
+}
+
+impl<S: Storage> ContentCacheRepository<S> {
+    pub fn new(storage: S) -> Self {
+        Self::with_budget(storage, MAX_CONTENT_CACHE_BYTES)
+    }
+
+    /// A cache that keeps at most `budget` bytes of content.
+    pub fn with_budget(storage: S, budget: usize) -> Self {
+        Self { storage, budget }
+    }
+
+    async fn get_index(&self) -> Result<ContentCacheIndex, RepositoryError> {
+        self.storage
+            .get(CONTENT_CACHE_INDEX_KEY)
+            .await
+            .map(Option::unwrap_or_default)
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn save_index(&self, index: &ContentCacheIndex) -> Result<(), RepositoryError> {
+        self.storage
+            .set(CONTENT_CACHE_INDEX_KEY, index)
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl<S: Storage + Send + Sync> Repository<CachedContent> for ContentCacheRepository<S> {
+    async fn save(&self, key: &str, content: &CachedContent) -> Result<(), RepositoryError> {
+        self.storage
+            .set(key, content)
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<CachedContent>, RepositoryError> {
+        match self.storage.get(key).await {
+            Ok(Some(content)) => Ok(Some(content)),
+            Ok(None) => Ok(None),
+            Err(e) => Err(RepositoryError::StorageError(e.to_string())),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), RepositoryError> {
+        self.storage
+            .remove(key)
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl<S: Storage + Send + Sync> ContentCacheRepositoryTrait for ContentCacheRepository<S> {
+    /// Saves `content` as used most recently, after evicting the least
+    /// recently used content it doesn't fit beside. Content larger than the
+    /// whole budget isn't cached.
+    async fn save_content(&self, content: &CachedContent) -> Result<(), RepositoryError> {
+        let bytes = content.stored_bytes();
+        if bytes > self.budget {
+            return Err(RepositoryError::StorageError(format!(
+                "{} bytes of {} exceed the content cache budget of {} bytes",
+                bytes, content.url, self.budget
+            )));
+        }
+        let mut index = self.get_index().await?;
+        for url in index.insert(&content.url, bytes, self.budget) {
+            Repository::delete(self, &content_cache_key(&url)).await?;
+        }
+        // Saved before the content, so content is never stored unaccounted
+        self.save_index(&index).await?;
+        Repository::save(self, &content_cache_key(&content.url), content).await
+    }
+
+    async fn get_content(&self, url: &str) -> Result<Option<CachedContent>, RepositoryError> {
+        let content = Repository::get(self, &content_cache_key(url)).await?;
+        if content.is_some() {
+            let mut index = self.get_index().await?;
+            if index.touch(url) {
+                self.save_index(&index).await?;
+            }
+        }
+        Ok(content)
+    }
+
+    async fn delete_content(&self, url: &str) -> Result<(), RepositoryError> {
+        let mut index = self.get_index().await?;
+        index.remove(url);
+        self.save_index(&index).await?;
+        Repository::delete(self, &content_cache_key(url)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::MemoryStorage;
+
+    #[tokio::test]
+    async fn test_save_and_get() {
+        let storage = MemoryStorage::default();
+        let repository = ContentCacheRepository::new(storage);
+
+        let content = CachedContent::new("https://example.com/package.zip", &[80, 75, 3, 4])
+            .with_validators(Some("\"abc\"".to_string()), None);
+        repository.save_content(&content).await.unwrap();
+
+        let loaded = repository
+            .get_content("https://example.com/package.zip")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(content, loaded);
+        assert!(
+            repository
+                .get_content("https://example.com/other.zip")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete() {
+        let storage = MemoryStorage::default();
+        let repository = ContentCacheRepository::new(storage);
+
+        repository
+            .save_content(&CachedContent::new("articles.md", b"# Artikel"))
+            .await
+            .unwrap();
+        repository.delete_content("articles.md").await.unwrap();
+
+        let loaded = repository.get_content("articles.md").await.unwrap();
+        assert!(loaded.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_least_recently_used_content_is_evicted() {
+        let storage = MemoryStorage::default();
+        let package = |name: &str| CachedContent::new(name, &[0; 30]);
+        let budget = 2 * package("a.zip").stored_bytes();
+        let repository = ContentCacheRepository::with_budget(storage.clone(), budget);
+
+        repository.save_content(&package("a.zip")).await.unwrap();
+        repository.save_content(&package("b.zip")).await.unwrap();
+        // Reading a.zip makes b.zip the least recently used
+        assert!(repository.get_content("a.zip").await.unwrap().is_some());
+        repository.save_content(&package("c.zip")).await.unwrap();
+
+        assert!(repository.get_content("b.zip").await.unwrap().is_none());
+        assert!(repository.get_content("a.zip").await.unwrap().is_some());
+        assert!(repository.get_content("c.zip").await.unwrap().is_some());
+        let stored: Option<CachedContent> = storage.get(&content_cache_key("b.zip")).await.unwrap();
+        assert!(stored.is_none());
+
+        let too_large = CachedContent::new("large.zip", &vec![0; budget]);
+        assert!(repository.save_content(&too_large).await.is_err());
+        assert!(repository.get_content("c.zip").await.unwrap().is_some());
+    }
+}
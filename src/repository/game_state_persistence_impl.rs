@@ -97,3 +97,28 @@ impl GameStatePersistence for GameStatePersistenceImpl {
         Ok(session_guard.game_state.clone())
     }
 }
+
+/// Persistence for practice runs: reads the current game state but never
+/// saves, so nothing is added to the challenge history.
+pub struct PracticeGameStatePersistence {
+    pub session: Arc<RwLock<konnektoren_core::session::Session>>,
+}
+
+impl PracticeGameStatePersistence {
+    pub fn new(session: Arc<RwLock<konnektoren_core::session::Session>>) -> Self {
+        Self { session }
+    }
+}
+
+impl GameStatePersistence for PracticeGameStatePersistence {
+    fn save_game_state(&self, _state: &GameState) -> Result<()> {
+        Ok(())
+    }
+
+    fn load_game_state(&self) -> Result<GameState> {
+        let session_guard = self.session.read().map_err(|e| {
+            PersistenceError::AccessError(format!("Failed to acquire read lock on session: {}", e))
+        })?;
+        Ok(session_guard.game_state.clone())
+    }
+}
@@ -1,11 +1,14 @@
+mod attempt_repository;
 #[cfg(feature = "backup")]
 mod backup;
 mod certificate_repository;
+mod content_cache_repository;
 mod game_state_persistence_impl;
 #[cfg(feature = "gdrive")]
 mod gdrive_backup;
 mod inbox_repository;
 mod profile_repository;
+mod review_repository;
 mod session_repository;
 mod settings_repository;
 
@@ -18,14 +21,24 @@ mod repository_error;
 mod storage;
 mod storage_error;
 
+pub use attempt_repository::{
+    AttemptRepository, AttemptRepositoryTrait, CHECKPOINT_STORAGE_KEY, DIALOG_OUTCOME_STORAGE_KEY,
+    HINTS_STORAGE_KEY, MAX_DIALOG_OUTCOME_RECORDS, MAX_HINT_RECORDS, MAX_TIMEOUT_RECORDS,
+    MISTAKE_REVIEW_STORAGE_KEY, TIMEOUT_STORAGE_KEY, checkpoint_key,
+};
 #[cfg(feature = "backup")]
 pub use backup::{BACKUP_KEY, Backup, BackupError, BackupInfo};
 pub use certificate_repository::{
     CERTIFICATE_STORAGE_KEY, CertificateRepository, CertificateRepositoryTrait,
 };
-pub use game_state_persistence_impl::GameStatePersistenceImpl;
+pub use content_cache_repository::{
+    CONTENT_CACHE_INDEX_KEY, CONTENT_CACHE_STORAGE_KEY, ContentCacheRepository,
+    ContentCacheRepositoryTrait, MAX_CONTENT_CACHE_BYTES, content_cache_key,
+};
+pub use game_state_persistence_impl::{GameStatePersistenceImpl, PracticeGameStatePersistence};
 pub use inbox_repository::{INBOX_STORAGE_KEY, InboxRepository, InboxRepositoryTrait};
 pub use profile_repository::{PROFILE_STORAGE_KEY, ProfileRepository, ProfileRepositoryTrait};
+pub use review_repository::{REVIEW_STORAGE_KEY, ReviewRepository, ReviewRepositoryTrait};
 pub use session_repository::{SESSION_STORAGE_KEY, SessionRepository, SessionRepositoryTrait};
 pub use settings_repository::{SETTINGS_STORAGE_KEY, SettingsRepository, SettingsRepositoryTrait};
 
@@ -0,0 +1,124 @@
+use super::repository::Repository;
+use super::repository_error::RepositoryError;
+use super::storage::Storage;
+use crate::model::ReviewSchedule;
+use async_trait::async_trait;
+
+pub const REVIEW_STORAGE_KEY: &str = "konnektoren_review";
+
+#[async_trait]
+pub trait ReviewRepositoryTrait: Send + Sync {
+    async fn save_schedule(
+        &self,
+        key: &str,
+        schedule: &ReviewSchedule,
+    ) -> Result<(), RepositoryError>;
+    async fn get_schedule(&self, key: &str) -> Result<Option<ReviewSchedule>, RepositoryError>;
+    async fn delete_schedule(&self, key: &str) -> Result<(), RepositoryError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ReviewRepository<S: Storage> {
+    storage: S,
+}
+
+impl<S: Storage> ReviewRepository<S> {
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl<S: Storage + Send + Sync> Repository<ReviewSchedule> for ReviewRepository<S> {
+    async fn save(&self, key: &str, schedule: &ReviewSchedule) -> Result<(), RepositoryError> {
+        self.storage
+            .set(key, schedule)
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<ReviewSchedule>, RepositoryError> {
+        match self.storage.get(key).await {
+            Ok(Some(schedule)) => Ok(Some(schedule)),
+            Ok(None) => Ok(None),
+            Err(e) => Err(RepositoryError::StorageError(e.to_string())),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), RepositoryError> {
+        self.storage
+            .remove(key)
+            .await
+            .map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl<S: Storage + Send + Sync> ReviewRepositoryTrait for ReviewRepository<S> {
+    async fn save_schedule(
+        &self,
+        key: &str,
+        schedule: &ReviewSchedule,
+    ) -> Result<(), RepositoryError> {
+        Repository::save(self, key, schedule).await
+    }
+
+    async fn get_schedule(&self, key: &str) -> Result<Option<ReviewSchedule>, RepositoryError> {
+        Repository::get(self, key).await
+    }
+
+    async fn delete_schedule(&self, key: &str) -> Result<(), RepositoryError> {
+        Repository::delete(self, key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ReviewItem;
+    use crate::repository::MemoryStorage;
+
+    #[tokio::test]
+    async fn test_save_and_get() {
+        let storage = MemoryStorage::default();
+        let repository = ReviewRepository::new(storage);
+
+        let mut schedule = ReviewSchedule::default();
+        schedule.items.push(ReviewItem::new(
+            "articles",
+            0,
+            "Haus".to_string(),
+            "das".to_string(),
+            1,
+        ));
+        repository
+            .save_schedule(REVIEW_STORAGE_KEY, &schedule)
+            .await
+            .unwrap();
+
+        let loaded = repository
+            .get_schedule(REVIEW_STORAGE_KEY)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(schedule, loaded);
+    }
+
+    #[tokio::test]
+    async fn test_delete() {
+        let storage = MemoryStorage::default();
+        let repository = ReviewRepository::new(storage);
+
+        repository
+            .save_schedule(REVIEW_STORAGE_KEY, &ReviewSchedule::default())
+            .await
+            .unwrap();
+        repository
+            .delete_schedule(REVIEW_STORAGE_KEY)
+            .await
+            .unwrap();
+
+        let loaded = repository.get_schedule(REVIEW_STORAGE_KEY).await.unwrap();
+        assert!(loaded.is_none());
+    }
+}
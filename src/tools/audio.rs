@@ -0,0 +1,51 @@
+//! Plays recorded audio such as the pronunciation of vocabulary items. Each
+//! file gets one audio element, created when it is preloaded or first played
+//! and kept for the session, so playing it again doesn't download it again.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::HtmlAudioElement;
+
+thread_local! {
+    static AUDIO: RefCell<HashMap<String, HtmlAudioElement>> = RefCell::new(HashMap::new());
+}
+
+fn audio_element(url: &str) -> Result<HtmlAudioElement, String> {
+    AUDIO.with(|audio| {
+        let mut audio = audio.borrow_mut();
+        if let Some(element) = audio.get(url) {
+            return Ok(element.clone());
+        }
+        let element = HtmlAudioElement::new_with_src(url)
+            .map_err(|e| format!("Failed to create audio for {}: {:?}", url, e))?;
+        element.set_preload("auto");
+        audio.insert(url.to_string(), element.clone());
+        Ok(element)
+    })
+}
+
+/// Starts downloading `url`, so it plays without delay later.
+pub fn preload_audio(url: &str) {
+    if let Err(e) = audio_element(url) {
+        tracing::debug!("{}", e);
+    }
+}
+
+/// Plays `url` from the start at `volume`, from `0.0` to `1.0`.
+///
+/// Fails when the file can't be loaded or the browser refuses to play it.
+/// The element is dropped then, so the next attempt loads the file again.
+pub async fn play_audio(url: &str, volume: f32) -> Result<(), String> {
+    let element = audio_element(url)?;
+    element.set_volume(volume.clamp(0.0, 1.0) as f64);
+    element.set_current_time(0.0);
+    let played = match element.play() {
+        Ok(promise) => JsFuture::from(promise).await.map(drop),
+        Err(e) => Err(e),
+    };
+    played.map_err(|e| {
+        AUDIO.with(|audio| audio.borrow_mut().remove(url));
+        format!("Failed to play {}: {:?}", url, e)
+    })
+}
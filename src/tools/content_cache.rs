@@ -0,0 +1,162 @@
+//! Downloads challenge assets through the content cache, so challenges that
+//! were played or prefetched once also work offline.
+
+use crate::model::{CacheStatus, CachedContent, ChallengeContent, ContentKind};
+use crate::repository::ContentCacheRepositoryTrait;
+use gloo::net::http::Request;
+use konnektoren_core::asset_loader::AssetLoader;
+use std::future::Future;
+use std::sync::Arc;
+
+enum Download {
+    NotModified,
+    Content(CachedContent, Vec<u8>),
+}
+
+async fn download(
+    url: &str,
+    kind: ContentKind,
+    cached: Option<&CachedContent>,
+) -> Result<Download, String> {
+    let mut request = Request::get(url);
+    if let Some(cached) = cached {
+        for (name, value) in cached.conditional_headers() {
+            request = request.header(name, value);
+        }
+    }
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    if response.status() == 304 && cached.is_some() {
+        return Ok(Download::NotModified);
+    }
+    if !response.ok() {
+        return Err(format!("Request failed: {}", response.status()));
+    }
+    let etag = response.headers().get("ETag");
+    let last_modified = response.headers().get("Last-Modified");
+    let data = response.binary().await.map_err(|e| e.to_string())?;
+    kind.validate(&data)?;
+    let content = CachedContent::new(url, &data).with_validators(etag, last_modified);
+    Ok(Download::Content(content, data))
+}
+
+/// The cached copy of `url` with its content, `None` when there is none or
+/// it is corrupt.
+async fn cached_copy(
+    repository: &Option<Arc<dyn ContentCacheRepositoryTrait>>,
+    url: &str,
+) -> Option<(CachedContent, Vec<u8>)> {
+    let cached = match repository {
+        Some(repository) => match repository.get_content(url).await {
+            Ok(cached) => cached,
+            Err(e) => {
+                tracing::warn!("Failed to read cached {}: {}", url, e);
+                None
+            }
+        },
+        None => None,
+    };
+    cached.and_then(|content| content.bytes().map(|data| (content, data)))
+}
+
+/// Keeps `content` for offline use, if there is a repository and it fits.
+async fn keep(
+    repository: &Option<Arc<dyn ContentCacheRepositoryTrait>>,
+    content: &CachedContent,
+) -> CacheStatus {
+    match repository {
+        Some(repository) => match repository.save_content(content).await {
+            Ok(()) => CacheStatus::Fresh,
+            Err(e) => {
+                tracing::warn!("Failed to cache {}: {}", content.url, e);
+                CacheStatus::Uncached
+            }
+        },
+        None => CacheStatus::Uncached,
+    }
+}
+
+/// Downloads `url`, revalidating a cached copy with its `ETag` or
+/// `Last-Modified`. When the download fails, the cached copy is used.
+pub async fn fetch_cached(
+    repository: Option<Arc<dyn ContentCacheRepositoryTrait>>,
+    url: &str,
+    kind: ContentKind,
+) -> Result<(Vec<u8>, CacheStatus), String> {
+    let cached = cached_copy(&repository, url).await;
+
+    match download(url, kind, cached.as_ref().map(|(content, _)| content)).await {
+        Ok(Download::NotModified) => match cached {
+            Some((_, data)) => Ok((data, CacheStatus::Fresh)),
+            None => Err(format!("No cached copy of {}", url)),
+        },
+        Ok(Download::Content(content, data)) => Ok((data, keep(&repository, &content).await)),
+        Err(err) => match cached {
+            Some((_, data)) => {
+                tracing::info!("Using cached {} after failed download: {}", url, err);
+                Ok((data, CacheStatus::Offline))
+            }
+            None => Err(err),
+        },
+    }
+}
+
+/// Like [`fetch_cached`], but `url` is loaded by `load`, such as an
+/// [`AssetLoader`], which resolves paths its own way and can't revalidate.
+/// When loading fails, the cached copy is used.
+pub async fn load_cached(
+    repository: Option<Arc<dyn ContentCacheRepositoryTrait>>,
+    url: &str,
+    kind: ContentKind,
+    load: impl Future<Output = Result<Vec<u8>, String>>,
+) -> Result<(Vec<u8>, CacheStatus), String> {
+    match load
+        .await
+        .and_then(|data| kind.validate(&data).map(|()| data))
+    {
+        Ok(data) => {
+            let status = keep(&repository, &CachedContent::new(url, &data)).await;
+            Ok((data, status))
+        }
+        Err(err) => match cached_copy(&repository, url).await {
+            Some((_, data)) => {
+                tracing::info!("Using cached {} after failed load: {}", url, err);
+                Ok((data, CacheStatus::Offline))
+            }
+            None => Err(err),
+        },
+    }
+}
+
+/// Loads markdown with the core [`AssetLoader`], the way informative
+/// challenges do.
+pub async fn load_asset(loader: &AssetLoader, path: &str) -> Result<Vec<u8>, String> {
+    loader
+        .load_binary(path)
+        .await
+        .map_err(|e| format!("Failed to load {}: {}", path, e))
+}
+
+/// Downloads `content` into the cache one by one, skipping assets that are
+/// cached already.
+pub async fn prefetch_content(
+    repository: Arc<dyn ContentCacheRepositoryTrait>,
+    content: Vec<ChallengeContent>,
+) {
+    let loader = AssetLoader::default();
+    for item in content {
+        if let Ok(Some(_)) = repository.get_content(&item.url).await {
+            continue;
+        }
+        let repository = Some(repository.clone());
+        let loaded = match item.kind {
+            ContentKind::Package => fetch_cached(repository, &item.url, item.kind).await,
+            ContentKind::Markdown => {
+                let load = load_asset(&loader, &item.url);
+                load_cached(repository, &item.url, item.kind, load).await
+            }
+        };
+        if let Err(e) = loaded {
+            tracing::debug!("Failed to prefetch {}: {}", item.url, e);
+        }
+    }
+}
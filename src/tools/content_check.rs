@@ -0,0 +1,581 @@
+//! Structural checks of challenge content, used by the `content-check`
+//! binary. Mistakes such as an empty sort table or a gap fill sentence with
+//! more gaps than answers are reported here instead of making a component
+//! panic.
+
+use crate::model::{VocabularyAudio, local_asset_path};
+use konnektoren_core::challenges::{ChallengeType, ChallengeVariant, Vocabulary};
+use konnektoren_core::game::GamePath;
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Range;
+use std::path::Path;
+
+use PathSegment::{Index, Key};
+
+/// A step into a challenge file, used to find the line of an issue.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    Key(&'static str),
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Key(key) => write!(f, ".{}", key),
+            Self::Index(index) => write!(f, "[{}]", index),
+        }
+    }
+}
+
+/// A mistake in a challenge or game path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentIssue {
+    /// Where the mistake is, e.g. `questions[2].option`.
+    pub path: Vec<PathSegment>,
+    pub message: String,
+}
+
+impl ContentIssue {
+    pub fn new(path: &[PathSegment], message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_vec(),
+            message: message.into(),
+        }
+    }
+
+    pub fn path_string(&self) -> String {
+        let path: String = self.path.iter().map(ToString::to_string).collect();
+        path.trim_start_matches('.').to_string()
+    }
+}
+
+impl fmt::Display for ContentIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path_string(), self.message)
+        }
+    }
+}
+
+/// Checks the invariants components rely on for each challenge type.
+pub fn check_challenge(challenge_type: &ChallengeType) -> Vec<ContentIssue> {
+    let mut issues = Vec::new();
+    match challenge_type {
+        ChallengeType::MultipleChoice(challenge) => {
+            if challenge.options.is_empty() {
+                issues.push(ContentIssue::new(&[Key("options")], "no options"));
+            }
+            let mut ids = HashSet::new();
+            for (index, option) in challenge.options.iter().enumerate() {
+                if !ids.insert(option.id) {
+                    issues.push(ContentIssue::new(
+                        &[Key("options"), Index(index), Key("id")],
+                        format!("duplicate option id {}", option.id),
+                    ));
+                }
+            }
+            if challenge.questions.is_empty() {
+                issues.push(ContentIssue::new(&[Key("questions")], "no questions"));
+            }
+            for (index, question) in challenge.questions.iter().enumerate() {
+                if !ids.contains(&question.option) {
+                    issues.push(ContentIssue::new(
+                        &[Key("questions"), Index(index), Key("option")],
+                        format!("option {} does not exist", question.option),
+                    ));
+                }
+            }
+        }
+        ChallengeType::ContextualChoice(challenge) => {
+            if challenge.items.is_empty() {
+                issues.push(ContentIssue::new(&[Key("items")], "no items"));
+            }
+            for (index, item) in challenge.items.iter().enumerate() {
+                if item.choices.is_empty() {
+                    issues.push(ContentIssue::new(
+                        &[Key("items"), Index(index), Key("choices")],
+                        "no choices",
+                    ));
+                }
+                for placeholder in numbered_placeholders(&item.template) {
+                    if placeholder >= item.choices.len() {
+                        issues.push(ContentIssue::new(
+                            &[Key("items"), Index(index), Key("template")],
+                            format!(
+                                "placeholder {{{}}} has no choice ({} choices)",
+                                placeholder,
+                                item.choices.len()
+                            ),
+                        ));
+                    }
+                }
+                for (choice_index, choice) in item.choices.iter().enumerate() {
+                    if !choice.options.contains(&choice.correct_answer) {
+                        issues.push(ContentIssue::new(
+                            &[
+                                Key("items"),
+                                Index(index),
+                                Key("choices"),
+                                Index(choice_index),
+                                Key("correct_answer"),
+                            ],
+                            format!(
+                                "correct answer `{}` is not an option",
+                                choice.correct_answer
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+        ChallengeType::GapFill(challenge) => {
+            if challenge.questions.is_empty() {
+                issues.push(ContentIssue::new(&[Key("questions")], "no questions"));
+            }
+            for (index, question) in challenge.questions.iter().enumerate() {
+                let gaps_in_sentence = question.sentence.matches("__").count();
+                if gaps_in_sentence == 0 {
+                    issues.push(ContentIssue::new(
+                        &[Key("questions"), Index(index), Key("sentence")],
+                        "sentence has no gap `__`",
+                    ));
+                }
+                if gaps_in_sentence != question.gaps.len() {
+                    issues.push(ContentIssue::new(
+                        &[Key("questions"), Index(index), Key("gaps")],
+                        format!(
+                            "sentence has {} gaps, but {} are defined",
+                            gaps_in_sentence,
+                            question.gaps.len()
+                        ),
+                    ));
+                }
+                for (gap_index, gap) in question.gaps.iter().enumerate() {
+                    if !gap.options.is_empty() && !gap.options.contains(&gap.correct) {
+                        issues.push(ContentIssue::new(
+                            &[
+                                Key("questions"),
+                                Index(index),
+                                Key("gaps"),
+                                Index(gap_index),
+                                Key("correct"),
+                            ],
+                            format!("correct answer `{}` is not an option", gap.correct),
+                        ));
+                    }
+                }
+            }
+        }
+        ChallengeType::SortTable(challenge) => {
+            if challenge.columns.is_empty() {
+                issues.push(ContentIssue::new(&[Key("columns")], "no columns"));
+            }
+            if challenge.rows.is_empty() {
+                issues.push(ContentIssue::new(&[Key("rows")], "no rows"));
+            }
+            for (index, row) in challenge.rows.iter().enumerate() {
+                if row.values.len() != challenge.columns.len() {
+                    issues.push(ContentIssue::new(
+                        &[Key("rows"), Index(index), Key("values")],
+                        format!(
+                            "{} values for {} columns",
+                            row.values.len(),
+                            challenge.columns.len()
+                        ),
+                    ));
+                }
+            }
+        }
+        ChallengeType::Ordering(challenge) => {
+            if challenge.items.is_empty() {
+                issues.push(ContentIssue::new(&[Key("items")], "no items"));
+            }
+            for (index, item) in challenge.items.iter().enumerate() {
+                let mut order = item.correct_order.clone();
+                order.sort_unstable();
+                if order != (0..item.elements.len()).collect::<Vec<_>>() {
+                    issues.push(ContentIssue::new(
+                        &[Key("items"), Index(index), Key("correct_order")],
+                        format!(
+                            "must list each of the {} elements once",
+                            item.elements.len()
+                        ),
+                    ));
+                }
+            }
+        }
+        ChallengeType::Vocabulary(challenge) => {
+            if challenge.items.is_empty() {
+                issues.push(ContentIssue::new(&[Key("items")], "no items"));
+            }
+            let mut ids = HashSet::new();
+            for (index, item) in challenge.items.iter().enumerate() {
+                if !ids.insert(item.id) {
+                    issues.push(ContentIssue::new(
+                        &[Key("items"), Index(index), Key("id")],
+                        format!("duplicate item id {}", item.id),
+                    ));
+                }
+            }
+        }
+        ChallengeType::Dialog(dialog) => {
+            if dialog.turns.is_empty() {
+                issues.push(ContentIssue::new(&[Key("turns")], "no turns"));
+            }
+            for (index, turn) in dialog.turns.iter().enumerate() {
+                if dialog.speaker_by_id(&turn.speaker).is_none() {
+                    issues.push(ContentIssue::new(
+                        &[Key("turns"), Index(index), Key("speaker")],
+                        format!("speaker `{}` does not exist", turn.speaker),
+                    ));
+                }
+                let options = turn.options.as_ref().map_or(0, Vec::len);
+                if turn.options.is_some() && options == 0 {
+                    issues.push(ContentIssue::new(
+                        &[Key("turns"), Index(index), Key("options")],
+                        "no options",
+                    ));
+                }
+                if let Some(correct) = turn.correct_option
+                    && correct >= options
+                {
+                    issues.push(ContentIssue::new(
+                        &[Key("turns"), Index(index), Key("correct_option")],
+                        format!("option {} does not exist ({} options)", correct, options),
+                    ));
+                }
+            }
+        }
+        ChallengeType::Informative(challenge) => {
+            if !challenge.text.iter().any(|text| text.language == "en") {
+                issues.push(ContentIssue::new(
+                    &[Key("text")],
+                    "no English text, which is the fallback for other languages",
+                ));
+            }
+        }
+        ChallengeType::Custom(challenge) => {
+            if challenge.html.trim().is_empty() && challenge.package_url.is_none() {
+                issues.push(ContentIssue::new(
+                    &[Key("html")],
+                    "neither html nor package_url is set",
+                ));
+            }
+        }
+        _ => {}
+    }
+    issues
+}
+
+/// Checks that the challenges of `game_path` have unique ids and refer to
+/// one of `challenge_ids`, unless that is empty.
+pub fn check_game_path(game_path: &GamePath, challenge_ids: &HashSet<String>) -> Vec<ContentIssue> {
+    let mut issues = Vec::new();
+    if game_path.challenges.is_empty() {
+        issues.push(ContentIssue::new(&[Key("challenges")], "no challenges"));
+    }
+    let mut ids = HashSet::new();
+    for (index, config) in game_path.challenges.iter().enumerate() {
+        if !ids.insert(config.id.as_str()) {
+            issues.push(ContentIssue::new(
+                &[Key("challenges"), Index(index), Key("id")],
+                format!("duplicate challenge id `{}`", config.id),
+            ));
+        }
+        if !challenge_ids.is_empty() && !challenge_ids.contains(&config.challenge) {
+            issues.push(ContentIssue::new(
+                &[Key("challenges"), Index(index), Key("challenge")],
+                format!("challenge `{}` does not exist", config.challenge),
+            ));
+        }
+    }
+    issues
+}
+
+/// Checks that the recorded audio of `vocabulary` belongs to its items, and
+/// that every audio file on this site exists below `site_root`. Files on
+/// other hosts are not checked.
+pub fn check_vocabulary_audio(
+    vocabulary: &Vocabulary,
+    audio: &VocabularyAudio,
+    site_root: &Path,
+) -> Vec<ContentIssue> {
+    let mut issues = Vec::new();
+    for (id, file) in &audio.files {
+        let Some(index) = vocabulary.items.iter().position(|item| item.id == *id) else {
+            issues.push(ContentIssue::new(
+                &[Key("items")],
+                format!("audio `{}` for unknown item {}", file, id),
+            ));
+            continue;
+        };
+        if let Some(path) = local_asset_path(file) {
+            if !site_root.join(path).is_file() {
+                issues.push(ContentIssue::new(
+                    &[Key("items"), Index(index), Key("audio")],
+                    format!("audio file `{}` does not exist", file),
+                ));
+            }
+        }
+    }
+    issues
+}
+
+/// Variant to render a challenge type with, so `ChallengeComponent` shows
+/// its main component.
+pub fn render_variant(challenge_type: &ChallengeType) -> ChallengeVariant {
+    match challenge_type {
+        ChallengeType::MultipleChoice(_) => ChallengeVariant::MultipleChoice,
+        ChallengeType::ContextualChoice(_) => ChallengeVariant::ContextualChoice,
+        ChallengeType::SortTable(_) => ChallengeVariant::SortTable,
+        ChallengeType::Informative(_) => ChallengeVariant::InformativeText,
+        ChallengeType::Custom(_) => ChallengeVariant::Custom,
+        ChallengeType::Dialog(dialog) if dialog.quiz_turns().next().is_some() => {
+            ChallengeVariant::DialogQuiz
+        }
+        ChallengeType::Dialog(_) => ChallengeVariant::DialogObserver,
+        _ => ChallengeVariant::default(),
+    }
+}
+
+/// Indices of the numbered placeholders `{0}`, `{1}`, … in a template.
+fn numbered_placeholders(template: &str) -> Vec<usize> {
+    template
+        .split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}'))
+        .filter_map(|(index, _)| index.trim().parse().ok())
+        .collect()
+}
+
+/// 1-based line of `path` in the YAML `source`, or of its deepest part that
+/// could be found. Only block style YAML is followed.
+pub fn locate(source: &str, path: &[PathSegment]) -> Option<usize> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut range = 0..lines.len();
+    let mut found = None;
+    for segment in path {
+        let hit = match segment {
+            Key(key) => {
+                find_key(&lines, range.clone(), key).map(|(line, end)| (line, line + 1..end))
+            }
+            Index(index) => {
+                find_item(&lines, range.clone(), *index).map(|(line, end)| (line, line..end))
+            }
+        };
+        match hit {
+            Some((line, next)) => {
+                found = Some(line + 1);
+                range = next;
+            }
+            None => break,
+        }
+    }
+    found
+}
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+fn is_content(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && !line.starts_with('#')
+}
+
+fn is_item(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("- ") || line == "-"
+}
+
+/// Line of `key` in `range` and the end of its block.
+fn find_key(lines: &[&str], range: Range<usize>, key: &str) -> Option<(usize, usize)> {
+    let end = range.end;
+    range.into_iter().find_map(|line| {
+        let mut content = lines[line].trim_start();
+        while let Some(rest) = content.strip_prefix("- ") {
+            content = rest.trim_start();
+        }
+        let rest = content.strip_prefix(key)?;
+        if !rest.starts_with(':') {
+            return None;
+        }
+        let column = lines[line].len() - content.len();
+        let block_end = (line + 1..end)
+            .find(|&next| {
+                let text = lines[next];
+                is_content(text)
+                    && (indent(text) < column || (indent(text) == column && !is_item(text)))
+            })
+            .unwrap_or(end);
+        Some((line, block_end))
+    })
+}
+
+/// Line of the `index`th item of the sequence in `range` and the end of it.
+fn find_item(lines: &[&str], range: Range<usize>, index: usize) -> Option<(usize, usize)> {
+    let end = range.end;
+    let mut items = range.filter(|&line| is_item(lines[line]));
+    let first = items.clone().next()?;
+    let column = indent(lines[first]);
+    let line = items
+        .filter(|&line| indent(lines[line]) == column)
+        .nth(index)?;
+    let item_end = (line + 1..end)
+        .find(|&next| is_content(lines[next]) && indent(lines[next]) <= column)
+        .unwrap_or(end);
+    Some((line, item_end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAP_FILL: &str = "\
+id: past-tense
+questions:
+  - sentence: Ich __ nach Berlin __.
+    gaps:
+      - position: 0
+        options: [bin, habe]
+        correct: bin
+      - position: 1
+        options: [gefahren]
+        correct: gefahren
+  # The second question
+  - sentence: Er __ viel.
+    gaps:
+    - position: 0
+      options: [hat, ist]
+      correct: war
+";
+
+    #[test]
+    fn issues_are_located() {
+        let path = |segments: &[PathSegment]| locate(GAP_FILL, segments);
+        assert_eq!(path(&[Key("questions")]), Some(2));
+        assert_eq!(path(&[Key("questions"), Index(1)]), Some(12));
+        assert_eq!(
+            path(&[
+                Key("questions"),
+                Index(0),
+                Key("gaps"),
+                Index(1),
+                Key("correct")
+            ]),
+            Some(10)
+        );
+        assert_eq!(
+            path(&[
+                Key("questions"),
+                Index(1),
+                Key("gaps"),
+                Index(0),
+                Key("correct")
+            ]),
+            Some(16)
+        );
+        assert_eq!(path(&[Key("questions"), Index(1), Key("hints")]), Some(12));
+        assert_eq!(path(&[Key("rows")]), None);
+    }
+
+    #[test]
+    fn issues_show_their_path() {
+        let issue = ContentIssue::new(
+            &[Key("rows"), Index(0), Key("values")],
+            "1 values for 2 columns",
+        );
+        assert_eq!(issue.to_string(), "rows[0].values: 1 values for 2 columns");
+    }
+
+    #[test]
+    fn placeholders_are_numbered() {
+        assert_eq!(
+            numbered_placeholders("Der Fluss {} fließt durch {1} und {0}."),
+            vec![1, 0]
+        );
+        assert!(numbered_placeholders("Kein Platzhalter").is_empty());
+    }
+
+    #[test]
+    fn game_paths_refer_to_challenges() {
+        use konnektoren_core::challenges::ChallengeConfig;
+
+        let config = |id: &str, challenge: &str| ChallengeConfig {
+            id: id.to_string(),
+            challenge: challenge.to_string(),
+            ..Default::default()
+        };
+        let game_path = GamePath {
+            challenges: vec![
+                config("articles-1", "articles"),
+                config("articles-1", "articles"),
+                config("konnektoren-1", "konnektoren"),
+            ],
+            ..Default::default()
+        };
+        let ids = HashSet::from(["articles".to_string()]);
+        let issues: Vec<_> = check_game_path(&game_path, &ids)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                "challenges[1].id: duplicate challenge id `articles-1`",
+                "challenges[2].challenge: challenge `konnektoren` does not exist",
+            ]
+        );
+        assert_eq!(check_game_path(&game_path, &HashSet::new()).len(), 1);
+    }
+
+    #[test]
+    fn missing_audio_files_are_reported() {
+        use konnektoren_core::challenges::VocabularyItem;
+
+        let root = std::env::temp_dir().join(format!("audio-check-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("assets/audio")).unwrap();
+        std::fs::write(root.join("assets/audio/hund.mp3"), [0u8; 4]).unwrap();
+
+        let item = |id: usize, text: &str| VocabularyItem {
+            id,
+            text: text.to_string(),
+            translation: None,
+            icon: None,
+            phonetic: None,
+        };
+        let vocabulary = Vocabulary {
+            id: "animals".to_string(),
+            name: "Tiere".to_string(),
+            description: String::new(),
+            icon: None,
+            lang: "de".to_string(),
+            items: vec![item(0, "der Hund"), item(1, "die Katze")],
+        };
+        let audio = VocabularyAudio {
+            files: [
+                (0, "/assets/audio/hund.mp3".to_string()),
+                (1, "/assets/audio/katze.mp3".to_string()),
+                (7, "https://konnektoren.help/vogel.mp3".to_string()),
+            ]
+            .into(),
+        };
+        let issues: Vec<String> = check_vocabulary_audio(&vocabulary, &audio, &root)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            issues,
+            vec![
+                "items[1].audio: audio file `/assets/audio/katze.mp3` does not exist",
+                "items: audio `https://konnektoren.help/vogel.mp3` for unknown item 7",
+            ]
+        );
+    }
+}
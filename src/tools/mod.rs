@@ -1,2 +1,21 @@
+#[cfg(feature = "csr")]
+mod audio;
+#[cfg(feature = "csr")]
+mod content_cache;
+mod content_check;
+#[cfg(feature = "csr")]
+mod music_player;
+mod shuffle;
 mod traced_request;
+#[cfg(feature = "csr")]
+pub use audio::{play_audio, preload_audio};
+#[cfg(feature = "csr")]
+pub use content_cache::{fetch_cached, load_asset, load_cached, prefetch_content};
+pub use content_check::{
+    ContentIssue, PathSegment, check_challenge, check_game_path, check_vocabulary_audio, locate,
+    render_variant,
+};
+#[cfg(feature = "csr")]
+pub use music_player::MusicPlayer;
+pub use shuffle::{SeededRng, challenge_seed, seed_from, shuffled_indices};
 pub use traced_request::{TracedRequest, TracedResponse, update_trace_from_response};
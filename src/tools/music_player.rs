@@ -0,0 +1,228 @@
+//! Background music with crossfades. Two audio elements take turns: the next
+//! track fades in on one while the current one fades out on the other.
+
+use crate::model::next_track;
+use gloo::events::EventListener;
+use gloo::timers::callback::{Interval, Timeout};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use web_sys::HtmlAudioElement;
+
+const FADE_STEP_MS: u32 = 50;
+
+struct Deck {
+    audio: HtmlAudioElement,
+    _listeners: [EventListener; 2],
+}
+
+#[derive(Default)]
+struct State {
+    decks: Vec<Deck>,
+    active: usize,
+    tracks: Vec<String>,
+    track: usize,
+    repeat: bool,
+    volume: f64,
+    crossfade_ms: u32,
+    /// The active track started fading into the next one.
+    advanced: bool,
+    hidden: bool,
+    fade: Option<Interval>,
+    fade_id: u32,
+}
+
+/// Plays a list of tracks one after another, crossfading between them.
+#[derive(Clone)]
+pub struct MusicPlayer(Rc<RefCell<State>>);
+
+impl Default for MusicPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MusicPlayer {
+    pub fn new() -> Self {
+        let state = Rc::new(RefCell::new(State::default()));
+        let decks = (0..2)
+            .filter_map(|index| deck(Rc::downgrade(&state), index))
+            .collect();
+        state.borrow_mut().decks = decks;
+        Self(state)
+    }
+
+    /// Plays `tracks` from the first one, crossfading over `crossfade_ms`.
+    /// Keeps playing when the first track is already playing.
+    pub fn play(&self, tracks: Vec<String>, repeat: bool, crossfade_ms: u32) {
+        let current = {
+            let mut state = self.0.borrow_mut();
+            state.repeat = repeat;
+            state.crossfade_ms = crossfade_ms;
+            let current = state.tracks.get(state.track).cloned();
+            state.tracks = tracks;
+            state.track = 0;
+            current
+        };
+        let first = self.0.borrow().tracks.first().cloned();
+        match first {
+            Some(first) if Some(&first) == current.as_ref() => {
+                self.0.borrow_mut().advanced = false;
+                self.update_loop();
+            }
+            Some(first) => crossfade(&self.0, &first),
+            None => self.stop(),
+        }
+    }
+
+    /// Changes the volume of the playing track without restarting it.
+    pub fn set_volume(&self, volume: f32) {
+        let mut state = self.0.borrow_mut();
+        state.volume = f64::from(volume.clamp(0.0, 1.0));
+        // A running crossfade picks up the new volume on its next step.
+        if let (None, Some(deck)) = (&state.fade, state.decks.get(state.active)) {
+            deck.audio.set_volume(state.volume);
+        }
+    }
+
+    /// Pauses while the page is hidden, and resumes when it is shown again.
+    pub fn set_hidden(&self, hidden: bool) {
+        let mut state = self.0.borrow_mut();
+        state.hidden = hidden;
+        if hidden {
+            for deck in &state.decks {
+                deck.audio.pause().ok();
+            }
+            return;
+        }
+        match state.decks.get(state.active) {
+            Some(deck) if !state.tracks.is_empty() => {
+                let _ = deck.audio.play();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn stop(&self) {
+        let mut state = self.0.borrow_mut();
+        state.fade = None;
+        state.tracks.clear();
+        for deck in &state.decks {
+            deck.audio.pause().ok();
+        }
+    }
+
+    /// A single repeating track loops in place instead of fading into itself.
+    fn update_loop(&self) {
+        let state = self.0.borrow();
+        let single = state.tracks.len() == 1 && state.repeat;
+        if let Some(deck) = state.decks.get(state.active) {
+            deck.audio.set_loop(single);
+        }
+    }
+}
+
+fn deck(state: Weak<RefCell<State>>, index: usize) -> Option<Deck> {
+    let audio = HtmlAudioElement::new().ok()?;
+    audio.set_preload("auto");
+    let on_time_update = {
+        let state = state.clone();
+        let track = audio.clone();
+        EventListener::new(&audio, "timeupdate", move |_| {
+            let Some(state) = state.upgrade() else {
+                return;
+            };
+            let fade_secs = f64::from(state.borrow().crossfade_ms) / 1000.0;
+            let remaining = track.duration() - track.current_time();
+            if remaining.is_finite() && remaining <= fade_secs {
+                advance(&state, index);
+            }
+        })
+    };
+    let on_ended = EventListener::new(&audio, "ended", move |_| {
+        if let Some(state) = state.upgrade() {
+            advance(&state, index);
+        }
+    });
+    Some(Deck {
+        audio,
+        _listeners: [on_time_update, on_ended],
+    })
+}
+
+/// Moves on from the track of deck `index` once, if it is the active one.
+fn advance(state: &Rc<RefCell<State>>, index: usize) {
+    let next = {
+        let mut state = state.borrow_mut();
+        let single_loop = state.tracks.len() == 1 && state.repeat;
+        if state.active != index || state.advanced || single_loop {
+            return;
+        }
+        state.advanced = true;
+        match next_track(state.track, state.tracks.len(), state.repeat) {
+            Some(next) => {
+                state.track = next;
+                state.tracks.get(next).cloned()
+            }
+            None => None,
+        }
+    };
+    if let Some(url) = next {
+        crossfade(state, &url);
+    }
+}
+
+/// Starts `url` on the idle deck and fades it in while the active deck fades
+/// out.
+fn crossfade(state: &Rc<RefCell<State>>, url: &str) {
+    let (incoming, outgoing, steps, fade_id) = {
+        let mut state = state.borrow_mut();
+        if state.decks.len() < 2 {
+            return;
+        }
+        let outgoing = state.decks[state.active].audio.clone();
+        state.active = 1 - state.active;
+        state.advanced = false;
+        state.fade_id = state.fade_id.wrapping_add(1);
+        let single_loop = state.tracks.len() == 1 && state.repeat;
+        let incoming = state.decks[state.active].audio.clone();
+        incoming.set_src(url);
+        incoming.set_loop(single_loop);
+        incoming.set_volume(0.0);
+        if !state.hidden {
+            let _ = incoming.play();
+        }
+        let steps = (state.crossfade_ms / FADE_STEP_MS).max(1);
+        (incoming, outgoing, steps, state.fade_id)
+    };
+
+    let mut step = 0;
+    let weak = Rc::downgrade(state);
+    let interval = Interval::new(FADE_STEP_MS, move || {
+        let Some(state) = weak.upgrade() else {
+            return;
+        };
+        if step >= steps {
+            return;
+        }
+        step += 1;
+        let progress = f64::from(step) / f64::from(steps);
+        let volume = state.borrow().volume;
+        incoming.set_volume(volume * progress);
+        outgoing.set_volume(volume * (1.0 - progress));
+        if step == steps {
+            outgoing.pause().ok();
+            // The interval can't be dropped while it runs.
+            let weak = Rc::downgrade(&state);
+            Timeout::new(0, move || {
+                if let Some(state) = weak.upgrade() {
+                    let mut state = state.borrow_mut();
+                    if state.fade_id == fade_id {
+                        state.fade = None;
+                    }
+                }
+            })
+            .forget();
+        }
+    });
+    state.borrow_mut().fade = Some(interval);
+}
@@ -0,0 +1,113 @@
+//! Seeded shuffling, so a challenge is laid out the same way for the same
+//! seed: in bug reports, in classroom sessions where everyone gets the same
+//! order, and on both sides of SSR hydration.
+//!
+//! The generator (SplitMix64) and the Fisher-Yates shuffle are implemented
+//! here rather than taken from `rand`, whose `StdRng` may change between
+//! releases.
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Stable hash of `parts` (FNV-1a), to derive a seed from text.
+pub fn seed_from(parts: &[&str]) -> u64 {
+    let mut hash = FNV_OFFSET;
+    let mut feed = |byte: u8| {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    };
+    for (index, part) in parts.iter().enumerate() {
+        if index > 0 {
+            feed(0xff);
+        }
+        part.bytes().for_each(&mut feed);
+    }
+    hash
+}
+
+/// Seed for the layout of a challenge within a session.
+pub fn challenge_seed(challenge_id: &str, session_id: &str) -> u64 {
+    seed_from(&[challenge_id, session_id])
+}
+
+/// Small deterministic random number generator (SplitMix64).
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Independent generator for one part of a challenge, e.g. a question
+    /// or a column, so the parts don't all share one order.
+    pub fn for_stream(seed: u64, stream: u64) -> Self {
+        Self::new(seed ^ stream.wrapping_mul(GOLDEN_GAMMA))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Number in `0..bound`. `bound` must not be zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            let other = self.below(index + 1);
+            items.swap(index, other);
+        }
+    }
+}
+
+/// `0..len` in the order given by `seed` and `stream`.
+pub fn shuffled_indices(len: usize, seed: u64, stream: u64) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..len).collect();
+    SeededRng::for_stream(seed, stream).shuffle(&mut indices);
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_is_stable() {
+        assert_eq!(seed_from(&["konnektoren"]), 0x43a2_0e82_6b20_c763);
+        assert_eq!(
+            challenge_seed("articles-1", "session-1"),
+            0xe929_cecb_77a7_1b1d
+        );
+        assert_ne!(seed_from(&["ab", "c"]), seed_from(&["a", "bc"]));
+    }
+
+    #[test]
+    fn test_shuffle_is_stable_for_a_seed() {
+        let seed = challenge_seed("articles-1", "session-1");
+        assert_eq!(shuffled_indices(6, seed, 0), vec![2, 3, 4, 1, 5, 0]);
+        assert_eq!(shuffled_indices(6, seed, 1), vec![4, 0, 5, 1, 2, 3]);
+        assert_eq!(shuffled_indices(6, 42, 0), vec![4, 3, 0, 2, 5, 1]);
+        assert_eq!(
+            shuffled_indices(6, challenge_seed("articles-1", "session-2"), 0),
+            vec![0, 4, 5, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_shuffle_keeps_all_items() {
+        let mut items: Vec<usize> = (0..20).collect();
+        SeededRng::new(7).shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+        assert!(shuffled_indices(0, 1, 0).is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+//! Renders the challenge components on the server and checks the markup for
+//! common accessibility mistakes.
+//!
+//! Run with:
+//!   cargo test --test a11y_audit --features ssr
+#![cfg(feature = "ssr")]
+
+use konnektoren_core::prelude::*;
+use konnektoren_yew::model::DefaultSessionInitializer;
+use konnektoren_yew::prelude::*;
+use konnektoren_yew::repository::LocalStorage;
+use regex::Regex;
+use std::sync::Arc;
+use yew::ServerRenderer;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct AuditProps {
+    challenge: Challenge,
+}
+
+#[function_component(Audit)]
+fn audit(props: &AuditProps) -> Html {
+    let storage = LocalStorage::new(None);
+    let repository_config = create_repositories(storage, Arc::new(DefaultSessionInitializer));
+
+    html! {
+        <RepositoryProvider config={repository_config}>
+        <ThemeProvider>
+        <DesignProvider>
+        <I18nProvider config={create_i18n_config()}>
+            <GameControllerProvider>
+                <ChallengeComponent challenge={props.challenge.clone()} preview={true} />
+                <ProgressBar value={1} max={3} label="Question 2 of 3" />
+            </GameControllerProvider>
+        </I18nProvider>
+        </DesignProvider>
+        </ThemeProvider>
+        </RepositoryProvider>
+    }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let pattern = format!(r#"\s{}="([^"]*)""#, regex::escape(name));
+    Regex::new(&pattern)
+        .unwrap()
+        .captures(tag)
+        .map(|captures| captures[1].to_string())
+}
+
+/// Returns one message per violation found in `html`.
+fn audit_html(html: &str) -> Vec<String> {
+    let mut violations = vec![];
+
+    let tags = Regex::new(r"<(img|input|select|textarea)\b[^>]*>").unwrap();
+    for tag in tags.find_iter(html).map(|m| m.as_str()) {
+        let labelled = attribute(tag, "aria-label").is_some()
+            || attribute(tag, "aria-labelledby").is_some()
+            || attribute(tag, "id").is_some_and(|id| html.contains(&format!(r#"for="{}""#, id)));
+        let ok = if tag.starts_with("<img") {
+            attribute(tag, "alt").is_some()
+        } else {
+            labelled || attribute(tag, "type").as_deref() == Some("hidden")
+        };
+        if !ok {
+            violations.push(format!("missing label or alt text: {}", tag));
+        }
+    }
+
+    let buttons = Regex::new(r"(?s)<button\b([^>]*)>(.*?)</button>").unwrap();
+    for button in buttons.captures_iter(html) {
+        let text = Regex::new(r"<[^>]*>").unwrap().replace_all(&button[2], "");
+        if text.trim().is_empty() && attribute(&button[1], "aria-label").is_none() {
+            violations.push(format!("button without accessible name: {}", &button[0]));
+        }
+    }
+
+    let roles = Regex::new(r#"<[a-z]+\b[^>]*\srole="(radio|progressbar)"[^>]*>"#).unwrap();
+    for role in roles.captures_iter(html) {
+        let required = match &role[1] {
+            "radio" => "aria-checked",
+            _ => "aria-valuenow",
+        };
+        if attribute(&role[0], required).is_none() {
+            violations.push(format!(
+                "role {} without {}: {}",
+                &role[1], required, &role[0]
+            ));
+        }
+    }
+
+    violations
+}
+
+#[test]
+fn test_audit_html_reports_violations() {
+    let html = r#"<img src="a.png"><button><i class="fa"></i></button><span role="radio">A</span>"#;
+    assert_eq!(audit_html(html).len(), 3);
+
+    let html = r#"<img src="a.png" alt=""><button aria-label="Close"></button><span role="radio" aria-checked="false">A</span>"#;
+    assert!(audit_html(html).is_empty());
+}
+
+#[tokio::test]
+async fn test_challenges_pass_accessibility_audit() {
+    let game = Game::default();
+    let mut violations = vec![];
+
+    for challenge_config in &game.game_paths[0].challenges {
+        let Ok(challenge) = game.create_challenge(&challenge_config.id) else {
+            continue;
+        };
+        let html = ServerRenderer::<Audit>::with_props(move || AuditProps { challenge })
+            .render()
+            .await;
+        violations.extend(
+            audit_html(&html)
+                .into_iter()
+                .map(|violation| format!("{}: {}", challenge_config.id, violation)),
+        );
+    }
+
+    assert!(violations.is_empty(), "{}", violations.join("\n"));
+}
@@ -0,0 +1,78 @@
+//! Renders mistake reviews on the server and checks that they are played
+//! with the variant of the original challenge.
+//!
+//! Run with:
+//!   cargo test --test mistake_review --features ssr
+#![cfg(feature = "ssr")]
+
+use konnektoren_core::challenges::{
+    Challenge, ChallengeResult, ChallengeType, ChallengeVariant, Choice, ContextItem,
+    ContextItemChoiceAnswers, ContextualChoice,
+};
+use konnektoren_yew::prelude::*;
+use yew::ServerRenderer;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct ReviewProps {
+    challenge: Challenge,
+    variant: Option<ChallengeVariant>,
+}
+
+#[function_component(Review)]
+fn review(props: &ReviewProps) -> Html {
+    html! {
+        <I18nProvider config={create_i18n_config()}>
+            <MistakeReviewComponent
+                challenge={props.challenge.clone()}
+                variant={props.variant.clone()}
+                on_close={Callback::noop()}
+                preview={true}
+            />
+        </I18nProvider>
+    }
+}
+
+fn capitals() -> Challenge {
+    let item = |country: &str, capital: &str, other: &str| ContextItem {
+        template: format!("The capital of {} is {{0}}.", country),
+        choices: vec![Choice {
+            id: 0,
+            correct_answer: capital.to_string(),
+            options: vec![capital.to_string(), other.to_string()],
+        }],
+    };
+    Challenge {
+        challenge_type: ChallengeType::ContextualChoice(ContextualChoice {
+            id: "capitals".to_string(),
+            name: "Capitals".to_string(),
+            items: vec![
+                item("Germany", "Berlin", "Munich"),
+                item("France", "Paris", "Lyon"),
+            ],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_contextual_choice_review_is_rendered() {
+    let challenge = capitals();
+    let result = ChallengeResult::ContextualChoice(vec![
+        ContextItemChoiceAnswers { ids: vec![0] },
+        ContextItemChoiceAnswers { ids: vec![1] },
+    ]);
+    let review = mistakes_challenge(&challenge, &result).unwrap();
+
+    let html = ServerRenderer::<Review>::with_props(move || ReviewProps {
+        challenge: review,
+        variant: Some(ChallengeVariant::ContextualChoice),
+    })
+    .render()
+    .await;
+
+    assert!(html.contains("contextual-choice"), "{}", html);
+    assert!(html.contains("France"), "{}", html);
+    assert!(!html.contains("Germany"), "{}", html);
+}
@@ -0,0 +1,36 @@
+//! Checks that components below a `SoundProvider` get its sounds, and that
+//! they stay quiet without one.
+//!
+//! Run with:
+//!   cargo test --test sound_wiring --features ssr
+#![cfg(feature = "ssr")]
+
+use konnektoren_yew::prelude::*;
+use yew::ServerRenderer;
+use yew::prelude::*;
+
+#[function_component(Probe)]
+fn probe() -> Html {
+    match use_context::<SoundContext>() {
+        Some(_) => html! { <p>{ "sounds" }</p> },
+        None => html! { <p>{ "silent" }</p> },
+    }
+}
+
+#[function_component(WithProvider)]
+fn with_provider() -> Html {
+    html! {
+        <SoundProvider>
+            <Probe />
+        </SoundProvider>
+    }
+}
+
+#[tokio::test]
+async fn test_sound_provider_reaches_its_children() {
+    let html = ServerRenderer::<WithProvider>::new().render().await;
+    assert!(html.contains("sounds"), "{}", html);
+
+    let html = ServerRenderer::<Probe>::new().render().await;
+    assert!(html.contains("silent"), "{}", html);
+}